@@ -0,0 +1,87 @@
+use chip8_assembler::*;
+
+use std::env;
+use std::fs;
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage: chip8-asm-fmt [--check] [--lowercase] [--spaces] [--indent=N]
+                     [--radix=keep|bin|dec|hex] FILE...
+
+Formats chip8 assembly files in place.
+  --check      Do not write any files. Exit with an error if a file is not formatted.
+  --lowercase  Write mnemonics and registers in lowercase.
+  --spaces     Separate operands with spaces instead of commas.
+  --indent=N   Indent statements by N spaces. Defaults to 2.
+  --radix=R    Rewrite numeric literals in binary, decimal or hex. Defaults to keep.";
+
+fn main() -> ExitCode {
+    let mut options = FormatOptions::default();
+    let mut check = false;
+    let mut filepaths: Vec<String> = Vec::new();
+
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--check" => check = true,
+            "--lowercase" => options.case = Case::Lower,
+            "--spaces" => options.separator = Separator::Space,
+            "--help" => {
+                println!("{}", USAGE);
+                return ExitCode::SUCCESS;
+            }
+            _ if arg.starts_with("--indent=") => match arg[9..].parse() {
+                Ok(indent_width) => options.indent_width = indent_width,
+                Err(_) => return usage_error(format!("Invalid indent: {}", &arg[9..])),
+            },
+            _ if arg.starts_with("--radix=") => {
+                options.radix = match &arg[8..] {
+                    "keep" => Radix::Keep,
+                    "bin" => Radix::Binary,
+                    "dec" => Radix::Decimal,
+                    "hex" => Radix::Hex,
+                    radix => return usage_error(format!("Invalid radix: {}", radix)),
+                }
+            }
+            _ if arg.starts_with("--") => return usage_error(format!("Unknown flag: {}", arg)),
+            _ => filepaths.push(arg),
+        }
+    }
+
+    if filepaths.is_empty() {
+        return usage_error("Expected at least one assembly file.".to_string());
+    }
+
+    let mut success = true;
+    for filepath in filepaths.iter() {
+        let text = match fs::read_to_string(filepath) {
+            Ok(text) => text,
+            Err(e) => {
+                println!("Error reading {}: {}", filepath, e);
+                success = false;
+                continue;
+            }
+        };
+
+        let formatted = format_source(&text, &options);
+        if formatted == text {
+            continue;
+        }
+        if check {
+            println!("Not formatted: {}", filepath);
+            success = false;
+        } else if let Err(e) = fs::write(filepath, formatted) {
+            println!("Error writing {}: {}", filepath, e);
+            success = false;
+        }
+    }
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn usage_error(message: String) -> ExitCode {
+    println!("{}\n\n{}", message, USAGE);
+    ExitCode::FAILURE
+}
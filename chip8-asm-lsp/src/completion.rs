@@ -0,0 +1,77 @@
+use crate::document::Document;
+
+use lsp_types::{CompletionItem, CompletionItemKind};
+
+const MNEMONICS: [(&str, &str); 27] = [
+    ("CLS", "Clear the display"),
+    ("RET", "Return from a subroutine"),
+    ("EXIT", "Stop the interpreter"),
+    ("JP", "Jump to an address, or V0 + an address"),
+    ("CALL", "Call a subroutine"),
+    ("SE", "Skip if equal"),
+    ("SNE", "Skip if not equal"),
+    ("LD", "Load"),
+    ("ADD", "Add"),
+    ("OR", "Bitwise or"),
+    ("AND", "Bitwise and"),
+    ("XOR", "Bitwise xor"),
+    ("SUB", "Subtract"),
+    ("SHR", "Shift right"),
+    ("SUBN", "Subtract negated"),
+    ("SHL", "Shift left"),
+    ("RND", "Random byte"),
+    ("DRW", "Draw a sprite"),
+    ("SKP", "Skip if key pressed"),
+    ("SKNP", "Skip if key not pressed"),
+    ("IF", "IF <cond> THEN ... [ELSE ...] ENDIF"),
+    ("ELSE", "Start the ELSE branch of an IF"),
+    ("ENDIF", "End an IF block"),
+    ("LOOP", "LOOP ... AGAIN"),
+    ("WHILE", "WHILE <cond> ... AGAIN"),
+    ("AGAIN", "End a LOOP or WHILE block"),
+    ("BREAK", "Exit the innermost loop"),
+];
+
+const SPECIAL_REGISTERS: [(&str, &str); 7] = [
+    ("I", "Address register"),
+    ("DT", "Delay timer"),
+    ("ST", "Sound timer"),
+    ("K", "Next key press"),
+    ("F", "Digit sprite"),
+    ("B", "Decimal digits"),
+    ("[I]", "Memory at I"),
+];
+
+/// @return the completions for a cursor at the given word index in its line.
+pub fn completions(document: &Document, word_index: usize) -> Vec<CompletionItem> {
+    if word_index == 0 {
+        return MNEMONICS
+            .iter()
+            .map(|(label, detail)| item(label.to_string(), detail, CompletionItemKind::KEYWORD))
+            .collect();
+    }
+
+    let registers = (0..16).map(|x| {
+        item(
+            format!("V{:X}", x),
+            "Register",
+            CompletionItemKind::VARIABLE,
+        )
+    });
+    let special_registers = SPECIAL_REGISTERS
+        .iter()
+        .map(|(label, detail)| item(label.to_string(), detail, CompletionItemKind::VARIABLE));
+    let labels = document
+        .label_names()
+        .map(|name| item(name.to_string(), "Label", CompletionItemKind::REFERENCE));
+    registers.chain(special_registers).chain(labels).collect()
+}
+
+fn item(label: String, detail: &str, kind: CompletionItemKind) -> CompletionItem {
+    CompletionItem {
+        label,
+        detail: Some(detail.to_string()),
+        kind: Some(kind),
+        ..Default::default()
+    }
+}
@@ -0,0 +1,37 @@
+use crate::completion::*;
+use crate::document::Document;
+
+use lsp_types::CompletionItemKind;
+
+fn labels(document: &Document, word_index: usize) -> Vec<String> {
+    completions(document, word_index)
+        .into_iter()
+        .map(|item| item.label)
+        .collect()
+}
+
+#[test]
+fn test_complete_mnemonics() {
+    let document = Document::new("main:\n  \n".to_string());
+    let items = completions(&document, 0);
+    assert!(
+        items
+            .iter()
+            .all(|item| item.kind == Some(CompletionItemKind::KEYWORD))
+    );
+    let labels = labels(&document, 0);
+    assert!(labels.contains(&"DRW".to_string()));
+    assert!(labels.contains(&"WHILE".to_string()));
+    assert!(!labels.contains(&"main".to_string()));
+}
+
+#[test]
+fn test_complete_operands() {
+    let document = Document::new("main:\n  LOOP\n  AGAIN\nsprite:\n  0xF0\n".to_string());
+    let labels = labels(&document, 1);
+    assert!(labels.contains(&"V0".to_string()));
+    assert!(labels.contains(&"VF".to_string()));
+    assert!(labels.contains(&"[I]".to_string()));
+    assert_eq!(vec!["main", "sprite"], labels[labels.len() - 2..].to_vec());
+    assert!(!labels.iter().any(|label| label.starts_with("__")));
+}
@@ -0,0 +1,185 @@
+use chip8_assembler::*;
+
+pub const MEM_ADDR_START: u16 = 0x200;
+pub const MEM_ADDR_MAX: u16 = 4096;
+
+/// A word in the source text, such as a label definition or a reference to a label.
+#[derive(Debug, Clone)]
+pub struct Word {
+    pub text: String,
+    pub line_num: u16,
+    pub column: u32,
+    pub length: u32,
+    /// Index of the word within its line. The mnemonic of an instruction has index 0.
+    pub index: usize,
+}
+
+impl Word {
+    pub fn contains(&self, line_num: u16, column: u32) -> bool {
+        self.line_num == line_num && self.column <= column && column <= self.column + self.length
+    }
+}
+
+/// An open assembly file, along with the result of assembling it.
+pub struct Document {
+    pub text: String,
+    pub statements: Vec<Statement>,
+    pub errors: Vec<AssemblerError>,
+    pub warnings: Vec<LintWarning>,
+    pub definitions: Vec<Word>,
+    pub references: Vec<Word>,
+}
+
+impl Document {
+    pub fn new(text: String) -> Self {
+        let (statements, errors) = match assemble_statements(&text, MEM_ADDR_START, MEM_ADDR_MAX) {
+            Ok(statements) => (statements, Vec::new()),
+            Err(errors) => (Vec::new(), errors),
+        };
+        let warnings = lint(&text, &statements);
+
+        let mut definitions: Vec<Word> = Vec::new();
+        let mut references: Vec<Word> = Vec::new();
+        for (line_num, line) in text.lines().enumerate() {
+            let words = line_words(line, line_num as u16);
+            match words.as_slice() {
+                [word] if word.text.ends_with(':') && word.text.len() > 1 => {
+                    definitions.push(Word {
+                        text: word.text[..word.text.len() - 1].to_string(),
+                        length: word.length - 1,
+                        ..word.clone()
+                    });
+                }
+                _ => references.extend(words.into_iter().skip(1)),
+            }
+        }
+        references.retain(|word| {
+            definitions
+                .iter()
+                .any(|definition| definition.text == word.text)
+        });
+
+        Self {
+            text,
+            statements,
+            errors,
+            warnings,
+            definitions,
+            references,
+        }
+    }
+
+    /// @return the word under the cursor.
+    pub fn word_at(&self, line_num: u16, column: u32) -> Option<Word> {
+        let line = self.text.lines().nth(line_num as usize)?;
+        line_words(line, line_num)
+            .into_iter()
+            .find(|word| word.contains(line_num, column))
+    }
+
+    /// @return the index of the word being typed at the cursor.
+    pub fn word_index_at(&self, line_num: u16, column: u32) -> usize {
+        let Some(line) = self.text.lines().nth(line_num as usize) else {
+            return 0;
+        };
+        let words = line_words(line, line_num);
+        match words.iter().find(|word| word.contains(line_num, column)) {
+            Some(word) => word.index,
+            None => words
+                .iter()
+                .filter(|word| word.column + word.length < column)
+                .count(),
+        }
+    }
+
+    /// @return the label name under the cursor, whether it is a definition or a reference.
+    pub fn label_at(&self, line_num: u16, column: u32) -> Option<&str> {
+        self.definitions
+            .iter()
+            .chain(self.references.iter())
+            .find(|word| word.contains(line_num, column))
+            .map(|word| word.text.as_str())
+    }
+
+    pub fn definition(&self, name: &str) -> Option<&Word> {
+        self.definitions.iter().find(|word| word.text == name)
+    }
+
+    pub fn references<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Word> {
+        self.references.iter().filter(move |word| word.text == name)
+    }
+
+    /// @return the memory address of a label, if the document assembled successfully.
+    pub fn label_address(&self, name: &str) -> Option<u16> {
+        self.statements
+            .iter()
+            .find_map(|statement| match statement.statement_type {
+                StatementType::Label { name: ref label } if label == name => {
+                    Some(statement.mem_addr)
+                }
+                _ => None,
+            })
+    }
+
+    /// @return the instruction statement written on a source line. Pseudo-instructions are not
+    /// included because their line does not match any single instruction.
+    pub fn instruction_statement(&self, line_num: u16) -> Option<&Statement> {
+        let line = self.text.lines().nth(line_num as usize)?;
+        self.statements.iter().find(|statement| {
+            statement.line_num == line_num
+                && statement.line == line
+                && matches!(statement.statement_type, StatementType::Instruction { .. })
+        })
+    }
+
+    /// @return the byte column of a column counted in UTF-16 code units, which LSP positions
+    /// use. A column past the end of the line is the end of the line.
+    pub fn byte_column(&self, line_num: u16, utf16_column: u32) -> u32 {
+        let Some(line) = self.text.lines().nth(line_num as usize) else {
+            return utf16_column;
+        };
+        let mut column = 0;
+        for (byte_column, c) in line.char_indices() {
+            if column >= utf16_column {
+                return byte_column as u32;
+            }
+            column += c.len_utf16() as u32;
+        }
+        line.len() as u32
+    }
+
+    /// @return the column in UTF-16 code units of a byte column.
+    pub fn utf16_column(&self, line_num: u16, byte_column: u32) -> u32 {
+        let Some(line) = self.text.lines().nth(line_num as usize) else {
+            return byte_column;
+        };
+        line.char_indices()
+            .take_while(|(column, _)| (*column as u32) < byte_column)
+            .map(|(_, c)| c.len_utf16() as u32)
+            .sum()
+    }
+
+    /// @return user defined label names, excluding the hidden control flow labels.
+    pub fn label_names(&self) -> impl Iterator<Item = &str> {
+        self.definitions
+            .iter()
+            .map(|word| word.text.as_str())
+            .filter(|name| !name.starts_with(HIDDEN_LABEL_PREFIX))
+    }
+}
+
+fn line_words(line: &str, line_num: u16) -> Vec<Word> {
+    let words = get_line_words(line);
+    (0..words.len())
+        .filter_map(|index| {
+            let location = LineLocation::try_from_line_words(line, &words, index)?;
+            Some(Word {
+                text: words[index].to_string(),
+                line_num,
+                column: location.column,
+                length: location.length,
+                index,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,84 @@
+use crate::document::*;
+
+const TEXT: &str = "main:
+  CALL draw
+  JP main
+draw:
+  LD I, sprite
+  RET
+sprite:
+  0xF0 0x90
+";
+
+fn positions<'a>(words: impl Iterator<Item = &'a Word>) -> Vec<(u16, u32, u32)> {
+    words
+        .map(|word| (word.line_num, word.column, word.length))
+        .collect()
+}
+
+#[test]
+fn test_definitions() {
+    let document = Document::new(TEXT.to_string());
+    assert!(document.errors.is_empty());
+    assert_eq!(
+        vec![(0, 0, 4)],
+        positions(document.definition("main").into_iter())
+    );
+    assert_eq!(
+        vec![(6, 0, 6)],
+        positions(document.definition("sprite").into_iter())
+    );
+    assert!(document.definition("missing").is_none());
+}
+
+#[test]
+fn test_references() {
+    let document = Document::new(TEXT.to_string());
+    assert_eq!(vec![(1, 7, 4)], positions(document.references("draw")));
+    assert_eq!(vec![(2, 5, 4)], positions(document.references("main")));
+    assert_eq!(vec![(4, 8, 6)], positions(document.references("sprite")));
+}
+
+#[test]
+fn test_label_at() {
+    let document = Document::new(TEXT.to_string());
+    assert_eq!(Some("draw"), document.label_at(1, 8));
+    assert_eq!(Some("draw"), document.label_at(3, 0));
+    assert_eq!(None, document.label_at(1, 3));
+    assert_eq!(None, document.label_at(20, 0));
+}
+
+#[test]
+fn test_label_address() {
+    let document = Document::new(TEXT.to_string());
+    assert_eq!(Some(0x200), document.label_address("main"));
+    assert_eq!(Some(0x204), document.label_address("draw"));
+    assert_eq!(Some(0x208), document.label_address("sprite"));
+}
+
+#[test]
+fn test_word_index_at() {
+    let document = Document::new(TEXT.to_string());
+    assert_eq!(0, document.word_index_at(4, 3));
+    assert_eq!(1, document.word_index_at(4, 6));
+    assert_eq!(2, document.word_index_at(4, 10));
+}
+
+#[test]
+fn test_hidden_labels_are_not_named() {
+    let document = Document::new("main:\n  LOOP\n  AGAIN\n".to_string());
+    assert_eq!(vec!["main"], document.label_names().collect::<Vec<&str>>());
+}
+
+#[test]
+fn test_utf16_columns() {
+    // "é" is 2 bytes and 1 UTF-16 code unit, "𝄞" is 4 bytes and 2 code units.
+    let document = Document::new("; é𝄞\n  JP main\nmain:\n".to_string());
+    assert_eq!(3, document.utf16_column(0, 4));
+    assert_eq!(5, document.utf16_column(0, 8));
+    assert_eq!(4, document.byte_column(0, 3));
+    assert_eq!(8, document.byte_column(0, 5));
+    assert_eq!(8, document.byte_column(0, 50));
+    assert_eq!(5, document.byte_column(1, 5));
+    assert_eq!(5, document.utf16_column(1, 5));
+}
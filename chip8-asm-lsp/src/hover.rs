@@ -0,0 +1,180 @@
+use crate::document::Document;
+
+use chip8_assembler::StatementType;
+use chip8_instructions::*;
+
+/// Build the markdown shown when hovering over a word in the document.
+pub fn hover_text(document: &Document, line_num: u16, column: u32) -> Option<String> {
+    if let Some(name) = document.label_at(line_num, column) {
+        return Some(match document.label_address(name) {
+            Some(addr) => format!("label `{}` at `0x{:03X}`", name, addr),
+            None => format!("label `{}`", name),
+        });
+    }
+
+    let word = document.word_at(line_num, column)?;
+    if word.index != 0 {
+        return None;
+    }
+    let statement = document.instruction_statement(line_num)?;
+    let StatementType::Instruction { instruction } = statement.statement_type else {
+        return None;
+    };
+    let (byte1, byte2) = encode_instruction(instruction);
+    Some(format!(
+        "```\n{}\n```\nEncoding `{:02X}{:02X}` at `0x{:03X}`\n\n{}",
+        syntax(instruction),
+        byte1,
+        byte2,
+        statement.mem_addr,
+        effect(instruction)
+    ))
+}
+
+/// @return the generic form of the instruction, e.g. `LD Vx, byte (6xkk)`.
+fn syntax(instruction: Instruction) -> &'static str {
+    match instruction {
+        Instruction::Unknown { .. } => "unknown",
+        Instruction::ClearDisplay => "CLS (00E0)",
+        Instruction::Return => "RET (00EE)",
+        Instruction::Exit => "EXIT (00FD)",
+        Instruction::Jump { .. } => "JP addr (1nnn)",
+        Instruction::Call { .. } => "CALL addr (2nnn)",
+        Instruction::SkipRegEqualsImm { .. } => "SE Vx, byte (3xkk)",
+        Instruction::SkipRegNotEqualsImm { .. } => "SNE Vx, byte (4xkk)",
+        Instruction::SkipRegEqualsReg { .. } => "SE Vx, Vy (5xy0)",
+        Instruction::LoadImmToReg { .. } => "LD Vx, byte (6xkk)",
+        Instruction::AddImmToReg { .. } => "ADD Vx, byte (7xkk)",
+        Instruction::LoadRegToReg { .. } => "LD Vx, Vy (8xy0)",
+        Instruction::OrReg { .. } => "OR Vx, Vy (8xy1)",
+        Instruction::AndReg { .. } => "AND Vx, Vy (8xy2)",
+        Instruction::XorReg { .. } => "XOR Vx, Vy (8xy3)",
+        Instruction::AddReg { .. } => "ADD Vx, Vy (8xy4)",
+        Instruction::SubReg { .. } => "SUB Vx, Vy (8xy5)",
+        Instruction::ShiftRight { .. } => "SHR Vx {, Vy} (8xy6)",
+        Instruction::SubNegReg { .. } => "SUBN Vx, Vy (8xy7)",
+        Instruction::ShiftLeft { .. } => "SHL Vx {, Vy} (8xyE)",
+        Instruction::SkipRegNotEqualsReg { .. } => "SNE Vx, Vy (9xy0)",
+        Instruction::LoadImmToPointer { .. } => "LD I, addr (Annn)",
+        Instruction::JumpOffset { .. } => "JP V0, addr (Bnnn)",
+        Instruction::Random { .. } => "RND Vx, byte (Cxkk)",
+        Instruction::Draw { .. } => "DRW Vx, Vy, nibble (Dxyn)",
+        Instruction::SkipKeyPressed { .. } => "SKP Vx (Ex9E)",
+        Instruction::SkipNotKeyPressed { .. } => "SKNP Vx (ExA1)",
+        Instruction::LoadDelayTimerToReg { .. } => "LD Vx, DT (Fx07)",
+        Instruction::LoadNextKeyPress { .. } => "LD Vx, K (Fx0A)",
+        Instruction::LoadRegToDelayTimer { .. } => "LD DT, Vx (Fx15)",
+        Instruction::LoadRegToSoundTimer { .. } => "LD ST, Vx (Fx18)",
+        Instruction::AddRegToPointer { .. } => "ADD I, Vx (Fx1E)",
+        Instruction::LoadDigitSpriteToPointer { .. } => "LD F, Vx (Fx29)",
+        Instruction::LoadDecimalDigitsToPointer { .. } => "LD B, Vx (Fx33)",
+        Instruction::WriteRegToPointer { .. } => "LD [I], Vx (Fx55)",
+        Instruction::ReadRegFromPointer { .. } => "LD Vx, [I] (Fx65)",
+    }
+}
+
+/// @return a description of what the instruction does with its actual operands.
+fn effect(instruction: Instruction) -> String {
+    match instruction {
+        Instruction::Unknown { byte1, byte2 } => {
+            format!("Unknown instruction `{:02X}{:02X}`.", byte1, byte2)
+        }
+        Instruction::ClearDisplay => "Clear the display.".to_string(),
+        Instruction::Return => "Return from a subroutine.".to_string(),
+        Instruction::Exit => "Stop the interpreter.".to_string(),
+        Instruction::Jump { addr } => format!("Jump to `0x{:03X}`.", addr),
+        Instruction::Call { addr } => format!("Call the subroutine at `0x{:03X}`.", addr),
+        Instruction::SkipRegEqualsImm { x, byte } => {
+            format!("Skip the next instruction if V{:X} == {}.", x, byte)
+        }
+        Instruction::SkipRegNotEqualsImm { x, byte } => {
+            format!("Skip the next instruction if V{:X} != {}.", x, byte)
+        }
+        Instruction::SkipRegEqualsReg { x, y } => {
+            format!("Skip the next instruction if V{:X} == V{:X}.", x, y)
+        }
+        Instruction::LoadImmToReg { x, byte } => format!("Set V{:X} = {}.", x, byte),
+        Instruction::AddImmToReg { x, byte } => {
+            format!("Set V{:X} = V{:X} + {}. VF is not changed.", x, x, byte)
+        }
+        Instruction::LoadRegToReg { x, y } => format!("Set V{:X} = V{:X}.", x, y),
+        Instruction::OrReg { x, y } => format!("Set V{:X} = V{:X} | V{:X}.", x, x, y),
+        Instruction::AndReg { x, y } => format!("Set V{:X} = V{:X} & V{:X}.", x, x, y),
+        Instruction::XorReg { x, y } => format!("Set V{:X} = V{:X} ^ V{:X}.", x, x, y),
+        Instruction::AddReg { x, y } => {
+            format!("Set V{:X} = V{:X} + V{:X}. VF = carry.", x, x, y)
+        }
+        Instruction::SubReg { x, y } => {
+            format!("Set V{:X} = V{:X} - V{:X}. VF = NOT borrow.", x, x, y)
+        }
+        Instruction::ShiftRight { x, y } if x == y => {
+            format!("Set V{:X} = V{:X} >> 1. VF = the shifted out bit.", x, x)
+        }
+        Instruction::ShiftRight { x, y } => format!(
+            "Set V{:X} = V{:X} >> 1, or V{:X} >> 1 with the shift quirk. VF = the shifted out bit.",
+            x, x, y
+        ),
+        Instruction::SubNegReg { x, y } => {
+            format!("Set V{:X} = V{:X} - V{:X}. VF = NOT borrow.", x, y, x)
+        }
+        Instruction::ShiftLeft { x, y } if x == y => {
+            format!("Set V{:X} = V{:X} << 1. VF = the shifted out bit.", x, x)
+        }
+        Instruction::ShiftLeft { x, y } => format!(
+            "Set V{:X} = V{:X} << 1, or V{:X} << 1 with the shift quirk. VF = the shifted out bit.",
+            x, x, y
+        ),
+        Instruction::SkipRegNotEqualsReg { x, y } => {
+            format!("Skip the next instruction if V{:X} != V{:X}.", x, y)
+        }
+        Instruction::LoadImmToPointer { addr } => format!("Set I = `0x{:03X}`.", addr),
+        Instruction::JumpOffset { addr } if addr >> 8 == 0 => {
+            format!("Jump to V0 + `0x{:03X}`.", addr)
+        }
+        Instruction::JumpOffset { addr } => format!(
+            "Jump to V0 + `0x{:03X}`, or V{:X} + `0x{:03X}` with the jump quirk.",
+            addr,
+            addr >> 8,
+            addr
+        ),
+        Instruction::Random { x, byte } => {
+            format!("Set V{:X} = a random byte & `0x{:02X}`.", x, byte)
+        }
+        Instruction::Draw { x, y, nibble } => format!(
+            "Draw the {} byte sprite at I to (V{:X}, V{:X}). VF = collision.",
+            nibble, x, y
+        ),
+        Instruction::SkipKeyPressed { x } => {
+            format!(
+                "Skip the next instruction if the key in V{:X} is pressed.",
+                x
+            )
+        }
+        Instruction::SkipNotKeyPressed { x } => {
+            format!(
+                "Skip the next instruction if the key in V{:X} is not pressed.",
+                x
+            )
+        }
+        Instruction::LoadDelayTimerToReg { x } => format!("Set V{:X} = DT.", x),
+        Instruction::LoadNextKeyPress { x } => {
+            format!("Wait for a key press and store the key in V{:X}.", x)
+        }
+        Instruction::LoadRegToDelayTimer { x } => format!("Set DT = V{:X}.", x),
+        Instruction::LoadRegToSoundTimer { x } => format!("Set ST = V{:X}.", x),
+        Instruction::AddRegToPointer { x } => format!("Set I = I + V{:X}.", x),
+        Instruction::LoadDigitSpriteToPointer { x } => {
+            format!("Set I to the digit sprite for the value of V{:X}.", x)
+        }
+        Instruction::LoadDecimalDigitsToPointer { x } => format!(
+            "Store the decimal digits of V{:X} in memory at I, I+1 and I+2.",
+            x
+        ),
+        Instruction::WriteRegToPointer { x } => {
+            format!("Store V0 through V{:X} in memory starting at I.", x)
+        }
+        Instruction::ReadRegFromPointer { x } => {
+            format!("Read V0 through V{:X} from memory starting at I.", x)
+        }
+    }
+}
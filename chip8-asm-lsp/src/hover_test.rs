@@ -0,0 +1,56 @@
+use crate::document::Document;
+use crate::hover::*;
+
+#[test]
+fn test_hover_label() {
+    let document = Document::new("main:\n  JP main\n".to_string());
+    assert_eq!(
+        Some("label `main` at `0x200`".to_string()),
+        hover_text(&document, 1, 6)
+    );
+}
+
+#[test]
+fn test_hover_label_without_address() {
+    let document = Document::new("main:\n  JP main\n  BAD\n".to_string());
+    assert_eq!(
+        Some("label `main`".to_string()),
+        hover_text(&document, 0, 1)
+    );
+}
+
+#[test]
+fn test_hover_instruction() {
+    let document = Document::new("  CLS\n  LD V1, 0x2A\n".to_string());
+    assert_eq!(
+        Some(
+            "```\nLD Vx, byte (6xkk)\n```\nEncoding `612A` at `0x202`\n\nSet V1 = 42.".to_string()
+        ),
+        hover_text(&document, 1, 3)
+    );
+}
+
+#[test]
+fn test_hover_jump_offset() {
+    let document = Document::new("  JP V0, 0x0A0\n  JP V0, 0x3A0\n".to_string());
+    let effect = |line_num| {
+        hover_text(&document, line_num, 3)
+            .unwrap()
+            .rsplit("\n\n")
+            .next()
+            .unwrap()
+            .to_string()
+    };
+    assert_eq!("Jump to V0 + `0x0A0`.", effect(0));
+    assert_eq!(
+        "Jump to V0 + `0x3A0`, or V3 + `0x3A0` with the jump quirk.",
+        effect(1)
+    );
+}
+
+#[test]
+fn test_no_hover_on_operands() {
+    let document = Document::new("  LD V1, 0x2A\n".to_string());
+    assert_eq!(None, hover_text(&document, 0, 6));
+    assert_eq!(None, hover_text(&document, 3, 0));
+}
@@ -0,0 +1,298 @@
+mod completion;
+mod document;
+mod hover;
+
+#[cfg(test)]
+mod completion_test;
+
+#[cfg(test)]
+mod document_test;
+
+#[cfg(test)]
+mod hover_test;
+
+#[cfg(test)]
+mod server_test;
+
+use document::{Document, Word};
+
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+    Notification as NotificationTrait, PublishDiagnostics,
+};
+use lsp_types::request::{
+    Completion, GotoDefinition, HoverRequest, References, Request as RequestTrait,
+};
+use lsp_types::*;
+use std::collections::HashMap;
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
+    let (connection, io_threads) = Connection::stdio();
+
+    let capabilities = ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        definition_provider: Some(OneOf::Left(true)),
+        references_provider: Some(OneOf::Left(true)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        completion_provider: Some(CompletionOptions::default()),
+        ..Default::default()
+    };
+    connection.initialize(serde_json::to_value(capabilities)?)?;
+
+    let mut server = Server {
+        connection,
+        documents: HashMap::new(),
+    };
+    server.run()?;
+
+    // The writer thread only exits once the connection is dropped.
+    drop(server);
+    io_threads.join()?;
+    Ok(())
+}
+
+struct Server {
+    connection: Connection,
+    documents: HashMap<Uri, Document>,
+}
+
+type ServerResult = Result<(), Box<dyn Error + Send + Sync>>;
+
+impl Server {
+    fn run(&mut self) -> ServerResult {
+        let receiver = self.connection.receiver.clone();
+        for message in &receiver {
+            match message {
+                Message::Request(request) => {
+                    if self.connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+                    self.handle_request(request)?;
+                }
+                Message::Notification(notification) => self.handle_notification(notification)?,
+                Message::Response(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_notification(&mut self, notification: Notification) -> ServerResult {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                if let Some(params) = notification_params::<DidOpenTextDocument>(notification) {
+                    self.update_document(params.text_document.uri, params.text_document.text)?;
+                }
+            }
+            DidChangeTextDocument::METHOD => {
+                if let Some(mut params) = notification_params::<DidChangeTextDocument>(notification)
+                    && let Some(change) = params.content_changes.pop()
+                {
+                    self.update_document(params.text_document.uri, change.text)?;
+                }
+            }
+            DidCloseTextDocument::METHOD => {
+                if let Some(params) = notification_params::<DidCloseTextDocument>(notification) {
+                    self.documents.remove(&params.text_document.uri);
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_request(&mut self, request: Request) -> ServerResult {
+        let response = match request.method.as_str() {
+            GotoDefinition::METHOD => self.answer::<GotoDefinition>(request, |server, params| {
+                server.goto_definition(params.text_document_position_params)
+            }),
+            References::METHOD => {
+                self.answer::<References>(request, |server, params| server.references(params))
+            }
+            HoverRequest::METHOD => self.answer::<HoverRequest>(request, |server, params| {
+                server.hover(params.text_document_position_params)
+            }),
+            Completion::METHOD => self.answer::<Completion>(request, |server, params| {
+                server.completion(params.text_document_position)
+            }),
+            _ => Response::new_err(
+                request.id,
+                ErrorCode::MethodNotFound as i32,
+                format!("Unknown method: {}", request.method),
+            ),
+        };
+        self.connection.sender.send(Message::Response(response))?;
+        Ok(())
+    }
+
+    /// @return the response to a request, or an InvalidParams error if its params do not
+    /// match the request type.
+    fn answer<R: RequestTrait>(
+        &self,
+        request: Request,
+        handler: impl Fn(&Self, R::Params) -> R::Result,
+    ) -> Response {
+        match serde_json::from_value::<R::Params>(request.params) {
+            Ok(params) => Response::new_ok(request.id, handler(self, params)),
+            Err(e) => Response::new_err(
+                request.id,
+                ErrorCode::InvalidParams as i32,
+                format!("Invalid params for {}: {}", R::METHOD, e),
+            ),
+        }
+    }
+
+    fn update_document(&mut self, uri: Uri, text: String) -> ServerResult {
+        let document = Document::new(text);
+        let warnings = document.warnings.iter().map(|warning| Diagnostic {
+            range: make_range(
+                &document,
+                warning.line_num,
+                0,
+                line_length(&document, warning.line_num),
+            ),
+            severity: Some(DiagnosticSeverity::WARNING),
+            code: Some(NumberOrString::String(warning.code.code().to_string())),
+            source: Some("chip8-lint".to_string()),
+            message: warning.message.clone(),
+            ..Default::default()
+        });
+        let diagnostics: Vec<Diagnostic> = document
+            .errors
+            .iter()
+            .map(|error| {
+                let range = match error.src_location {
+                    Some(ref location) => {
+                        let (column, length) = match location.line_location {
+                            Some(ref line_location) => (line_location.column, line_location.length),
+                            None => (0, line_length(&document, location.line_num)),
+                        };
+                        make_range(&document, location.line_num, column, length)
+                    }
+                    None => make_range(&document, 0, 0, 0),
+                };
+                Diagnostic {
+                    range,
+                    severity: Some(DiagnosticSeverity::ERROR),
+                    source: Some("chip8-asm".to_string()),
+                    message: error.message.clone(),
+                    ..Default::default()
+                }
+            })
+            .chain(warnings)
+            .collect();
+
+        let params = PublishDiagnosticsParams::new(uri.clone(), diagnostics, None);
+        let notification = Notification::new(PublishDiagnostics::METHOD.to_string(), params);
+        self.connection
+            .sender
+            .send(Message::Notification(notification))?;
+        self.documents.insert(uri, document);
+        Ok(())
+    }
+
+    fn goto_definition(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> Option<GotoDefinitionResponse> {
+        let (document, line_num, column) = self.document_position(&params)?;
+        let name = document.label_at(line_num, column)?;
+        let definition = document.definition(name)?;
+        Some(GotoDefinitionResponse::Scalar(make_location(
+            &params.text_document.uri,
+            document,
+            definition,
+        )))
+    }
+
+    fn references(&self, params: ReferenceParams) -> Option<Vec<Location>> {
+        let uri = &params.text_document_position.text_document.uri;
+        let (document, line_num, column) =
+            self.document_position(&params.text_document_position)?;
+        let name = document.label_at(line_num, column)?;
+        let definition = document
+            .definition(name)
+            .filter(|_| params.context.include_declaration);
+        Some(
+            definition
+                .into_iter()
+                .chain(document.references(name))
+                .map(|word| make_location(uri, document, word))
+                .collect(),
+        )
+    }
+
+    fn hover(&self, params: TextDocumentPositionParams) -> Option<Hover> {
+        let (document, line_num, column) = self.document_position(&params)?;
+        let text = hover::hover_text(document, line_num, column)?;
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: text,
+            }),
+            range: None,
+        })
+    }
+
+    fn completion(&self, params: TextDocumentPositionParams) -> Option<CompletionResponse> {
+        let (document, line_num, column) = self.document_position(&params)?;
+        let word_index = document.word_index_at(line_num, column);
+        Some(CompletionResponse::Array(completion::completions(
+            document, word_index,
+        )))
+    }
+
+    /// @return the document and the position in it, with the column converted from UTF-16
+    /// code units to bytes.
+    fn document_position(
+        &self,
+        params: &TextDocumentPositionParams,
+    ) -> Option<(&Document, u16, u32)> {
+        let document = self.documents.get(&params.text_document.uri)?;
+        let line_num = params.position.line as u16;
+        Some((
+            document,
+            line_num,
+            document.byte_column(line_num, params.position.character),
+        ))
+    }
+}
+
+/// @return the params of a notification, or None after logging them if they do not match
+/// the notification type.
+fn notification_params<N: NotificationTrait>(notification: Notification) -> Option<N::Params> {
+    match serde_json::from_value(notification.params) {
+        Ok(params) => Some(params),
+        Err(e) => {
+            eprintln!("Invalid params for {}: {}", N::METHOD, e);
+            None
+        }
+    }
+}
+
+fn line_length(document: &Document, line_num: u16) -> u32 {
+    document
+        .text
+        .lines()
+        .nth(line_num as usize)
+        .map_or(0, |line| line.len() as u32)
+}
+
+/// @return the LSP range of a span of a line given in bytes.
+fn make_range(document: &Document, line_num: u16, column: u32, length: u32) -> Range {
+    Range::new(
+        Position::new(line_num as u32, document.utf16_column(line_num, column)),
+        Position::new(
+            line_num as u32,
+            document.utf16_column(line_num, column + length),
+        ),
+    )
+}
+
+fn make_location(uri: &Uri, document: &Document, word: &Word) -> Location {
+    Location::new(
+        uri.clone(),
+        make_range(document, word.line_num, word.column, word.length),
+    )
+}
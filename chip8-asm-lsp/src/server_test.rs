@@ -0,0 +1,131 @@
+use crate::Server;
+
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, RequestId, Response};
+use lsp_types::*;
+use serde_json::json;
+use std::collections::HashMap;
+
+const URI: &str = "file:///game.asm";
+
+/// @return a server, and the client end of its connection.
+fn new_server() -> (Server, Connection) {
+    let (connection, client) = Connection::memory();
+    let server = Server {
+        connection,
+        documents: HashMap::new(),
+    };
+    (server, client)
+}
+
+fn open(server: &mut Server, client: &Connection, text: &str) {
+    let notification = Notification::new(
+        "textDocument/didOpen".to_string(),
+        json!({
+            "textDocument": { "uri": URI, "languageId": "chip8", "version": 1, "text": text }
+        }),
+    );
+    server.handle_notification(notification).unwrap();
+    // Diagnostics are published on every change.
+    client.receiver.try_recv().unwrap();
+}
+
+fn request(
+    server: &mut Server,
+    client: &Connection,
+    method: &str,
+    params: serde_json::Value,
+) -> Response {
+    let request = Request::new(RequestId::from(1), method.to_string(), params);
+    server.handle_request(request).unwrap();
+    match client.receiver.try_recv().unwrap() {
+        Message::Response(response) => response,
+        message => panic!("Expected a response, found {:?}", message),
+    }
+}
+
+fn position_params(line: u32, character: u32) -> serde_json::Value {
+    json!({
+        "textDocument": { "uri": URI },
+        "position": { "line": line, "character": character }
+    })
+}
+
+#[test]
+fn test_definition() {
+    let (mut server, client) = new_server();
+    open(&mut server, &client, "main:\n  JP main\n");
+    let response = request(
+        &mut server,
+        &client,
+        "textDocument/definition",
+        position_params(1, 6),
+    );
+    let location: Location = serde_json::from_value(response.result.unwrap()).unwrap();
+    assert_eq!(
+        Range::new(Position::new(0, 0), Position::new(0, 4)),
+        location.range
+    );
+}
+
+#[test]
+fn test_positions_are_utf16() {
+    let (mut server, client) = new_server();
+    // "ñ" is 2 bytes in UTF-8 but 1 UTF-16 code unit.
+    open(&mut server, &client, "ñame:\n  JP ñame\n");
+    let response = request(
+        &mut server,
+        &client,
+        "textDocument/references",
+        json!({
+            "textDocument": { "uri": URI },
+            "position": { "line": 1, "character": 8 },
+            "context": { "includeDeclaration": true }
+        }),
+    );
+    let locations: Vec<Location> = serde_json::from_value(response.result.unwrap()).unwrap();
+    assert_eq!(
+        vec![
+            Range::new(Position::new(0, 0), Position::new(0, 4)),
+            Range::new(Position::new(1, 5), Position::new(1, 9)),
+        ],
+        locations
+            .into_iter()
+            .map(|location| location.range)
+            .collect::<Vec<Range>>()
+    );
+}
+
+#[test]
+fn test_invalid_params() {
+    let (mut server, client) = new_server();
+    let response = request(
+        &mut server,
+        &client,
+        "textDocument/hover",
+        json!({ "position": "nowhere" }),
+    );
+    assert!(response.result.is_none());
+    assert_eq!(
+        ErrorCode::InvalidParams as i32,
+        response.error.unwrap().code
+    );
+}
+
+#[test]
+fn test_unknown_method() {
+    let (mut server, client) = new_server();
+    let response = request(&mut server, &client, "textDocument/frobnicate", json!({}));
+    assert_eq!(
+        ErrorCode::MethodNotFound as i32,
+        response.error.unwrap().code
+    );
+}
+
+#[test]
+fn test_invalid_notification_is_skipped() {
+    let (mut server, client) = new_server();
+    let notification = Notification::new("textDocument/didOpen".to_string(), json!(42));
+    assert!(server.handle_notification(notification).is_ok());
+    assert!(client.receiver.try_recv().is_err());
+    assert!(server.documents.is_empty());
+}
@@ -1,4 +1,4 @@
-use chip8_assembler::assemble;
+use chip8_assembler::{ControlFlowGraph, assemble_statements, lint, source_map_file, symbol_file};
 
 use std::env;
 use std::fs;
@@ -21,6 +21,15 @@ fn main() {
             }
         })
         .expect("Expected output file path arg: -o={FILE_PATH}");
+    let symbols_filepath = args
+        .iter()
+        .find_map(|arg| arg.strip_prefix("-s=").map(str::to_string));
+    let source_map_filepath = args
+        .iter()
+        .find_map(|arg| arg.strip_prefix("-m=").map(str::to_string));
+    let graph_filepath = args
+        .iter()
+        .find_map(|arg| arg.strip_prefix("-g=").map(str::to_string));
     if !fs::metadata(&output_filepath).is_err() {
         println!("Output file already exists: {}", output_filepath);
         return;
@@ -32,9 +41,9 @@ fn main() {
         return;
     }
     let assembly_text = assembly_result.unwrap();
-    let program_result = assemble(&assembly_text, MEM_ADDR_START, MEM_ADDR_MAX);
+    let statements_result = assemble_statements(&assembly_text, MEM_ADDR_START, MEM_ADDR_MAX);
 
-    if let Err(ref errors) = program_result {
+    if let Err(ref errors) = statements_result {
         println!("Error(s) assembling {}:", assembly_filepath);
         for error in errors {
             println!("  {:?}", error);
@@ -42,12 +51,52 @@ fn main() {
         return;
     }
 
-    let program = program_result.unwrap();
-    let write_result = fs::write(output_filepath, program);
+    let statements = statements_result.unwrap();
+    for warning in lint(&assembly_text, &statements) {
+        println!(
+            "Warning {} on line {}: {}",
+            warning.code.code(),
+            warning.line_num + 1,
+            warning.message
+        );
+    }
+
+    let program: Vec<u8> = statements
+        .iter()
+        .flat_map(|statement| statement.bytes())
+        .collect();
+    let write_result = fs::write(output_filepath, &program);
     if let Err(e) = write_result {
         println!("Error writing to output file: {}", e);
         return;
     }
+
+    // Optional symbol file for the profiler and other tools: -s={FILE_PATH}
+    if let Some(symbols_filepath) = symbols_filepath
+        && let Err(e) = fs::write(symbols_filepath, symbol_file(&statements))
+    {
+        println!("Error writing to symbol file: {}", e);
+    }
+
+    // Optional source map for coverage reports: -m={FILE_PATH}
+    if let Some(source_map_filepath) = source_map_filepath
+        && let Err(e) = fs::write(
+            source_map_filepath,
+            source_map_file(assembly_filepath, &statements),
+        )
+    {
+        println!("Error writing to source map file: {}", e);
+    }
+
+    // Optional Graphviz control-flow graph: -g={FILE_PATH}
+    if let Some(graph_filepath) = graph_filepath
+        && let Err(e) = fs::write(
+            graph_filepath,
+            ControlFlowGraph::new(&program, MEM_ADDR_START).to_dot(),
+        )
+    {
+        println!("Error writing to graph file: {}", e);
+    }
 }
 
 const MEM_ADDR_START: u16 = 0x200;
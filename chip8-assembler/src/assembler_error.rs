@@ -19,6 +19,14 @@ impl AssemblerError {
     pub fn new_message(message: String) -> Self {
         Self::new(message, None)
     }
+
+    /// Attach the line number to an error that was raised without a source location.
+    pub fn or_line_num(self, line_num: u16) -> Self {
+        match self.src_location {
+            Some(_) => self,
+            None => Self::new_no_options(self.message, Location::new_line_num(line_num)),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -11,3 +11,16 @@ fn test_line_location_from_line_words() {
     assert_eq!(17, line_location.column);
     assert_eq!(4, line_location.length);
 }
+
+#[test]
+fn test_or_line_num_fills_missing_location() {
+    let error = AssemblerError::new_message("oops".to_string()).or_line_num(7);
+    assert_eq!(7, error.src_location.unwrap().line_num);
+}
+
+#[test]
+fn test_or_line_num_keeps_existing_location() {
+    let error = AssemblerError::new_no_options("oops".to_string(), Location::new_line_num(3))
+        .or_line_num(7);
+    assert_eq!(3, error.src_location.unwrap().line_num);
+}
@@ -0,0 +1,209 @@
+use crate::assembler_error::*;
+use crate::parse::get_line_words;
+
+/// A line of assembly after the control flow pseudo-instructions have been expanded.
+/// Generated lines keep the line number of the pseudo-instruction they came from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceLine {
+    pub line_num: u16,
+    pub line: String,
+}
+
+impl SourceLine {
+    pub fn new(line_num: u16, line: String) -> Self {
+        Self { line_num, line }
+    }
+}
+
+/// Prefix of the labels generated for control flow blocks.
+pub const HIDDEN_LABEL_PREFIX: &str = "__";
+
+enum Block {
+    If {
+        id: u32,
+        line_num: u16,
+        has_else: bool,
+    },
+    Loop {
+        id: u32,
+        line_num: u16,
+    },
+}
+
+/// Expand the structured control flow pseudo-instructions into skips, jumps and hidden labels.
+///
+/// - `IF <cond> THEN` ... [`ELSE` ...] `ENDIF`
+/// - `LOOP` ... `AGAIN` loops forever.
+/// - `WHILE <cond>` ... `AGAIN` loops while the condition holds.
+/// - `BREAK` jumps past the end of the innermost loop.
+///
+/// A condition is `Vx == Vy|imm`, `Vx != Vy|imm`, `Vx KEY` or `Vx -KEY`.
+pub fn expand_control_flow(text: &str) -> Result<Vec<SourceLine>, Vec<AssemblerError>> {
+    let mut lines: Vec<SourceLine> = Vec::new();
+    let mut errors: Vec<AssemblerError> = Vec::new();
+    let mut blocks: Vec<Block> = Vec::new();
+    let mut next_id: u32 = 0;
+
+    for (line_num, line) in text.lines().enumerate() {
+        let line_num = line_num as u16;
+        let words = get_line_words(line);
+        let keyword = words.first().map(|word| word.to_uppercase());
+        let mut emit = |line: String| lines.push(SourceLine::new(line_num, line));
+
+        match keyword.as_deref() {
+            Some("IF") => {
+                if words.last().map(|word| word.to_uppercase()).as_deref() != Some("THEN") {
+                    errors.push(error("Expected THEN at the end of an IF.", line_num));
+                    continue;
+                }
+                match get_skip_if_true(&words[1..words.len() - 1], line_num) {
+                    Ok(skip) => {
+                        let id = next_id;
+                        next_id += 1;
+                        emit(skip);
+                        emit(format!("JP {}", if_else_label(id)));
+                        blocks.push(Block::If {
+                            id,
+                            line_num,
+                            has_else: false,
+                        });
+                    }
+                    Err(err) => errors.push(err),
+                }
+            }
+            Some("ELSE") => match blocks.last_mut() {
+                Some(Block::If { has_else: true, .. }) => {
+                    errors.push(error("IF block already has an ELSE.", line_num));
+                }
+                Some(Block::If { id, has_else, .. }) => {
+                    *has_else = true;
+                    emit(format!("JP {}", if_end_label(*id)));
+                    emit(format!("{}:", if_else_label(*id)));
+                }
+                _ => errors.push(error("ELSE without a matching IF.", line_num)),
+            },
+            Some("ENDIF") => match blocks.last() {
+                Some(Block::If { id, has_else, .. }) => {
+                    if !has_else {
+                        emit(format!("{}:", if_else_label(*id)));
+                    }
+                    emit(format!("{}:", if_end_label(*id)));
+                    blocks.pop();
+                }
+                _ => errors.push(error("ENDIF without a matching IF.", line_num)),
+            },
+            Some("LOOP") | Some("WHILE") => {
+                let condition_skip = if keyword.as_deref() == Some("WHILE") {
+                    match get_skip_if_true(&words[1..], line_num) {
+                        Ok(skip) => Some(skip),
+                        Err(err) => {
+                            errors.push(err);
+                            continue;
+                        }
+                    }
+                } else if words.len() > 1 {
+                    errors.push(error("LOOP does not take any arguments.", line_num));
+                    continue;
+                } else {
+                    None
+                };
+
+                let id = next_id;
+                next_id += 1;
+                emit(format!("{}:", loop_start_label(id)));
+                if let Some(skip) = condition_skip {
+                    emit(skip);
+                    emit(format!("JP {}", loop_end_label(id)));
+                }
+                blocks.push(Block::Loop { id, line_num });
+            }
+            Some("AGAIN") => match blocks.last() {
+                Some(Block::Loop { id, .. }) => {
+                    emit(format!("JP {}", loop_start_label(*id)));
+                    emit(format!("{}:", loop_end_label(*id)));
+                    blocks.pop();
+                }
+                _ => errors.push(error("AGAIN without a matching LOOP or WHILE.", line_num)),
+            },
+            Some("BREAK") => {
+                let innermost_loop = blocks.iter().rev().find_map(|block| match block {
+                    Block::Loop { id, .. } => Some(*id),
+                    Block::If { .. } => None,
+                });
+                match innermost_loop {
+                    Some(id) => emit(format!("JP {}", loop_end_label(id))),
+                    None => errors.push(error("BREAK outside of a LOOP or WHILE.", line_num)),
+                }
+            }
+            _ if is_hidden_label(&words) => errors.push(error(
+                format!(
+                    "Labels starting with {} are reserved for control flow.",
+                    HIDDEN_LABEL_PREFIX
+                )
+                .as_str(),
+                line_num,
+            )),
+            _ => emit(line.to_string()),
+        }
+    }
+
+    for block in blocks.iter() {
+        errors.push(match block {
+            Block::If { line_num, .. } => error("IF without a matching ENDIF.", *line_num),
+            Block::Loop { line_num, .. } => {
+                error("LOOP or WHILE without a matching AGAIN.", *line_num)
+            }
+        });
+    }
+
+    if errors.is_empty() {
+        Ok(lines)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Get the skip instruction that skips the following line when the condition is true.
+fn get_skip_if_true(condition: &[&str], line_num: u16) -> Result<String, AssemblerError> {
+    match condition {
+        [x, op, operand] => match *op {
+            "==" => Ok(format!("SE {} {}", x, operand)),
+            "!=" => Ok(format!("SNE {} {}", x, operand)),
+            _ => Err(error(
+                format!("Unknown condition operator: {}", op).as_str(),
+                line_num,
+            )),
+        },
+        [x, key] if key.to_uppercase() == "KEY" => Ok(format!("SKP {}", x)),
+        [x, key] if key.to_uppercase() == "-KEY" => Ok(format!("SKNP {}", x)),
+        _ => Err(error(
+            "Expected a condition of the form `Vx == y`, `Vx != y`, `Vx KEY` or `Vx -KEY`.",
+            line_num,
+        )),
+    }
+}
+
+/// @return true if the line defines a label that could collide with the generated ones.
+fn is_hidden_label(words: &[&str]) -> bool {
+    matches!(words, [word] if word.ends_with(':') && word.starts_with(HIDDEN_LABEL_PREFIX))
+}
+
+fn error(message: &str, line_num: u16) -> AssemblerError {
+    AssemblerError::new_no_options(message.to_string(), Location::new_line_num(line_num))
+}
+
+fn if_else_label(id: u32) -> String {
+    format!("{}if{}_else", HIDDEN_LABEL_PREFIX, id)
+}
+
+fn if_end_label(id: u32) -> String {
+    format!("{}if{}_end", HIDDEN_LABEL_PREFIX, id)
+}
+
+fn loop_start_label(id: u32) -> String {
+    format!("{}loop{}_start", HIDDEN_LABEL_PREFIX, id)
+}
+
+fn loop_end_label(id: u32) -> String {
+    format!("{}loop{}_end", HIDDEN_LABEL_PREFIX, id)
+}
@@ -0,0 +1,204 @@
+use crate::control_flow::*;
+use crate::*;
+
+fn expanded_lines(text: &str) -> Vec<String> {
+    expand_control_flow(text)
+        .unwrap()
+        .into_iter()
+        .map(|source_line| source_line.line.trim().to_string())
+        .collect()
+}
+
+fn first_error_message(text: &str) -> String {
+    let errors = expand_control_flow(text).unwrap_err();
+    errors.first().unwrap().message.clone()
+}
+
+#[test]
+fn test_plain_lines_pass_through() {
+    let lines = expand_control_flow("main:\n  CLS\n").unwrap();
+    assert_eq!(
+        vec![
+            SourceLine::new(0, "main:".to_string()),
+            SourceLine::new(1, "  CLS".to_string()),
+        ],
+        lines
+    );
+}
+
+#[test]
+fn test_if_endif() {
+    let lines = expanded_lines("IF V1 == 0 THEN\n  CLS\nENDIF");
+    assert_eq!(
+        vec![
+            "SE V1 0",
+            "JP __if0_else",
+            "CLS",
+            "__if0_else:",
+            "__if0_end:"
+        ],
+        lines
+    );
+}
+
+#[test]
+fn test_if_else_endif() {
+    let lines = expanded_lines("if v1 != v2 then\n  CLS\nelse\n  RET\nendif");
+    assert_eq!(
+        vec![
+            "SNE v1 v2",
+            "JP __if0_else",
+            "CLS",
+            "JP __if0_end",
+            "__if0_else:",
+            "RET",
+            "__if0_end:"
+        ],
+        lines
+    );
+}
+
+#[test]
+fn test_key_conditions() {
+    let lines = expanded_lines("IF V3 KEY THEN\nENDIF\nIF V4 -KEY THEN\nENDIF");
+    assert_eq!("SKP V3", lines[0]);
+    assert_eq!("SKNP V4", lines[4]);
+}
+
+#[test]
+fn test_loop_again_break() {
+    let lines = expanded_lines("LOOP\n  IF V0 == 5 THEN\n    BREAK\n  ENDIF\n  ADD V0 1\nAGAIN");
+    assert_eq!(
+        vec![
+            "__loop0_start:",
+            "SE V0 5",
+            "JP __if1_else",
+            "JP __loop0_end",
+            "__if1_else:",
+            "__if1_end:",
+            "ADD V0 1",
+            "JP __loop0_start",
+            "__loop0_end:"
+        ],
+        lines
+    );
+}
+
+#[test]
+fn test_while_again() {
+    let lines = expanded_lines("WHILE V0 != 10\n  ADD V0 1\nAGAIN");
+    assert_eq!(
+        vec![
+            "__loop0_start:",
+            "SNE V0 10",
+            "JP __loop0_end",
+            "ADD V0 1",
+            "JP __loop0_start",
+            "__loop0_end:"
+        ],
+        lines
+    );
+}
+
+#[test]
+fn test_generated_lines_keep_line_num() {
+    let lines = expand_control_flow("CLS\nIF V0 == 1 THEN\nENDIF").unwrap();
+    assert_eq!(1, lines[1].line_num);
+    assert_eq!(1, lines[2].line_num);
+    assert_eq!(2, lines[3].line_num);
+}
+
+#[test]
+fn test_unclosed_if() {
+    let errors = expand_control_flow("CLS\nIF V0 == 1 THEN\nCLS").unwrap_err();
+    let error = errors.first().unwrap();
+    assert_eq!("IF without a matching ENDIF.", error.message);
+    assert_eq!(1, error.src_location.as_ref().unwrap().line_num);
+}
+
+#[test]
+fn test_unclosed_loop() {
+    assert_eq!(
+        "LOOP or WHILE without a matching AGAIN.",
+        first_error_message("LOOP\nCLS")
+    );
+}
+
+#[test]
+fn test_unbalanced_blocks() {
+    assert_eq!("ENDIF without a matching IF.", first_error_message("ENDIF"));
+    assert_eq!(
+        "ELSE without a matching IF.",
+        first_error_message("LOOP\nELSE\nAGAIN")
+    );
+    assert_eq!(
+        "AGAIN without a matching LOOP or WHILE.",
+        first_error_message("IF V0 == 0 THEN\nAGAIN\nENDIF")
+    );
+    assert_eq!(
+        "IF block already has an ELSE.",
+        first_error_message("IF V0 == 0 THEN\nELSE\nELSE\nENDIF")
+    );
+}
+
+#[test]
+fn test_break_outside_loop() {
+    assert_eq!(
+        "BREAK outside of a LOOP or WHILE.",
+        first_error_message("IF V0 == 0 THEN\nBREAK\nENDIF")
+    );
+}
+
+#[test]
+fn test_invalid_conditions() {
+    assert_eq!(
+        "Expected THEN at the end of an IF.",
+        first_error_message("IF V0 == 0")
+    );
+    assert_eq!(
+        "Unknown condition operator: <",
+        first_error_message("WHILE V0 < 3\nAGAIN")
+    );
+}
+
+const STRUCTURED_PROGRAM_SRC: &str = "
+  LD V0 0
+  WHILE V0 != 3
+    IF V0 == 1 THEN
+      LD V1 1
+    ELSE
+      LD V1 2
+    ENDIF
+    ADD V0 1
+  AGAIN
+";
+
+#[test]
+fn test_assemble_structured_program() {
+    let result = assemble(STRUCTURED_PROGRAM_SRC, 0x200, 0x1000);
+    assert!(result.is_ok(), "Expected Ok, but got {:?}", result);
+    assert_eq!(
+        vec![
+            0x60, 0x00, // 0x200: LD V0 0
+            0x40, 0x03, // 0x202: SNE V0 3
+            0x12, 0x14, // 0x204: JP 0x214
+            0x30, 0x01, // 0x206: SE V0 1
+            0x12, 0x0E, // 0x208: JP 0x20E
+            0x61, 0x01, // 0x20A: LD V1 1
+            0x12, 0x10, // 0x20C: JP 0x210
+            0x61, 0x02, // 0x20E: LD V1 2
+            0x70, 0x01, // 0x210: ADD V0 1
+            0x12, 0x02, // 0x212: JP 0x202
+        ],
+        result.unwrap()
+    );
+}
+
+#[test]
+fn test_reserved_label_prefix() {
+    assert_eq!(
+        "Labels starting with __ are reserved for control flow.",
+        first_error_message("__if0_end:\nIF V0 == 1 THEN\nENDIF")
+    );
+    assert!(expand_control_flow("my__label:\n  CLS").is_ok());
+}
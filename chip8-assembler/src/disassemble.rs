@@ -0,0 +1,142 @@
+use crate::flow_graph::ControlFlowGraph;
+
+use chip8_instructions::*;
+
+use std::collections::BTreeSet;
+
+/// Disassemble a program into assembly text that assembles back into the same bytes.
+/// Jump, call and pointer targets inside the program are given labels. Bytes that are not
+/// reachable from the start of the program, or that would not encode back into the same
+/// bytes, are written as data rows.
+pub fn disassemble(program: &[u8], mem_addr_start: u16) -> String {
+    disassemble_with_modified_code(program, mem_addr_start, &BTreeSet::new())
+}
+
+/// Disassemble a program as `disassemble` does, marking the lines with bytes in
+/// `modified_code` as code the program wrote over while running.
+pub fn disassemble_with_modified_code(
+    program: &[u8],
+    mem_addr_start: u16,
+    modified_code: &BTreeSet<u16>,
+) -> String {
+    let mem_addr_end = mem_addr_start as usize + program.len();
+    let graph = ControlFlowGraph::new(program, mem_addr_start);
+    let instructions: Vec<(u16, Option<Instruction>)> = program
+        .chunks(2)
+        .enumerate()
+        .map(|(i, bytes)| {
+            let addr = mem_addr_start + 2 * i as u16;
+            match bytes {
+                [byte1, byte2] => match graph.get_instruction(addr) {
+                    None | Some(Instruction::Unknown { .. }) => (addr, None),
+                    // Some encodings have ignored bits which would be lost by reassembling.
+                    Some(instruction) if encode_instruction(instruction) != (*byte1, *byte2) => {
+                        (addr, None)
+                    }
+                    instruction => (addr, instruction),
+                },
+                _ => (addr, None),
+            }
+        })
+        .collect();
+
+    let labels: BTreeSet<u16> = instructions
+        .iter()
+        .filter_map(|(_, instruction)| instruction.and_then(target_addr))
+        .filter(|addr| {
+            (mem_addr_start as usize..mem_addr_end).contains(&(*addr as usize))
+                && (addr - mem_addr_start).is_multiple_of(2)
+        })
+        .collect();
+
+    let mut text = String::new();
+    for (addr, instruction) in instructions.iter() {
+        if labels.contains(addr) {
+            text += format!("{}:\n", label_name(*addr)).as_str();
+        }
+        let offset = (addr - mem_addr_start) as usize;
+        let line = match instruction {
+            Some(instruction) => instruction_to_assembly(*instruction, &labels),
+            None => program[offset..(offset + 2).min(program.len())]
+                .iter()
+                .map(|byte| format!("0x{:02X}", byte))
+                .collect::<Vec<String>>()
+                .join(" "),
+        };
+        let size = (program.len() - offset).min(2) as u16;
+        let note = if (*addr..addr + size).any(|addr| modified_code.contains(&addr)) {
+            " modified"
+        } else {
+            ""
+        };
+        text += format!("  {:<24}; 0x{:03X}{}\n", line, addr, note).as_str();
+    }
+    text
+}
+
+/// @return the address an instruction jumps to, calls or points I at.
+fn target_addr(instruction: Instruction) -> Option<u16> {
+    match instruction {
+        Instruction::Jump { addr }
+        | Instruction::Call { addr }
+        | Instruction::LoadImmToPointer { addr }
+        | Instruction::JumpOffset { addr } => Some(addr),
+        _ => None,
+    }
+}
+
+pub fn label_name(addr: u16) -> String {
+    format!("L{:03X}", addr)
+}
+
+/// Write an instruction in the syntax accepted by the assembler. Addresses in `labels` are
+/// written as label names.
+pub fn instruction_to_assembly(instruction: Instruction, labels: &BTreeSet<u16>) -> String {
+    let addr_operand = |addr: u16| {
+        if labels.contains(&addr) {
+            label_name(addr)
+        } else {
+            format!("0x{:03X}", addr)
+        }
+    };
+    match instruction {
+        Instruction::Unknown { byte1, byte2 } => format!("0x{:02X} 0x{:02X}", byte1, byte2),
+        Instruction::ClearDisplay => "CLS".to_string(),
+        Instruction::Return => "RET".to_string(),
+        Instruction::Exit => "EXIT".to_string(),
+        Instruction::Jump { addr } => format!("JP {}", addr_operand(addr)),
+        Instruction::Call { addr } => format!("CALL {}", addr_operand(addr)),
+        Instruction::SkipRegEqualsImm { x, byte } => format!("SE V{:X}, 0x{:02X}", x, byte),
+        Instruction::SkipRegNotEqualsImm { x, byte } => format!("SNE V{:X}, 0x{:02X}", x, byte),
+        Instruction::SkipRegEqualsReg { x, y } => format!("SE V{:X}, V{:X}", x, y),
+        Instruction::LoadImmToReg { x, byte } => format!("LD V{:X}, 0x{:02X}", x, byte),
+        Instruction::AddImmToReg { x, byte } => format!("ADD V{:X}, 0x{:02X}", x, byte),
+        Instruction::LoadRegToReg { x, y } => format!("LD V{:X}, V{:X}", x, y),
+        Instruction::OrReg { x, y } => format!("OR V{:X}, V{:X}", x, y),
+        Instruction::AndReg { x, y } => format!("AND V{:X}, V{:X}", x, y),
+        Instruction::XorReg { x, y } => format!("XOR V{:X}, V{:X}", x, y),
+        Instruction::AddReg { x, y } => format!("ADD V{:X}, V{:X}", x, y),
+        Instruction::SubReg { x, y } => format!("SUB V{:X}, V{:X}", x, y),
+        Instruction::ShiftRight { x, y } if x == y => format!("SHR V{:X}", x),
+        Instruction::ShiftRight { x, y } => format!("SHR V{:X}, V{:X}", x, y),
+        Instruction::SubNegReg { x, y } => format!("SUBN V{:X}, V{:X}", x, y),
+        Instruction::ShiftLeft { x, y } if x == y => format!("SHL V{:X}", x),
+        Instruction::ShiftLeft { x, y } => format!("SHL V{:X}, V{:X}", x, y),
+        Instruction::SkipRegNotEqualsReg { x, y } => format!("SNE V{:X}, V{:X}", x, y),
+        Instruction::LoadImmToPointer { addr } => format!("LD I, {}", addr_operand(addr)),
+        Instruction::JumpOffset { addr } => format!("JP V0, {}", addr_operand(addr)),
+        Instruction::Random { x, byte } => format!("RND V{:X}, 0x{:02X}", x, byte),
+        Instruction::Draw { x, y, nibble } => format!("DRW V{:X}, V{:X}, {}", x, y, nibble),
+        Instruction::SkipKeyPressed { x } => format!("SKP V{:X}", x),
+        Instruction::SkipNotKeyPressed { x } => format!("SKNP V{:X}", x),
+        Instruction::LoadDelayTimerToReg { x } => format!("LD V{:X}, DT", x),
+        Instruction::LoadNextKeyPress { x } => format!("LD V{:X}, K", x),
+        Instruction::LoadRegToDelayTimer { x } => format!("LD DT, V{:X}", x),
+        Instruction::LoadRegToSoundTimer { x } => format!("LD ST, V{:X}", x),
+        Instruction::AddRegToPointer { x } => format!("ADD I, V{:X}", x),
+        Instruction::LoadDigitSpriteToPointer { x } => format!("LD F, V{:X}", x),
+        Instruction::LoadDecimalDigitsToPointer { x } => format!("LD B, V{:X}", x),
+        Instruction::WriteRegToPointer { x } => format!("LD [I], V{:X}", x),
+        Instruction::ReadRegFromPointer { x } => format!("LD V{:X}, [I]", x),
+    }
+}
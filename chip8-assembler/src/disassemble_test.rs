@@ -0,0 +1,99 @@
+use crate::*;
+
+use chip8_instructions::Instruction;
+use std::collections::BTreeSet;
+
+const COUNTER_PROGRAM: [u8; 28] = [
+    0x62, 0x0F, 0xF0, 0x29, 0x12, 0x0E, 0xD8, 0x85, 0x70, 0x01, 0x80, 0x22, 0xF0, 0x29, 0xD8, 0x85,
+    0x61, 0x3C, 0xF1, 0x15, 0xF1, 0x07, 0x41, 0x00, 0x12, 0x06, 0x12, 0x14,
+];
+
+#[test]
+fn test_disassemble_labels_targets() {
+    let text = disassemble(&COUNTER_PROGRAM, 0x200);
+    let lines: Vec<&str> = text.lines().map(|line| line.trim()).collect();
+    assert_eq!("LD V2, 0x0F             ; 0x200", lines[0]);
+    assert_eq!("JP L20E                 ; 0x204", lines[2]);
+    assert_eq!("L206:", lines[3]);
+    assert!(lines.contains(&"L214:"));
+}
+
+#[test]
+fn test_disassemble_round_trip() {
+    let text = disassemble(&COUNTER_PROGRAM, 0x200);
+    let bytes = assemble(&text, 0x200, 0x1000).unwrap();
+    assert_eq!(COUNTER_PROGRAM.to_vec(), bytes);
+}
+
+#[test]
+fn test_disassemble_data() {
+    // Two unknown instructions and a trailing odd byte.
+    let program = [0xFF, 0xFF, 0x51, 0x26, 0xAB];
+    let text = disassemble(&program, 0x200);
+    let lines: Vec<&str> = text.lines().map(|line| line.trim()).collect();
+    assert_eq!("0xFF 0xFF               ; 0x200", lines[0]);
+    assert_eq!("0x51 0x26               ; 0x202", lines[1]);
+    assert_eq!("0xAB                    ; 0x204", lines[2]);
+    assert_eq!(program.to_vec(), assemble(&text, 0x200, 0x1000).unwrap());
+}
+
+#[test]
+fn test_instruction_to_assembly() {
+    let labels = BTreeSet::from([0x300]);
+    assert_eq!(
+        "CALL L300",
+        instruction_to_assembly(Instruction::Call { addr: 0x300 }, &labels)
+    );
+    assert_eq!(
+        "LD I, 0x302",
+        instruction_to_assembly(Instruction::LoadImmToPointer { addr: 0x302 }, &labels)
+    );
+    assert_eq!(
+        "DRW V1, VA, 5",
+        instruction_to_assembly(
+            Instruction::Draw {
+                x: 1,
+                y: 0xA,
+                nibble: 5
+            },
+            &labels
+        )
+    );
+}
+
+#[test]
+fn test_shift_to_assembly() {
+    let labels = BTreeSet::new();
+    assert_eq!(
+        "SHR V1",
+        instruction_to_assembly(Instruction::ShiftRight { x: 1, y: 1 }, &labels)
+    );
+    assert_eq!(
+        "SHL V1, V2",
+        instruction_to_assembly(Instruction::ShiftLeft { x: 1, y: 2 }, &labels)
+    );
+}
+
+#[test]
+fn test_disassemble_unreachable_bytes_as_data() {
+    // A sprite after the main loop, which would otherwise decode as `SE V0, 0x81`.
+    let program = [0xA2, 0x04, 0x12, 0x00, 0x30, 0x81];
+    let text = disassemble(&program, 0x200);
+    let lines: Vec<&str> = text.lines().map(|line| line.trim()).collect();
+    assert_eq!("L200:", lines[0]);
+    assert_eq!("LD I, L204              ; 0x200", lines[1]);
+    assert_eq!("L204:", lines[3]);
+    assert_eq!("0x30 0x81               ; 0x204", lines[4]);
+    assert_eq!(program.to_vec(), assemble(&text, 0x200, 0x1000).unwrap());
+}
+
+#[test]
+fn test_disassemble_marks_modified_code() {
+    // JP 0x204; 0x00 0x00; EXIT
+    let program = [0x12, 0x04, 0x00, 0x00, 0x00, 0xFD];
+    let text = disassemble_with_modified_code(&program, 0x200, &BTreeSet::from([0x205]));
+    let lines: Vec<&str> = text.lines().map(|line| line.trim()).collect();
+    assert_eq!("JP L204                 ; 0x200", lines[0]);
+    assert_eq!("EXIT                    ; 0x204 modified", lines[3]);
+    assert_eq!(program.to_vec(), assemble(&text, 0x200, 0x1000).unwrap());
+}
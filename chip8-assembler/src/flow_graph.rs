@@ -0,0 +1,312 @@
+use crate::disassemble::{instruction_to_assembly, label_name};
+
+use chip8_instructions::*;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Range;
+
+/// The most entries looked for in a `JP V0` table. V0 can reach 128 two byte instructions
+/// past the base address.
+const MAX_JUMP_TABLE_ENTRIES: u16 = 128;
+
+/// A run of instructions that always run in order, entered only at its first instruction
+/// and left only after its last.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BasicBlock {
+    pub start: u16,
+    /// The address after the last instruction.
+    pub end: u16,
+    /// The blocks that can run next. A skip lists the next instruction before the one it
+    /// skips to, and a call lists the instruction it returns to.
+    pub successors: Vec<u16>,
+    /// The subroutine called by the last instruction.
+    pub call: Option<u16>,
+}
+
+impl BasicBlock {
+    /// @return the address of the last instruction.
+    pub fn last_addr(&self) -> u16 {
+        self.end - 2
+    }
+}
+
+/// A `JP V0, addr` and where it can jump to.
+///
+/// Programs jump through a table of `JP` instructions with V0 a multiple of 2, so the
+/// targets are the run of `JP` instructions at the base address. If there are none the
+/// base address is taken as the only target.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JumpTable {
+    /// The address of the `JP V0` instruction.
+    pub addr: u16,
+    pub base: u16,
+    pub targets: Vec<u16>,
+}
+
+/// The control flow of a program, found by following every jump, call, return and skip
+/// from its first instruction without running it.
+///
+/// Unknown instructions are followed by the next instruction, as the interpreter carries on
+/// past them. Code that is only reached by computed jumps the tables cannot find, or by
+/// code the program writes while running, is reported as unreachable.
+#[derive(Debug, Clone)]
+pub struct ControlFlowGraph {
+    program: Vec<u8>,
+    mem_addr_start: u16,
+    /// The reachable instructions by address.
+    instructions: BTreeMap<u16, Instruction>,
+    blocks: BTreeMap<u16, BasicBlock>,
+    subroutines: BTreeSet<u16>,
+    jump_tables: Vec<JumpTable>,
+}
+
+impl ControlFlowGraph {
+    pub fn new(program: &[u8], mem_addr_start: u16) -> Self {
+        let mut graph = Self {
+            program: program.to_vec(),
+            mem_addr_start,
+            instructions: BTreeMap::new(),
+            blocks: BTreeMap::new(),
+            subroutines: BTreeSet::new(),
+            jump_tables: Vec::new(),
+        };
+
+        let mut leaders: BTreeSet<u16> = BTreeSet::new();
+        let mut to_visit: Vec<u16> = Vec::new();
+        if graph.decode(mem_addr_start).is_some() {
+            leaders.insert(mem_addr_start);
+            to_visit.push(mem_addr_start);
+        }
+        while let Some(addr) = to_visit.pop() {
+            if graph.instructions.contains_key(&addr) {
+                continue;
+            }
+            let Some(instruction) = graph.decode(addr) else {
+                continue;
+            };
+            graph.instructions.insert(addr, instruction);
+
+            if let Instruction::JumpOffset { addr: base } = instruction {
+                let targets = graph.jump_table_targets(base);
+                graph.jump_tables.push(JumpTable {
+                    addr,
+                    base,
+                    targets,
+                });
+            }
+            let (successors, ends_block) = graph.flow(addr, instruction);
+            if let Instruction::Call { addr: target } = instruction
+                && graph.decode(target).is_some()
+            {
+                graph.subroutines.insert(target);
+                leaders.insert(target);
+                to_visit.push(target);
+            }
+            if ends_block {
+                leaders.extend(successors.iter());
+            }
+            to_visit.extend(successors);
+        }
+        graph.jump_tables.sort_by_key(|table| table.addr);
+
+        for start in leaders.iter() {
+            let block = graph.build_block(*start, &leaders);
+            graph.blocks.insert(*start, block);
+        }
+        graph
+    }
+
+    /// @return the instruction at an address inside the program, reachable or not.
+    fn decode(&self, addr: u16) -> Option<Instruction> {
+        let offset = addr.checked_sub(self.mem_addr_start)? as usize;
+        match self.program.get(offset..offset + 2)? {
+            [byte1, byte2] => Some(decode_instruction(*byte1, *byte2)),
+            _ => None,
+        }
+    }
+
+    /// @return the instructions that can run after the one at `addr` and whether it ends
+    /// a block.
+    fn flow(&self, addr: u16, instruction: Instruction) -> (Vec<u16>, bool) {
+        let next = addr + 2;
+        let (successors, ends_block) = match instruction {
+            Instruction::Jump { addr } => (vec![addr], true),
+            Instruction::Call { .. } => (vec![next], true),
+            Instruction::Return | Instruction::Exit => (vec![], true),
+            Instruction::JumpOffset { addr: base } => (self.jump_table_targets(base), true),
+            Instruction::SkipRegEqualsImm { .. }
+            | Instruction::SkipRegNotEqualsImm { .. }
+            | Instruction::SkipRegEqualsReg { .. }
+            | Instruction::SkipRegNotEqualsReg { .. }
+            | Instruction::SkipKeyPressed { .. }
+            | Instruction::SkipNotKeyPressed { .. } => (vec![next, next + 2], true),
+            _ => (vec![next], false),
+        };
+        let successors = successors
+            .into_iter()
+            .filter(|addr| self.decode(*addr).is_some())
+            .collect();
+        (successors, ends_block)
+    }
+
+    fn jump_table_targets(&self, base: u16) -> Vec<u16> {
+        let targets: Vec<u16> = (0..MAX_JUMP_TABLE_ENTRIES)
+            .map(|i| base + 2 * i)
+            .take_while(|addr| matches!(self.decode(*addr), Some(Instruction::Jump { .. })))
+            .collect();
+        if targets.is_empty() {
+            vec![base]
+        } else {
+            targets
+        }
+    }
+
+    fn build_block(&self, start: u16, leaders: &BTreeSet<u16>) -> BasicBlock {
+        let mut addr = start;
+        loop {
+            let instruction = self.instructions[&addr];
+            let (successors, ends_block) = self.flow(addr, instruction);
+            let next = addr + 2;
+            if ends_block || leaders.contains(&next) || !self.instructions.contains_key(&next) {
+                let call = match instruction {
+                    Instruction::Call { addr } if self.subroutines.contains(&addr) => Some(addr),
+                    _ => None,
+                };
+                return BasicBlock {
+                    start,
+                    end: next,
+                    successors,
+                    call,
+                };
+            }
+            addr = next;
+        }
+    }
+
+    /// @return the basic blocks in address order.
+    pub fn get_blocks(&self) -> Vec<&BasicBlock> {
+        self.blocks.values().collect()
+    }
+
+    /// @return the block starting at an address.
+    pub fn get_block(&self, addr: u16) -> Option<&BasicBlock> {
+        self.blocks.get(&addr)
+    }
+
+    /// @return the addresses of the subroutines the program calls.
+    pub fn get_subroutines(&self) -> &BTreeSet<u16> {
+        &self.subroutines
+    }
+
+    /// @return the blocks of a subroutine, reached from its first block without following
+    /// its calls.
+    pub fn get_subroutine_blocks(&self, addr: u16) -> BTreeSet<u16> {
+        let mut blocks: BTreeSet<u16> = BTreeSet::new();
+        let mut to_visit = vec![addr];
+        while let Some(start) = to_visit.pop() {
+            let Some(block) = self.blocks.get(&start) else {
+                continue;
+            };
+            if blocks.insert(start) {
+                to_visit.extend(block.successors.iter());
+            }
+        }
+        blocks
+    }
+
+    pub fn get_jump_tables(&self) -> &[JumpTable] {
+        &self.jump_tables
+    }
+
+    /// @return the reachable instruction starting at an address.
+    pub fn get_instruction(&self, addr: u16) -> Option<Instruction> {
+        self.instructions.get(&addr).copied()
+    }
+
+    /// @return true if the byte at an address is part of a reachable instruction.
+    pub fn is_reachable(&self, addr: u16) -> bool {
+        self.instructions.contains_key(&addr)
+            || self.instructions.contains_key(&addr.wrapping_sub(1))
+    }
+
+    /// @return the number of bytes in the program that are part of reachable instructions.
+    pub fn reachable_bytes(&self) -> usize {
+        self.program_addrs()
+            .filter(|addr| self.is_reachable(*addr))
+            .count()
+    }
+
+    /// @return the address ranges of the program that no reachable instruction covers.
+    pub fn unreachable_ranges(&self) -> Vec<Range<u16>> {
+        let mut ranges: Vec<Range<u16>> = Vec::new();
+        for addr in self
+            .program_addrs()
+            .filter(|addr| !self.is_reachable(*addr))
+        {
+            match ranges.last_mut() {
+                Some(range) if range.end == addr => range.end += 1,
+                _ => ranges.push(addr..addr + 1),
+            }
+        }
+        ranges
+    }
+
+    fn program_addrs(&self) -> Range<u16> {
+        self.mem_addr_start..self.mem_addr_start + self.program.len() as u16
+    }
+
+    /// @return the graph in Graphviz DOT format, with a node listing the instructions of
+    /// each block. Subroutines have a double border, calls are dashed edges and jump table
+    /// entries are dotted edges.
+    pub fn to_dot(&self) -> String {
+        let labels: BTreeSet<u16> = self.blocks.keys().copied().collect();
+        let mut dot = String::from("digraph program {\n");
+        dot += "  node [shape=box, fontname=\"monospace\"];\n";
+        for block in self.blocks.values() {
+            let mut text = format!("{}:\\l", label_name(block.start));
+            for addr in (block.start..block.end).step_by(2) {
+                text += &format!(
+                    "  {}\\l",
+                    instruction_to_assembly(self.instructions[&addr], &labels)
+                );
+            }
+            let border = if self.subroutines.contains(&block.start) {
+                ", peripheries=2"
+            } else {
+                ""
+            };
+            dot += &format!(
+                "  {} [label=\"{}\"{}];\n",
+                label_name(block.start),
+                text,
+                border
+            );
+        }
+
+        for block in self.blocks.values() {
+            let last = self.instructions[&block.last_addr()];
+            for (i, successor) in block.successors.iter().enumerate() {
+                let style = match last {
+                    Instruction::JumpOffset { .. } => " [style=dotted]",
+                    // Only skips have a second successor.
+                    _ if i == 1 => " [label=\"skip\"]",
+                    _ => "",
+                };
+                dot += &format!(
+                    "  {} -> {}{};\n",
+                    label_name(block.start),
+                    label_name(*successor),
+                    style
+                );
+            }
+            if let Some(call) = block.call {
+                dot += &format!(
+                    "  {} -> {} [style=dashed, label=\"call\"];\n",
+                    label_name(block.start),
+                    label_name(call)
+                );
+            }
+        }
+        dot += "}\n";
+        dot
+    }
+}
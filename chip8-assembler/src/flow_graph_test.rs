@@ -0,0 +1,153 @@
+use crate::*;
+
+fn graph(text: &str) -> ControlFlowGraph {
+    ControlFlowGraph::new(&assemble(text, 0x200, 0x1000).unwrap(), 0x200)
+}
+
+fn block_starts(graph: &ControlFlowGraph) -> Vec<u16> {
+    graph.get_blocks().iter().map(|block| block.start).collect()
+}
+
+#[test]
+fn test_straight_line_code_is_one_block() {
+    let graph = graph("CLS\nLD V0, 1\nEXIT");
+    assert_eq!(
+        vec![&BasicBlock {
+            start: 0x200,
+            end: 0x206,
+            successors: vec![],
+            call: None,
+        }],
+        graph.get_blocks()
+    );
+}
+
+#[test]
+fn test_skip_branches_to_both_instructions() {
+    let graph = graph("main:\n  SE V0, 1\n  ADD V1, 1\n  JP main");
+    assert_eq!(vec![0x200, 0x202, 0x204], block_starts(&graph));
+    assert_eq!(
+        vec![0x202, 0x204],
+        graph.get_block(0x200).unwrap().successors
+    );
+    assert_eq!(vec![0x204], graph.get_block(0x202).unwrap().successors);
+    assert_eq!(vec![0x200], graph.get_block(0x204).unwrap().successors);
+}
+
+#[test]
+fn test_jump_into_middle_of_block_splits_it() {
+    let graph = graph("CLS\nloop:\n  ADD V0, 1\n  JP loop");
+    assert_eq!(vec![0x200, 0x202], block_starts(&graph));
+    assert_eq!(0x202, graph.get_block(0x200).unwrap().end);
+    assert_eq!(vec![0x202], graph.get_block(0x200).unwrap().successors);
+}
+
+#[test]
+fn test_subroutines() {
+    let text = "main:
+  CALL draw
+  JP main
+draw:
+  SE V0, 0
+  CALL erase
+  RET
+erase:
+  CLS
+  RET
+";
+    let graph = graph(text);
+    assert_eq!(
+        &std::collections::BTreeSet::from([0x204, 0x20a]),
+        graph.get_subroutines()
+    );
+    let main = graph.get_block(0x200).unwrap();
+    assert_eq!(Some(0x204), main.call);
+    assert_eq!(vec![0x202], main.successors);
+    assert_eq!(
+        std::collections::BTreeSet::from([0x204, 0x206, 0x208]),
+        graph.get_subroutine_blocks(0x204)
+    );
+}
+
+#[test]
+fn test_jump_table() {
+    let text = "main:
+  JP V0, table
+table:
+  JP one
+  JP two
+one:
+  CLS
+  JP main
+two:
+  EXIT
+";
+    let graph = graph(text);
+    assert_eq!(
+        &[JumpTable {
+            addr: 0x200,
+            base: 0x202,
+            targets: vec![0x202, 0x204],
+        }],
+        graph.get_jump_tables()
+    );
+    assert!(graph.unreachable_ranges().is_empty());
+}
+
+#[test]
+fn test_jump_offset_without_table() {
+    let graph = graph("JP V0, code\ncode:\n  CLS\n  EXIT");
+    assert_eq!(vec![0x202], graph.get_jump_tables()[0].targets);
+    assert!(graph.is_reachable(0x204));
+}
+
+#[test]
+fn test_unreachable_bytes() {
+    let text = "main:
+  LD I, sprite
+  JP main
+  CLS
+sprite:
+  0xFF 0x81 0x7E
+";
+    let graph = graph(text);
+    assert_eq!(4, graph.reachable_bytes());
+    assert_eq!(vec![0x204..0x209], graph.unreachable_ranges());
+    assert!(graph.is_reachable(0x203));
+    assert!(!graph.is_reachable(0x204));
+    assert_eq!(None, graph.get_instruction(0x204));
+}
+
+#[test]
+fn test_unknown_instruction_falls_through() {
+    let graph = ControlFlowGraph::new(&[0xFF, 0xFF, 0x00, 0xE0, 0x00, 0xFD], 0x200);
+    assert_eq!(6, graph.reachable_bytes());
+}
+
+#[test]
+fn test_targets_outside_program_are_ignored() {
+    let graph = graph("CALL 0x300\nJP 0x400");
+    assert!(graph.get_subroutines().is_empty());
+    assert_eq!(vec![0x202], graph.get_block(0x200).unwrap().successors);
+    assert!(graph.get_block(0x202).unwrap().successors.is_empty());
+}
+
+#[test]
+fn test_to_dot() {
+    let text = "main:
+  CALL sub
+  SE V0, 0
+  JP main
+  EXIT
+sub:
+  RET
+";
+    let dot = graph(text).to_dot();
+    assert!(dot.starts_with("digraph program {\n"));
+    assert!(dot.contains("  L200 [label=\"L200:\\l  CALL L208\\l\"];\n"));
+    assert!(dot.contains("  L208 [label=\"L208:\\l  RET\\l\", peripheries=2];\n"));
+    assert!(dot.contains("  L200 -> L208 [style=dashed, label=\"call\"];\n"));
+    assert!(dot.contains("  L202 -> L204;\n"));
+    assert!(dot.contains("  L202 -> L206 [label=\"skip\"];\n"));
+    assert!(dot.ends_with("}\n"));
+}
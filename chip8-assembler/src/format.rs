@@ -0,0 +1,211 @@
+use crate::parse::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Case {
+    Upper,
+    Lower,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Separator {
+    Comma,
+    Space,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Radix {
+    Keep,
+    Binary,
+    Decimal,
+    Hex,
+}
+
+#[derive(Debug, Clone)]
+pub struct FormatOptions {
+    pub case: Case,
+    pub separator: Separator,
+    pub radix: Radix,
+    pub indent_width: usize,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            case: Case::Upper,
+            separator: Separator::Comma,
+            radix: Radix::Keep,
+            indent_width: 2,
+        }
+    }
+}
+
+/// Width of a numeric operand, used to pad literals when changing their radix.
+#[derive(Clone, Copy)]
+enum Width {
+    Nibble,
+    Byte,
+    Addr,
+}
+
+struct FormattedLine {
+    code: String,
+    comment: Option<String>,
+}
+
+const KEYWORDS: [&str; 10] = ["I", "DT", "ST", "K", "F", "B", "[I]", "THEN", "KEY", "-KEY"];
+
+/// Format assembly source. Labels are placed in the first column and everything else is
+/// indented under them, with one more level for each control flow block. Trailing comments
+/// on consecutive lines are aligned into one column.
+pub fn format_source(text: &str, options: &FormatOptions) -> String {
+    let mut lines: Vec<FormattedLine> = Vec::new();
+    let mut depth: usize = 0;
+
+    for line in text.lines() {
+        let (code, comment) = split_comment(line);
+        let words = get_line_words(code);
+        let Some(first_word) = words.first() else {
+            let code = match comment {
+                Some(_) if line.starts_with(char::is_whitespace) => indent(depth + 1, options),
+                _ => String::new(),
+            };
+            lines.push(FormattedLine { code, comment });
+            continue;
+        };
+
+        let keyword = first_word.to_uppercase();
+        if matches!(keyword.as_str(), "ELSE" | "ENDIF" | "AGAIN") {
+            depth = depth.saturating_sub(1);
+        }
+
+        let code = if words.len() == 1 && first_word.ends_with(':') {
+            first_word.to_string()
+        } else if try_parse_number_literal(first_word).is_ok() {
+            let bytes: Vec<String> = words
+                .iter()
+                .map(|word| format_operand(word, Width::Byte, options))
+                .collect();
+            indent(depth + 1, options) + bytes.join(" ").as_str()
+        } else {
+            indent(depth + 1, options) + format_instruction(&words, options).as_str()
+        };
+
+        if matches!(keyword.as_str(), "IF" | "WHILE" | "LOOP" | "ELSE") {
+            depth += 1;
+        }
+        lines.push(FormattedLine { code, comment });
+    }
+
+    align_comments(&lines)
+}
+
+/// Split a line into the code and the trailing comment, which keeps its comment starter.
+fn split_comment(line: &str) -> (&str, Option<String>) {
+    let comment_start = COMMENT_STARTERS
+        .iter()
+        .filter_map(|comment_starter| line.find(comment_starter))
+        .min();
+    match comment_start {
+        Some(i) => (&line[..i], Some(line[i..].trim_end().to_string())),
+        None => (line, None),
+    }
+}
+
+fn format_instruction(words: &[&str], options: &FormatOptions) -> String {
+    let mnemonic = apply_case(words[0], options.case);
+    let operands = &words[1..];
+    if operands.is_empty() {
+        return mnemonic;
+    }
+
+    let upper_mnemonic = mnemonic.to_uppercase();
+    let is_condition = matches!(upper_mnemonic.as_str(), "IF" | "WHILE");
+    let formatted_operands: Vec<String> = operands
+        .iter()
+        .enumerate()
+        .map(|(i, operand)| {
+            let width = match upper_mnemonic.as_str() {
+                "JP" | "CALL" => Width::Addr,
+                "LD" if i == 1 && operands[0].to_uppercase() == "I" => Width::Addr,
+                "DRW" if i == 2 => Width::Nibble,
+                _ => Width::Byte,
+            };
+            format_operand(operand, width, options)
+        })
+        .collect();
+
+    let separator = match options.separator {
+        Separator::Comma if !is_condition => ", ",
+        _ => " ",
+    };
+    format!("{} {}", mnemonic, formatted_operands.join(separator))
+}
+
+fn format_operand(operand: &str, width: Width, options: &FormatOptions) -> String {
+    let upper_operand = operand.to_uppercase();
+    if is_register(&upper_operand) || KEYWORDS.contains(&upper_operand.as_str()) {
+        return apply_case(operand, options.case);
+    }
+
+    let Ok(n) = try_parse_number_literal(operand) else {
+        return operand.to_string();
+    };
+    let digits = |bits_per_digit: usize| match width {
+        Width::Nibble => 4_usize.div_ceil(bits_per_digit),
+        Width::Byte => 8_usize.div_ceil(bits_per_digit),
+        Width::Addr => 12_usize.div_ceil(bits_per_digit),
+    };
+    match options.radix {
+        Radix::Keep => operand.to_string(),
+        Radix::Binary => format!("0b{:0width$b}", n, width = digits(1)),
+        Radix::Decimal => format!("{}", n),
+        Radix::Hex => format!("0x{:0width$X}", n, width = digits(4)),
+    }
+}
+
+fn is_register(operand: &str) -> bool {
+    operand.len() == 2
+        && operand.starts_with('V')
+        && operand[1..].chars().all(|c| c.is_ascii_hexdigit())
+}
+
+fn apply_case(word: &str, case: Case) -> String {
+    match case {
+        Case::Upper => word.to_uppercase(),
+        Case::Lower => word.to_lowercase(),
+    }
+}
+
+fn indent(depth: usize, options: &FormatOptions) -> String {
+    " ".repeat(depth * options.indent_width)
+}
+
+/// Join the lines, aligning the trailing comments of each run of consecutive commented lines.
+fn align_comments(lines: &[FormattedLine]) -> String {
+    let has_trailing_comment =
+        |line: &FormattedLine| line.comment.is_some() && !line.code.trim().is_empty();
+
+    let mut output = String::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let run_len = lines[i..]
+            .iter()
+            .take_while(|line| has_trailing_comment(line))
+            .count()
+            .max(1);
+        let run = &lines[i..i + run_len];
+        let comment_column = run.iter().map(|line| line.code.len()).max().unwrap_or(0) + 1;
+        for line in run {
+            match line.comment {
+                Some(ref comment) if has_trailing_comment(line) => {
+                    output += format!("{:<comment_column$}{}", line.code, comment).as_str();
+                }
+                Some(ref comment) => output += format!("{}{}", line.code, comment).as_str(),
+                None => output += line.code.as_str(),
+            }
+            output.push('\n');
+        }
+        i += run_len;
+    }
+    output
+}
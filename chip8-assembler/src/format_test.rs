@@ -0,0 +1,99 @@
+use crate::format::*;
+
+const MESSY_SRC: &str = "main:
+ld v2 0x0F   ; mask
+    LD F, v0 // digit
+jp draw
+draw:
+  drw V8 V8 5
+  IF v1 == 0 THEN
+  jp main
+  ELSE
+  cls
+  # nested comment
+  ENDIF
+0x01 2 0b11
+
+# top level comment
+";
+
+#[test]
+fn test_format_default_options() {
+    let formatted = format_source(MESSY_SRC, &FormatOptions::default());
+    assert_eq!(
+        "main:
+  LD V2, 0x0F ; mask
+  LD F, V0    // digit
+  JP draw
+draw:
+  DRW V8, V8, 5
+  IF V1 == 0 THEN
+    JP main
+  ELSE
+    CLS
+    # nested comment
+  ENDIF
+  0x01 2 0b11
+
+# top level comment
+",
+        formatted
+    );
+}
+
+#[test]
+fn test_format_is_idempotent() {
+    let options = FormatOptions::default();
+    let formatted = format_source(MESSY_SRC, &options);
+    assert_eq!(formatted, format_source(&formatted, &options));
+}
+
+#[test]
+fn test_format_lowercase_spaces() {
+    let options = FormatOptions {
+        case: Case::Lower,
+        separator: Separator::Space,
+        indent_width: 4,
+        ..Default::default()
+    };
+    assert_eq!(
+        "main:\n    ld v1 [i]\n",
+        format_source("main:\nLD V1, [I]", &options)
+    );
+}
+
+#[test]
+fn test_format_hex_radix() {
+    let options = FormatOptions {
+        radix: Radix::Hex,
+        ..Default::default()
+    };
+    assert_eq!(
+        "  LD V1, 0x0A\n  JP 0x200\n  LD I, 0x010\n  DRW V0, V1, 0x5\n  0xFF 0x01\n",
+        format_source("LD V1 10\nJP 512\nLD I 16\nDRW V0 V1 5\n255 1", &options)
+    );
+}
+
+#[test]
+fn test_format_binary_and_decimal_radix() {
+    let binary = FormatOptions {
+        radix: Radix::Binary,
+        ..Default::default()
+    };
+    assert_eq!("  0b00000101\n", format_source("5", &binary));
+
+    let decimal = FormatOptions {
+        radix: Radix::Decimal,
+        ..Default::default()
+    };
+    assert_eq!("  SE V1, 16\n", format_source("SE V1 0x10", &decimal));
+}
+
+#[test]
+fn test_format_all_comment_starters() {
+    let formatted = format_source(
+        "CLS ; a\nCLS // b\nCLS # c\nCLS @ d",
+        &FormatOptions::default(),
+    );
+    assert_eq!("  CLS ; a\n  CLS // b\n  CLS # c\n  CLS @ d\n", formatted);
+}
@@ -1,10 +1,27 @@
 mod assembler_error;
+mod control_flow;
+mod disassemble;
+mod flow_graph;
+mod format;
+mod lint;
 mod parse;
 mod statement;
+mod symbols;
 
-use assembler_error::*;
+pub use assembler_error::{AssemblerError, LineLocation, Location};
+pub use control_flow::HIDDEN_LABEL_PREFIX;
+pub use disassemble::{
+    disassemble, disassemble_with_modified_code, instruction_to_assembly, label_name,
+};
+pub use flow_graph::{BasicBlock, ControlFlowGraph, JumpTable};
+pub use format::{Case, FormatOptions, Radix, Separator, format_source};
+pub use lint::{LINT_ALLOW_MARKER, LintCode, LintWarning, lint};
+pub use parse::get_line_words;
+pub use statement::{Statement, StatementType};
+pub use symbols::{source_map_file, symbol_file};
+
+use control_flow::*;
 use parse::*;
-use statement::*;
 
 use std::collections::HashMap;
 
@@ -13,10 +30,24 @@ pub fn assemble(
     mem_addr_start: u16,
     mem_addr_max: u16,
 ) -> Result<Vec<u8>, Vec<AssemblerError>> {
-    let mut statements: Vec<Statement> = text
-        .lines()
-        .enumerate()
-        .flat_map(|(line_num, line)| get_partial_statement(line, line_num as u16))
+    let statements = assemble_statements(text, mem_addr_start, mem_addr_max)?;
+    let bytes: Vec<u8> = statements
+        .iter()
+        .flat_map(|statement| statement.bytes())
+        .collect();
+    Ok(bytes)
+}
+
+/// Assemble the text into fully parsed statements, which keep the source line and memory
+/// address of every instruction, data row and label.
+pub fn assemble_statements(
+    text: &str,
+    mem_addr_start: u16,
+    mem_addr_max: u16,
+) -> Result<Vec<Statement>, Vec<AssemblerError>> {
+    let mut statements: Vec<Statement> = expand_control_flow(text)?
+        .iter()
+        .flat_map(|source_line| get_partial_statement(&source_line.line, source_line.line_num))
         .collect();
 
     let mut next_mem_addr: u16 = mem_addr_start;
@@ -57,7 +88,12 @@ pub fn assemble(
     for statement in statements.iter_mut() {
         match statement.statement_type {
             StatementType::Bytes { ref mut data } => {
-                *data = parse_bytes(statement.line.as_str())?;
+                *data = parse_bytes(statement.line.as_str()).map_err(|errors| {
+                    errors
+                        .into_iter()
+                        .map(|err| err.or_line_num(statement.line_num))
+                        .collect::<Vec<AssemblerError>>()
+                })?;
             }
             StatementType::Instruction {
                 ref mut instruction,
@@ -68,7 +104,7 @@ pub fn assemble(
                     &labels,
                     mem_addr_max,
                 )
-                .map_err(|err| vec![err])?
+                .map_err(|err| vec![err.or_line_num(statement.line_num)])?
             }
             StatementType::Label { ref mut name } => {
                 *name = parse_label(&statement.line, statement.line_num)
@@ -78,22 +114,35 @@ pub fn assemble(
         };
     }
 
-    let bytes: Vec<u8> = statements
-        .iter()
-        .map(|statement| statement.bytes())
-        .flatten()
-        .collect();
-    Ok(bytes)
+    Ok(statements)
 }
 
 #[cfg(test)]
 mod assembler_error_test;
 
+#[cfg(test)]
+mod control_flow_test;
+
+#[cfg(test)]
+mod disassemble_test;
+
+#[cfg(test)]
+mod flow_graph_test;
+
+#[cfg(test)]
+mod format_test;
+
 #[cfg(test)]
 mod lib_test;
 
+#[cfg(test)]
+mod lint_test;
+
 #[cfg(test)]
 mod parse_test;
 
 #[cfg(test)]
 mod statement_test;
+
+#[cfg(test)]
+mod symbols_test;
@@ -0,0 +1,455 @@
+use crate::control_flow::HIDDEN_LABEL_PREFIX;
+use crate::flow_graph::ControlFlowGraph;
+use crate::parse::*;
+use crate::statement::*;
+
+use chip8_instructions::Instruction;
+use std::collections::{HashMap, HashSet};
+
+/// Comment marker that suppresses warnings on its line, e.g. `; lint-allow: W001, W006`.
+/// Without any codes every warning on the line is suppressed.
+pub const LINT_ALLOW_MARKER: &str = "lint-allow";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintCode {
+    UnreachableCode,
+    SkipOverLongInstruction,
+    PointerIntoCode,
+    DrawPastData,
+    ClobberedFlag,
+    UnusedLabel,
+    CallWithoutReturn,
+}
+
+impl LintCode {
+    /// @return the stable code used to identify and suppress the warning.
+    pub fn code(&self) -> &'static str {
+        match self {
+            LintCode::UnreachableCode => "W001",
+            LintCode::SkipOverLongInstruction => "W002",
+            LintCode::PointerIntoCode => "W003",
+            LintCode::DrawPastData => "W004",
+            LintCode::ClobberedFlag => "W005",
+            LintCode::UnusedLabel => "W006",
+            LintCode::CallWithoutReturn => "W007",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LintWarning {
+    pub code: LintCode,
+    pub message: String,
+    pub line_num: u16,
+}
+
+impl LintWarning {
+    fn new(code: LintCode, message: String, line_num: u16) -> Self {
+        Self {
+            code,
+            message,
+            line_num,
+        }
+    }
+}
+
+/// Check assembled statements for code that is valid but likely a mistake.
+/// The source text is used to find the `lint-allow` comments.
+pub fn lint(text: &str, statements: &[Statement]) -> Vec<LintWarning> {
+    let program = Program::new(statements);
+    let mut warnings: Vec<LintWarning> = Vec::new();
+    lint_unreachable_code(&program, &mut warnings);
+    lint_skip_over_long_instruction(&program, &mut warnings);
+    lint_pointer_into_code(&program, &mut warnings);
+    lint_draw_past_data(&program, &mut warnings);
+    lint_clobbered_flag(&program, &mut warnings);
+    lint_unused_labels(&program, &mut warnings);
+    lint_call_without_return(&program, &mut warnings);
+
+    let lines: Vec<&str> = text.lines().collect();
+    warnings.retain(|warning| {
+        let line = lines.get(warning.line_num as usize).unwrap_or(&"");
+        !is_allowed(line, warning.code)
+    });
+    warnings.sort_by_key(|warning| warning.line_num);
+    warnings
+}
+
+fn is_allowed(line: &str, code: LintCode) -> bool {
+    let Some(marker_start) = line.find(LINT_ALLOW_MARKER) else {
+        return false;
+    };
+    let codes: Vec<&str> = line[marker_start + LINT_ALLOW_MARKER.len()..]
+        .split(|c: char| c.is_whitespace() || c == ',' || c == ':')
+        .filter(|word| word.starts_with('W'))
+        .collect();
+    codes.is_empty() || codes.contains(&code.code())
+}
+
+struct Program<'a> {
+    statements: &'a [Statement],
+    /// Index into `statements` of the instruction at each address.
+    instructions: HashMap<u16, usize>,
+    labels: Vec<&'a Statement>,
+}
+
+impl<'a> Program<'a> {
+    fn new(statements: &'a [Statement]) -> Self {
+        let instructions = statements
+            .iter()
+            .enumerate()
+            .filter(|(_, statement)| {
+                matches!(statement.statement_type, StatementType::Instruction { .. })
+            })
+            .map(|(i, statement)| (statement.mem_addr, i))
+            .collect();
+        let labels = statements
+            .iter()
+            .filter(|statement| matches!(statement.statement_type, StatementType::Label { .. }))
+            .collect();
+        Self {
+            statements,
+            instructions,
+            labels,
+        }
+    }
+
+    fn instruction_at(&self, addr: u16) -> Option<Instruction> {
+        let i = self.instructions.get(&addr)?;
+        get_instruction(&self.statements[*i])
+    }
+
+    fn label_name(&self, addr: u16) -> Option<&str> {
+        self.labels
+            .iter()
+            .find_map(|statement| match statement.statement_type {
+                StatementType::Label { ref name } if statement.mem_addr == addr => {
+                    Some(name.as_str())
+                }
+                _ => None,
+            })
+    }
+
+    /// @return the address range of the consecutive data rows that contain `addr`.
+    fn data_region(&self, addr: u16) -> Option<(u16, u16)> {
+        let mut regions: Vec<(u16, u16)> = Vec::new();
+        let mut in_data = false;
+        for statement in self.statements.iter() {
+            let is_data = matches!(statement.statement_type, StatementType::Bytes { .. });
+            let end = statement.mem_addr + statement.size;
+            match regions.last_mut() {
+                Some(region) if is_data && in_data => region.1 = end,
+                _ if is_data => regions.push((statement.mem_addr, end)),
+                _ => {}
+            }
+            in_data = is_data;
+        }
+        regions
+            .into_iter()
+            .find(|(start, end)| *start <= addr && addr < *end)
+    }
+}
+
+fn get_instruction(statement: &Statement) -> Option<Instruction> {
+    match statement.statement_type {
+        StatementType::Instruction { instruction } => Some(instruction),
+        _ => None,
+    }
+}
+
+fn is_skip(instruction: Instruction) -> bool {
+    matches!(
+        instruction,
+        Instruction::SkipRegEqualsImm { .. }
+            | Instruction::SkipRegNotEqualsImm { .. }
+            | Instruction::SkipRegEqualsReg { .. }
+            | Instruction::SkipRegNotEqualsReg { .. }
+            | Instruction::SkipKeyPressed { .. }
+            | Instruction::SkipNotKeyPressed { .. }
+    )
+}
+
+fn lint_unreachable_code(program: &Program, warnings: &mut Vec<LintWarning>) {
+    let Some(first) = program.statements.first() else {
+        return;
+    };
+    let bytes: Vec<u8> = program
+        .statements
+        .iter()
+        .flat_map(|statement| statement.bytes())
+        .collect();
+    let graph = ControlFlowGraph::new(&bytes, first.mem_addr);
+
+    // Warn once at the start of each run of unreachable instructions.
+    let mut in_unreachable_run = false;
+    for statement in program.statements.iter() {
+        match statement.statement_type {
+            StatementType::Instruction { .. } => {
+                let reachable = graph.get_instruction(statement.mem_addr).is_some();
+                if !reachable && !in_unreachable_run {
+                    warnings.push(LintWarning::new(
+                        LintCode::UnreachableCode,
+                        "Unreachable code, which no jump, call, skip or earlier instruction leads to."
+                            .to_string(),
+                        statement.line_num,
+                    ));
+                }
+                in_unreachable_run = !reachable;
+            }
+            StatementType::Bytes { .. } => in_unreachable_run = false,
+            StatementType::Label { .. } => {}
+        }
+    }
+}
+
+fn lint_skip_over_long_instruction(program: &Program, warnings: &mut Vec<LintWarning>) {
+    for pair in program.statements.windows(2) {
+        let [skip, next] = pair else {
+            continue;
+        };
+        let StatementType::Bytes { ref data } = next.statement_type else {
+            continue;
+        };
+        if get_instruction(skip).is_some_and(is_skip) && data.starts_with(&[0xF0, 0x00]) {
+            warnings.push(LintWarning::new(
+                LintCode::SkipOverLongInstruction,
+                "Skip is followed by the 4 byte XO-CHIP instruction F000 and only skips its first half."
+                    .to_string(),
+                skip.line_num,
+            ));
+        }
+    }
+}
+
+fn lint_pointer_into_code(program: &Program, warnings: &mut Vec<LintWarning>) {
+    for statement in program.statements.iter() {
+        let Some(Instruction::LoadImmToPointer { addr }) = get_instruction(statement) else {
+            continue;
+        };
+        let is_code = program.instructions.contains_key(&addr)
+            || program.instructions.contains_key(&addr.wrapping_sub(1));
+        if is_code {
+            warnings.push(LintWarning::new(
+                LintCode::PointerIntoCode,
+                format!("I is set to 0x{:03X}, which points into code.", addr),
+                statement.line_num,
+            ));
+        }
+    }
+}
+
+fn lint_draw_past_data(program: &Program, warnings: &mut Vec<LintWarning>) {
+    // The value of I is only tracked within straight line code.
+    let mut pointer: Option<u16> = None;
+    for statement in program.statements.iter() {
+        let Some(instruction) = get_instruction(statement) else {
+            pointer = None;
+            continue;
+        };
+        match instruction {
+            Instruction::LoadImmToPointer { addr } => pointer = Some(addr),
+            Instruction::Draw { nibble, .. } => {
+                let Some(addr) = pointer else {
+                    continue;
+                };
+                let Some((start, end)) = program.data_region(addr) else {
+                    continue;
+                };
+                if addr + nibble as u16 > end {
+                    let name = program
+                        .label_name(start)
+                        .map_or(format!("0x{:03X}", start), |name| name.to_string());
+                    warnings.push(LintWarning::new(
+                        LintCode::DrawPastData,
+                        format!(
+                            "Drawing {} bytes from 0x{:03X} reads past the end of `{}`, which has {} bytes left.",
+                            nibble,
+                            addr,
+                            name,
+                            end - addr
+                        ),
+                        statement.line_num,
+                    ));
+                }
+            }
+            Instruction::AddRegToPointer { .. }
+            | Instruction::LoadDigitSpriteToPointer { .. }
+            | Instruction::Jump { .. }
+            | Instruction::JumpOffset { .. }
+            | Instruction::Call { .. }
+            | Instruction::Return => pointer = None,
+            _ => {}
+        }
+    }
+}
+
+const VF: u8 = 0xF;
+
+/// @return true when the instruction overwrites VF with a flag.
+fn sets_flag(instruction: Instruction) -> bool {
+    matches!(
+        instruction,
+        Instruction::AddReg { .. }
+            | Instruction::SubReg { .. }
+            | Instruction::ShiftRight { .. }
+            | Instruction::SubNegReg { .. }
+            | Instruction::ShiftLeft { .. }
+            | Instruction::Draw { .. }
+    )
+}
+
+fn writes_vf(instruction: Instruction) -> bool {
+    if sets_flag(instruction) {
+        return true;
+    }
+    match instruction {
+        Instruction::LoadImmToReg { x, .. }
+        | Instruction::AddImmToReg { x, .. }
+        | Instruction::LoadRegToReg { x, .. }
+        | Instruction::OrReg { x, .. }
+        | Instruction::AndReg { x, .. }
+        | Instruction::XorReg { x, .. }
+        | Instruction::Random { x, .. }
+        | Instruction::LoadDelayTimerToReg { x }
+        | Instruction::LoadNextKeyPress { x }
+        | Instruction::ReadRegFromPointer { x } => x == VF,
+        _ => false,
+    }
+}
+
+fn reads_vf(instruction: Instruction) -> bool {
+    match instruction {
+        Instruction::SkipRegEqualsReg { x, y }
+        | Instruction::SkipRegNotEqualsReg { x, y }
+        | Instruction::OrReg { x, y }
+        | Instruction::AndReg { x, y }
+        | Instruction::XorReg { x, y }
+        | Instruction::AddReg { x, y }
+        | Instruction::SubReg { x, y }
+        | Instruction::SubNegReg { x, y }
+        | Instruction::ShiftRight { x, y }
+        | Instruction::ShiftLeft { x, y }
+        | Instruction::Draw { x, y, .. } => x == VF || y == VF,
+        Instruction::LoadRegToReg { y, .. } => y == VF,
+        Instruction::SkipRegEqualsImm { x, .. }
+        | Instruction::SkipRegNotEqualsImm { x, .. }
+        | Instruction::AddImmToReg { x, .. }
+        | Instruction::SkipKeyPressed { x }
+        | Instruction::SkipNotKeyPressed { x }
+        | Instruction::LoadRegToDelayTimer { x }
+        | Instruction::LoadRegToSoundTimer { x }
+        | Instruction::AddRegToPointer { x }
+        | Instruction::LoadDigitSpriteToPointer { x }
+        | Instruction::LoadDecimalDigitsToPointer { x }
+        | Instruction::WriteRegToPointer { x } => x == VF,
+        _ => false,
+    }
+}
+
+fn lint_clobbered_flag(program: &Program, warnings: &mut Vec<LintWarning>) {
+    let mut prev: Option<(Instruction, u16)> = None;
+    for statement in program.statements.iter() {
+        let Some(instruction) = get_instruction(statement) else {
+            prev = None;
+            continue;
+        };
+
+        let result_register = match instruction {
+            Instruction::AddReg { x, .. }
+            | Instruction::SubReg { x, .. }
+            | Instruction::ShiftRight { x, .. }
+            | Instruction::SubNegReg { x, .. }
+            | Instruction::ShiftLeft { x, .. } => Some(x),
+            _ => None,
+        };
+        if result_register == Some(VF) {
+            warnings.push(LintWarning::new(
+                LintCode::ClobberedFlag,
+                "The result written to VF is immediately overwritten by the flag.".to_string(),
+                statement.line_num,
+            ));
+        } else if let Some((prev_instruction, prev_line_num)) = prev {
+            let explicit_write = writes_vf(prev_instruction) && !sets_flag(prev_instruction);
+            if explicit_write && writes_vf(instruction) && !reads_vf(instruction) {
+                warnings.push(LintWarning::new(
+                    LintCode::ClobberedFlag,
+                    "VF is overwritten by the next instruction before it is read.".to_string(),
+                    prev_line_num,
+                ));
+            }
+        }
+        prev = Some((instruction, statement.line_num));
+    }
+}
+
+fn lint_unused_labels(program: &Program, warnings: &mut Vec<LintWarning>) {
+    let referenced_words: HashSet<&str> = program
+        .statements
+        .iter()
+        .filter(|statement| matches!(statement.statement_type, StatementType::Instruction { .. }))
+        .flat_map(|statement| get_line_words(&statement.line).into_iter().skip(1))
+        .collect();
+    let entry_addr = program
+        .statements
+        .first()
+        .map(|statement| statement.mem_addr);
+
+    for statement in program.labels.iter() {
+        let StatementType::Label { ref name } = statement.statement_type else {
+            continue;
+        };
+        if name.starts_with(HIDDEN_LABEL_PREFIX)
+            || Some(statement.mem_addr) == entry_addr
+            || referenced_words.contains(name.as_str())
+        {
+            continue;
+        }
+        warnings.push(LintWarning::new(
+            LintCode::UnusedLabel,
+            format!("Label '{}' is never used.", name),
+            statement.line_num,
+        ));
+    }
+}
+
+fn lint_call_without_return(program: &Program, warnings: &mut Vec<LintWarning>) {
+    for statement in program.statements.iter() {
+        let Some(Instruction::Call { addr }) = get_instruction(statement) else {
+            continue;
+        };
+        if has_return_path(program, addr) == Some(false) {
+            let name = program
+                .label_name(addr)
+                .map_or(format!("0x{:03X}", addr), |name| format!("'{}'", name));
+            warnings.push(LintWarning::new(
+                LintCode::CallWithoutReturn,
+                format!("The subroutine {} never reaches a RET.", name),
+                statement.line_num,
+            ));
+        }
+    }
+}
+
+/// Follow the control flow from a subroutine's address.
+/// @return whether a RET is reachable, or None when the control flow cannot be followed.
+fn has_return_path(program: &Program, addr: u16) -> Option<bool> {
+    let mut visited: HashSet<u16> = HashSet::new();
+    let mut stack: Vec<u16> = vec![addr];
+    while let Some(addr) = stack.pop() {
+        if !visited.insert(addr) {
+            continue;
+        }
+        match program.instruction_at(addr)? {
+            Instruction::Return => return Some(true),
+            Instruction::Exit => {}
+            Instruction::Jump { addr } => stack.push(addr),
+            Instruction::JumpOffset { .. } => return None,
+            instruction if is_skip(instruction) => {
+                stack.push(addr + 2);
+                stack.push(addr + 4);
+            }
+            _ => stack.push(addr + 2),
+        }
+    }
+    Some(false)
+}
@@ -0,0 +1,128 @@
+use crate::*;
+
+fn lint_codes(text: &str) -> Vec<(&'static str, u16)> {
+    let statements = assemble_statements(text, 0x200, 0x1000).unwrap();
+    lint(text, &statements)
+        .iter()
+        .map(|warning| (warning.code.code(), warning.line_num))
+        .collect()
+}
+
+#[test]
+fn test_clean_program_has_no_warnings() {
+    let text = "main:
+  CALL draw
+  JP main
+draw:
+  LD I, sprite
+  DRW V0, V1, 2
+  RET
+sprite:
+  0xFF 0x81
+";
+    assert_eq!(Vec::<(&str, u16)>::new(), lint_codes(text));
+}
+
+#[test]
+fn test_unreachable_code() {
+    let text = "main:\n  JP main\n  CLS\n  CLS";
+    assert_eq!(vec![("W001", 2)], lint_codes(text));
+}
+
+#[test]
+fn test_conditional_jump_is_not_unreachable() {
+    let text = "main:\n  SE V0, 1\n  JP main\n  CLS\n  JP main";
+    assert_eq!(Vec::<(&str, u16)>::new(), lint_codes(text));
+}
+
+#[test]
+fn test_code_after_break_is_unreachable() {
+    let text = "LOOP\n  BREAK\n  CLS\nAGAIN\nEXIT";
+    assert_eq!(vec![("W001", 2)], lint_codes(text));
+}
+
+#[test]
+fn test_skip_over_long_instruction() {
+    let text = "main:\n  SE V0, 1\n  0xF0 0x00 0x02 0x00\n  JP main";
+    assert_eq!(vec![("W002", 1)], lint_codes(text));
+}
+
+#[test]
+fn test_pointer_into_code() {
+    let text = "main:\n  LD I, code\ncode:\n  CLS\n  JP main";
+    assert_eq!(vec![("W003", 1)], lint_codes(text));
+}
+
+#[test]
+fn test_draw_past_data() {
+    let text = "main:
+  LD I, sprite
+  DRW V0, V1, 3
+  LD I, sprite
+  DRW V0, V1, 2
+  JP main
+sprite:
+  0xFF 0x81
+";
+    assert_eq!(vec![("W004", 2)], lint_codes(text));
+}
+
+#[test]
+fn test_result_in_vf_clobbered_by_flag() {
+    let text = "main:\n  ADD VF, V1\n  JP main";
+    assert_eq!(vec![("W005", 1)], lint_codes(text));
+}
+
+#[test]
+fn test_vf_clobbered_before_read() {
+    let text = "main:\n  LD VF, 1\n  SHL V1\n  LD VF, 1\n  ADD V1, VF\n  ADD V1, V2\n  DRW V0, V1, 1\n  JP main";
+    assert_eq!(vec![("W005", 1)], lint_codes(text));
+}
+
+#[test]
+fn test_unused_label() {
+    let text = "main:\n  CLS\nunused:\n  JP main";
+    assert_eq!(vec![("W006", 2)], lint_codes(text));
+}
+
+#[test]
+fn test_call_without_return() {
+    let text = "main:\n  CALL forever\n  JP main\nforever:\n  JP forever";
+    assert_eq!(vec![("W007", 1)], lint_codes(text));
+}
+
+#[test]
+fn test_call_with_return_through_branch() {
+    let text = "main:
+  CALL sub
+  JP main
+sub:
+  SE V0, 0
+  JP done
+  JP sub
+done:
+  RET
+";
+    assert_eq!(Vec::<(&str, u16)>::new(), lint_codes(text));
+}
+
+#[test]
+fn test_lint_allow_comments() {
+    let text = "main:\n  JP main\n  CLS ; lint-allow: W001\nunused: # lint-allow\n  JP main";
+    assert_eq!(Vec::<(&str, u16)>::new(), lint_codes(text));
+
+    let text = "main:\n  JP main\n  CLS ; lint-allow: W006";
+    assert_eq!(vec![("W001", 2)], lint_codes(text));
+}
+
+#[test]
+fn test_uncalled_subroutine_is_unreachable() {
+    let text = "main:\n  JP main\nunused:\n  CLS\n  RET";
+    assert_eq!(vec![("W006", 2), ("W001", 3)], lint_codes(text));
+}
+
+#[test]
+fn test_jump_table_is_reachable() {
+    let text = "main:\n  JP V0, table\ntable:\n  JP main\n  JP main";
+    assert_eq!(Vec::<(&str, u16)>::new(), lint_codes(text));
+}
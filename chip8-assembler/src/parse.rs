@@ -84,10 +84,10 @@ pub fn parse_instruction(
         "RET" => Ok(Instruction::Return),
         "EXIT" => Ok(Instruction::Exit),
         "JP" => get_jp(line, &words, line_num, labels, mem_addr_max),
-        "CALL" => get_call(line, &words, line_num, mem_addr_max),
+        "CALL" => get_call(line, &words, line_num, labels, mem_addr_max),
         "SE" => get_se(line, &words, line_num),
         "SNE" => get_sne(line, &words, line_num),
-        "LD" => get_ld(line, &words, line_num, mem_addr_max),
+        "LD" => get_ld(line, &words, line_num, labels, mem_addr_max),
         "ADD" => get_add(line, &words, line_num),
         "OR" => get_or(line, &words, line_num),
         "AND" => get_and(line, &words, line_num),
@@ -109,7 +109,7 @@ pub fn parse_instruction(
 
 /// Break lines into relevant words.
 /// Comments and commas are removed.
-fn get_line_words(mut line: &str) -> Vec<&str> {
+pub fn get_line_words(mut line: &str) -> Vec<&str> {
     for comment_start in COMMENT_STARTERS.iter() {
         line = line.split(comment_start).next().unwrap_or("");
     }
@@ -131,12 +131,7 @@ fn get_jp(
     labels: &HashMap<String, u16>,
     mem_addr_max: u16,
 ) -> Result<Instruction, AssemblerError> {
-    let get_addr = |word: &str| {
-        if let Some(addr) = labels.get(word) {
-            return Ok(*addr);
-        }
-        try_parse_addr_literal(word, mem_addr_max)
-    };
+    let get_addr = |word: &str| try_parse_addr(word, labels, mem_addr_max);
     if words.len() == 2 {
         let addr = get_addr(words[1])?;
         return Ok(Instruction::Jump { addr });
@@ -158,6 +153,7 @@ fn get_call(
     line: &str,
     words: &Vec<&str>,
     line_num: u16,
+    labels: &HashMap<String, u16>,
     mem_addr_max: u16,
 ) -> Result<Instruction, AssemblerError> {
     if words.len() != 2 {
@@ -167,7 +163,7 @@ fn get_call(
         ));
     }
     Ok(Instruction::Call {
-        addr: try_parse_addr_literal(words[1], mem_addr_max)?,
+        addr: try_parse_addr(words[1], labels, mem_addr_max)?,
     })
 }
 
@@ -209,6 +205,7 @@ fn get_ld(
     line: &str,
     words: &Vec<&str>,
     line_num: u16,
+    labels: &HashMap<String, u16>,
     mem_addr_max: u16,
 ) -> Result<Instruction, AssemblerError> {
     if words.len() != 3 {
@@ -247,7 +244,7 @@ fn get_ld(
     }
 
     if arg1.to_uppercase() == "I" {
-        let addr = try_parse_addr_literal(arg2, mem_addr_max)?;
+        let addr = try_parse_addr(arg2, labels, mem_addr_max)?;
         return Ok(Instruction::LoadImmToPointer { addr });
     }
 
@@ -354,14 +351,19 @@ fn get_sub(line: &str, words: &Vec<&str>, line_num: u16) -> Result<Instruction,
 }
 
 fn get_shr(line: &str, words: &Vec<&str>, line_num: u16) -> Result<Instruction, AssemblerError> {
-    if words.len() != 2 {
+    if words.len() != 2 && words.len() != 3 {
         return Err(AssemblerError::new_no_options(
-            "Expected one register argument for a SHR instruction".to_string(),
+            "Expected one or two register arguments for a SHR instruction".to_string(),
             Location::new_no_options(LineLocation::new(0, line.len() as u32), line_num),
         ));
     }
     let x = try_parse_register(words[1])?;
-    Ok(Instruction::ShiftRight { x })
+    // Without Vy, shift Vx in place whether or not the interpreter shifts Vy into Vx.
+    let y = match words.get(2) {
+        Some(word) => try_parse_register(word)?,
+        None => x,
+    };
+    Ok(Instruction::ShiftRight { x, y })
 }
 
 fn get_subn(line: &str, words: &Vec<&str>, line_num: u16) -> Result<Instruction, AssemblerError> {
@@ -377,14 +379,19 @@ fn get_subn(line: &str, words: &Vec<&str>, line_num: u16) -> Result<Instruction,
 }
 
 fn get_shl(line: &str, words: &Vec<&str>, line_num: u16) -> Result<Instruction, AssemblerError> {
-    if words.len() != 2 {
+    if words.len() != 2 && words.len() != 3 {
         return Err(AssemblerError::new_no_options(
-            "Expected one register argument for a SHL instruction".to_string(),
+            "Expected one or two register arguments for a SHL instruction".to_string(),
             Location::new_no_options(LineLocation::new(0, line.len() as u32), line_num),
         ));
     }
     let x = try_parse_register(words[1])?;
-    Ok(Instruction::ShiftLeft { x })
+    // Without Vy, shift Vx in place whether or not the interpreter shifts Vy into Vx.
+    let y = match words.get(2) {
+        Some(word) => try_parse_register(word)?,
+        None => x,
+    };
+    Ok(Instruction::ShiftLeft { x, y })
 }
 
 fn get_rnd(line: &str, words: &Vec<&str>, line_num: u16) -> Result<Instruction, AssemblerError> {
@@ -434,7 +441,7 @@ fn get_sknp(line: &str, words: &Vec<&str>, line_num: u16) -> Result<Instruction,
     Ok(Instruction::SkipNotKeyPressed { x })
 }
 
-fn try_parse_number_literal(n_str: &str) -> Result<u64, AssemblerError> {
+pub fn try_parse_number_literal(n_str: &str) -> Result<u64, AssemblerError> {
     let (radix, s) = if n_str.to_lowercase().starts_with("0x") {
         (16, &n_str[2..])
     } else if n_str.to_lowercase().starts_with("0b") {
@@ -466,6 +473,18 @@ fn try_parse_addr_literal(n_str: &str, mem_addr_max: u16) -> Result<u16, Assembl
     }
 }
 
+/// Parse an address operand, which is either a label or an address literal.
+fn try_parse_addr(
+    word: &str,
+    labels: &HashMap<String, u16>,
+    mem_addr_max: u16,
+) -> Result<u16, AssemblerError> {
+    if let Some(addr) = labels.get(word) {
+        return Ok(*addr);
+    }
+    try_parse_addr_literal(word, mem_addr_max)
+}
+
 fn try_parse_register(reg_str: &str) -> Result<u8, AssemblerError> {
     if !reg_str.to_uppercase().starts_with('V') {
         return Err(AssemblerError::new_message(format!(
@@ -518,5 +537,5 @@ fn try_parse_nibble_literal(n_str: &str) -> Result<u8, AssemblerError> {
     }
 }
 
-const COMMENT_STARTERS: [&str; 4] = [";", "//", "#", "@"];
+pub const COMMENT_STARTERS: [&str; 4] = [";", "//", "#", "@"];
 const NUM_REGISTERS: u8 = 0xF;
@@ -115,7 +115,14 @@ fn test_parse_sub() {
 fn test_parse_shift_right() {
     let line = "SHR V6";
     let instruction = parse_instruction(line, 0, &HashMap::new(), 0xFFFF).unwrap();
-    assert_eq!(instruction, Instruction::ShiftRight { x: 6 });
+    assert_eq!(instruction, Instruction::ShiftRight { x: 6, y: 6 });
+}
+
+#[test]
+fn test_parse_shift_right_from_reg() {
+    let line = "SHR V6, V7";
+    let instruction = parse_instruction(line, 0, &HashMap::new(), 0xFFFF).unwrap();
+    assert_eq!(instruction, Instruction::ShiftRight { x: 6, y: 7 });
 }
 
 #[test]
@@ -129,7 +136,7 @@ fn test_parse_sub_neg() {
 fn test_parse_shift_left() {
     let line = "SHL VC";
     let instruction = parse_instruction(line, 0, &HashMap::new(), 0xFFFF).unwrap();
-    assert_eq!(instruction, Instruction::ShiftLeft { x: 0xC });
+    assert_eq!(instruction, Instruction::ShiftLeft { x: 0xC, y: 0xC });
 }
 
 #[test]
@@ -287,3 +294,17 @@ fn test_parse_label() {
     let label = parse_label("my_label:", 0).unwrap();
     assert_eq!("my_label", label);
 }
+
+#[test]
+fn test_parse_call_label() {
+    let labels = HashMap::from([("subroutine".to_string(), 0x234)]);
+    let instruction = parse_instruction("CALL subroutine", 0, &labels, 0xFFFF).unwrap();
+    assert_eq!(instruction, Instruction::Call { addr: 0x234 });
+}
+
+#[test]
+fn test_parse_ld_i_label() {
+    let labels = HashMap::from([("sprite".to_string(), 0x345)]);
+    let instruction = parse_instruction("LD I, sprite", 0, &labels, 0xFFFF).unwrap();
+    assert_eq!(instruction, Instruction::LoadImmToPointer { addr: 0x345 });
+}
@@ -1,6 +1,6 @@
 use chip8_instructions::*;
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct Statement {
     pub mem_addr: u16,
     pub line_num: u16,
@@ -9,7 +9,7 @@ pub struct Statement {
     pub statement_type: StatementType,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub enum StatementType {
     Bytes { data: Vec<u8> },
     Instruction { instruction: Instruction },
@@ -0,0 +1,35 @@
+use crate::control_flow::HIDDEN_LABEL_PREFIX;
+use crate::statement::{Statement, StatementType};
+
+/// @return a symbol file with a line per label giving its address in hex and its name,
+/// such as `0x21a draw_score`. Labels generated for control flow are left out.
+pub fn symbol_file(statements: &[Statement]) -> String {
+    statements
+        .iter()
+        .filter_map(|statement| match statement.statement_type {
+            StatementType::Label { ref name } if !name.starts_with(HIDDEN_LABEL_PREFIX) => {
+                Some(format!("0x{:03x} {}\n", statement.mem_addr, name))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// @return a source map naming the source file, then a line per instruction or data row
+/// giving its address in hex, its line number counting from 1, and its size in bytes, such
+/// as `0x200 3 2`.
+pub fn source_map_file(source_path: &str, statements: &[Statement]) -> String {
+    let mut text = format!("source {}\n", source_path);
+    for statement in statements.iter() {
+        if let StatementType::Label { .. } = statement.statement_type {
+            continue;
+        }
+        text += &format!(
+            "0x{:03x} {} {}\n",
+            statement.mem_addr,
+            statement.line_num + 1,
+            statement.size
+        );
+    }
+    text
+}
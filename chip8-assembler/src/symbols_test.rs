@@ -0,0 +1,39 @@
+use crate::*;
+
+const SYMBOLS_SRC: &str = "
+main:
+  LD V0, 0x01
+  IF V0 == 0x01 THEN
+    CALL draw_score
+  ENDIF
+loop:
+  JP loop
+draw_score:
+  RET
+";
+
+#[test]
+fn test_symbol_file() {
+    let statements = assemble_statements(SYMBOLS_SRC, 0x200, 0x1000).unwrap();
+    assert_eq!(
+        "0x200 main\n0x208 loop\n0x20a draw_score\n",
+        symbol_file(&statements)
+    );
+}
+
+#[test]
+fn test_source_map_file() {
+    let statements = assemble_statements(SYMBOLS_SRC, 0x200, 0x1000).unwrap();
+    assert_eq!(
+        concat!(
+            "source game.asm\n",
+            "0x200 3 2\n",
+            "0x202 4 2\n",
+            "0x204 4 2\n",
+            "0x206 5 2\n",
+            "0x208 8 2\n",
+            "0x20a 10 2\n",
+        ),
+        source_map_file("game.asm", &statements)
+    );
+}
@@ -0,0 +1,200 @@
+mod packet;
+mod registers;
+mod stub;
+
+#[cfg(test)]
+mod packet_test;
+
+#[cfg(test)]
+mod registers_test;
+
+#[cfg(test)]
+mod stub_test;
+
+use chip8::Chip8;
+use chip8::logger;
+use chip8::source_map::SourceMap;
+use chip8::{DEFAULT_INSTRUCTIONS_PER_FRAME, MAX_INSTRUCTIONS_PER_FRAME, MAX_PROGRAM_BYTES};
+use packet::{Connection, INTERRUPT, Input, encode_hex};
+use stub::{Action, GdbStub};
+
+use std::env;
+use std::fs;
+use std::io;
+use std::net::{TcpListener, TcpStream};
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage: chip8-gdbstub [--port=N] [--ipf=N] [--source_map=FILE] ROM
+
+Serves a ROM to GDB over the GDB Remote Serial Protocol on a local TCP port, stopped before
+its first instruction. Connect with `target remote localhost:PORT`. GDB reads the registers
+V0-VF, I, PC, SP, DT and ST from the stub, and can read and write memory, set breakpoints,
+step and continue. `monitor help` lists the stub's own commands, such as pressing keys.
+GDB has no CHIP-8 architecture, so use gdb-multiarch rather than a GDB built for one host
+architecture, and expect no disassembly.
+  --port=N        Port to listen on. Defaults to 1234.
+  --ipf=N         Instructions per frame, between timer ticks, from 1 to 10000.
+                  Defaults to 10.
+  --source_map=F  Source map from the assembler's -m, for `monitor source` and
+                  `monitor line`.";
+
+const DEFAULT_PORT: u16 = 1234;
+
+struct Options {
+    rom_filepath: String,
+    port: u16,
+    instructions_per_frame: u32,
+    source_map_filepath: Option<String>,
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args() {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match run(&options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// @return None if help was requested.
+fn parse_args() -> Result<Option<Options>, String> {
+    let mut rom_filepath: Option<String> = None;
+    let mut options = Options {
+        rom_filepath: String::new(),
+        port: DEFAULT_PORT,
+        instructions_per_frame: DEFAULT_INSTRUCTIONS_PER_FRAME,
+        source_map_filepath: None,
+    };
+    for arg in env::args().skip(1) {
+        match arg.split_once('=') {
+            _ if arg == "--help" => return Ok(None),
+            Some(("--port", value)) => {
+                options.port = value
+                    .parse()
+                    .map_err(|_| format!("Invalid port: {}", value))?
+            }
+            Some(("--ipf", value)) => {
+                options.instructions_per_frame = value
+                    .parse()
+                    .ok()
+                    .filter(|ipf| (1..=MAX_INSTRUCTIONS_PER_FRAME).contains(ipf))
+                    .ok_or(format!("Invalid ipf: {}", value))?
+            }
+            Some(("--source_map", value)) => options.source_map_filepath = Some(value.to_string()),
+            // Read by logger::from_env_args.
+            Some(("--log_file", _)) => {}
+            _ if arg.starts_with("--") => return Err(format!("Unknown flag: {}", arg)),
+            _ if rom_filepath.is_none() => rom_filepath = Some(arg),
+            _ => return Err(format!("Unexpected argument: {}", arg)),
+        }
+    }
+    options.rom_filepath = rom_filepath.ok_or("Expected a ROM file.")?;
+    Ok(Some(options))
+}
+
+fn run(options: &Options) -> Result<(), String> {
+    let program = fs::read(&options.rom_filepath)
+        .map_err(|e| format!("Error reading {}: {}", options.rom_filepath, e))?;
+    if program.len() > MAX_PROGRAM_BYTES {
+        return Err(format!(
+            "ROM too large: {} is {} bytes, and at most {} fit in memory.",
+            options.rom_filepath,
+            program.len(),
+            MAX_PROGRAM_BYTES
+        ));
+    }
+    let source_map = match options.source_map_filepath {
+        Some(ref source_map_filepath) => {
+            let text = fs::read_to_string(source_map_filepath)
+                .map_err(|e| format!("Error reading {}: {}", source_map_filepath, e))?;
+            Some(
+                SourceMap::parse(&text)
+                    .map_err(|e| format!("Error in {}: {}", source_map_filepath, e))?,
+            )
+        }
+        None => None,
+    };
+    let mut stub = GdbStub::new(
+        Chip8::new(logger::from_env_args()),
+        &program,
+        source_map,
+        options.instructions_per_frame,
+    );
+
+    let listener = TcpListener::bind(("127.0.0.1", options.port))
+        .map_err(|e| format!("Error listening on port {}: {}", options.port, e))?;
+    println!("Waiting for GDB on localhost:{}", options.port);
+    let (stream, addr) = listener
+        .accept()
+        .map_err(|e| format!("Error accepting a connection: {}", e))?;
+    println!(
+        "GDB connected from {}, stopped at {:#x}",
+        addr,
+        stub.get_chip8().get_program_counter()
+    );
+    serve(&mut stub, stream).map_err(|e| format!("Connection error: {}", e))?;
+    println!("GDB disconnected");
+    Ok(())
+}
+
+/// Answer packets until GDB detaches, kills the program or closes the connection.
+fn serve(stub: &mut GdbStub, stream: TcpStream) -> io::Result<()> {
+    stream.set_nodelay(true)?;
+    let mut connection = Connection::new(stream);
+    while let Some(input) = connection.read_input()? {
+        let Input::Packet(packet) = input else {
+            continue;
+        };
+        match stub.handle_packet(&packet) {
+            Action::Reply(reply) => connection.send_packet(reply.as_bytes())?,
+            Action::StartNoAckMode => {
+                connection.send_packet(b"OK")?;
+                connection.set_no_ack(true);
+            }
+            Action::Resume { step } => {
+                let stream = connection.get_stream();
+                let stop = stub.resume(step, || is_interrupted(stream));
+                if let Some(message) = stop.message {
+                    connection
+                        .send_packet(format!("O{}", encode_hex(message.as_bytes())).as_bytes())?;
+                }
+                connection.send_packet(stop.reply.as_bytes())?;
+            }
+            Action::Detach => {
+                connection.send_packet(b"OK")?;
+                return Ok(());
+            }
+            Action::Kill => return Ok(()),
+        }
+    }
+    Ok(())
+}
+
+/// @return true if GDB has sent an interrupt, without waiting for input. The interrupt is
+/// left to read, and skipped as it arrives between packets. It may follow other bytes, such
+/// as an acknowledgement, so all the pending input is checked.
+fn is_interrupted(stream: &TcpStream) -> bool {
+    let mut pending = [0; 256];
+    if stream.set_nonblocking(true).is_err() {
+        return false;
+    }
+    let interrupted = match stream.peek(&mut pending) {
+        Ok(len) => pending[..len].contains(&INTERRUPT),
+        Err(_) => false,
+    };
+    let _ = stream.set_nonblocking(false);
+    interrupted
+}
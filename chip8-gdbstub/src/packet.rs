@@ -0,0 +1,166 @@
+use std::io;
+use std::io::{Read, Write};
+
+/// The byte GDB sends outside of any packet to interrupt a running program.
+pub const INTERRUPT: u8 = 0x03;
+
+/// What GDB sent.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Input {
+    /// A packet with its escapes removed.
+    Packet(Vec<u8>),
+    /// A packet whose checksum did not match.
+    BadChecksum,
+    /// GDB acknowledged the last packet.
+    Ack,
+    /// GDB asked for the last packet again.
+    Nack,
+    Interrupt,
+}
+
+/// @return the modulo 256 sum of the bytes, which ends each packet.
+pub fn checksum(data: &[u8]) -> u8 {
+    data.iter().fold(0, |sum, byte| sum.wrapping_add(*byte))
+}
+
+/// @return a packet framed as `$data#checksum`, with the bytes that have a meaning in the
+/// protocol escaped.
+pub fn encode_packet(data: &[u8]) -> Vec<u8> {
+    let mut escaped = Vec::with_capacity(data.len());
+    for byte in data.iter() {
+        match byte {
+            b'$' | b'#' | b'}' | b'*' => escaped.extend([b'}', byte ^ 0x20]),
+            _ => escaped.push(*byte),
+        }
+    }
+    let mut packet = vec![b'$'];
+    packet.extend(&escaped);
+    packet.extend(format!("#{:02x}", checksum(&escaped)).as_bytes());
+    packet
+}
+
+pub fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+pub fn decode_hex(text: &str) -> Result<Vec<u8>, String> {
+    if !text.len().is_multiple_of(2) || !text.is_ascii() {
+        return Err(format!("Invalid hex: {}", text));
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&text[i..i + 2], 16).map_err(|_| format!("Invalid hex: {}", text))
+        })
+        .collect()
+}
+
+/// Both ends of a GDB connection: reads packets and acknowledges them, and sends packets,
+/// resending the last one when GDB asks.
+pub struct Connection<S: Read + Write> {
+    stream: S,
+    /// Set once GDB agrees to `QStartNoAckMode`, after which neither side sends `+`.
+    no_ack: bool,
+    last_packet: Vec<u8>,
+}
+
+impl<S: Read + Write> Connection<S> {
+    pub fn new(stream: S) -> Self {
+        Self {
+            stream,
+            no_ack: false,
+            last_packet: Vec::new(),
+        }
+    }
+
+    pub fn get_stream(&self) -> &S {
+        &self.stream
+    }
+
+    pub fn set_no_ack(&mut self, no_ack: bool) {
+        self.no_ack = no_ack;
+    }
+
+    /// Read the next input, acknowledging packets and asking again for ones that arrived
+    /// corrupted.
+    /// @return None when GDB has closed the connection.
+    pub fn read_input(&mut self) -> io::Result<Option<Input>> {
+        loop {
+            let Some(byte) = self.read_byte()? else {
+                return Ok(None);
+            };
+            let input = match byte {
+                b'+' => Input::Ack,
+                b'-' => {
+                    let packet = self.last_packet.clone();
+                    self.stream.write_all(&packet)?;
+                    self.stream.flush()?;
+                    Input::Nack
+                }
+                INTERRUPT => Input::Interrupt,
+                b'$' => match self.read_packet()? {
+                    Some(input) => input,
+                    None => return Ok(None),
+                },
+                // Anything else between packets is noise.
+                _ => continue,
+            };
+            return Ok(Some(input));
+        }
+    }
+
+    /// Read the rest of a packet after its `$`.
+    fn read_packet(&mut self) -> io::Result<Option<Input>> {
+        let mut escaped = Vec::new();
+        loop {
+            match self.read_byte()? {
+                Some(b'#') => break,
+                Some(byte) => escaped.push(byte),
+                None => return Ok(None),
+            }
+        }
+        let mut sum = [0; 2];
+        for digit in sum.iter_mut() {
+            match self.read_byte()? {
+                Some(byte) => *digit = byte,
+                None => return Ok(None),
+            }
+        }
+
+        let matches = std::str::from_utf8(&sum)
+            .ok()
+            .and_then(|sum| u8::from_str_radix(sum, 16).ok())
+            == Some(checksum(&escaped));
+        if !self.no_ack {
+            self.stream.write_all(if matches { b"+" } else { b"-" })?;
+            self.stream.flush()?;
+        }
+        if !matches {
+            return Ok(Some(Input::BadChecksum));
+        }
+
+        let mut data = Vec::with_capacity(escaped.len());
+        let mut bytes = escaped.into_iter();
+        while let Some(byte) = bytes.next() {
+            match byte {
+                b'}' => data.push(bytes.next().unwrap_or_default() ^ 0x20),
+                _ => data.push(byte),
+            }
+        }
+        Ok(Some(Input::Packet(data)))
+    }
+
+    fn read_byte(&mut self) -> io::Result<Option<u8>> {
+        let mut byte = [0];
+        match self.stream.read(&mut byte)? {
+            0 => Ok(None),
+            _ => Ok(Some(byte[0])),
+        }
+    }
+
+    pub fn send_packet(&mut self, data: &[u8]) -> io::Result<()> {
+        self.last_packet = encode_packet(data);
+        self.stream.write_all(&self.last_packet)?;
+        self.stream.flush()
+    }
+}
@@ -0,0 +1,111 @@
+use crate::packet::*;
+
+use std::io::{Cursor, Read, Write};
+
+/// A stream that reads from a fixed input and records what is written.
+struct TestStream {
+    input: Cursor<Vec<u8>>,
+    output: Vec<u8>,
+}
+
+impl TestStream {
+    fn new(input: &[u8]) -> Self {
+        Self {
+            input: Cursor::new(input.to_vec()),
+            output: Vec::new(),
+        }
+    }
+}
+
+impl Read for TestStream {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.input.read(buf)
+    }
+}
+
+impl Write for TestStream {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.output.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+fn read_all(connection: &mut Connection<TestStream>) -> Vec<Input> {
+    let mut inputs = Vec::new();
+    while let Some(input) = connection.read_input().unwrap() {
+        inputs.push(input);
+    }
+    inputs
+}
+
+#[test]
+fn test_encode_packet() {
+    assert_eq!(b"$OK#9a".to_vec(), encode_packet(b"OK"));
+    assert_eq!(b"$#00".to_vec(), encode_packet(b""));
+    assert_eq!(b"$a}\x03}\x04#62".to_vec(), encode_packet(b"a#$"));
+}
+
+#[test]
+fn test_hex() {
+    assert_eq!("00ff1a", encode_hex(&[0x00, 0xFF, 0x1A]));
+    assert_eq!(Ok(vec![0x00, 0xFF, 0x1A]), decode_hex("00ff1A"));
+    assert_eq!(Err("Invalid hex: abc".to_string()), decode_hex("abc"));
+    assert_eq!(Err("Invalid hex: zz".to_string()), decode_hex("zz"));
+}
+
+#[test]
+fn test_read_packets_and_acknowledge() {
+    let mut connection = Connection::new(TestStream::new(b"+$g#67\x03$m200,2#5d-"));
+    assert_eq!(
+        vec![
+            Input::Ack,
+            Input::Packet(b"g".to_vec()),
+            Input::Interrupt,
+            Input::Packet(b"m200,2".to_vec()),
+            Input::Nack,
+        ],
+        read_all(&mut connection)
+    );
+    assert_eq!(b"++".to_vec(), connection.get_stream().output);
+}
+
+#[test]
+fn test_bad_checksum_is_refused() {
+    let mut connection = Connection::new(TestStream::new(b"$g#00"));
+    assert_eq!(vec![Input::BadChecksum], read_all(&mut connection));
+    assert_eq!(b"-".to_vec(), connection.get_stream().output);
+}
+
+#[test]
+fn test_escaped_packet() {
+    let mut connection = Connection::new(TestStream::new(b"$X}\x03#d8"));
+    assert_eq!(
+        vec![Input::Packet(b"X#".to_vec())],
+        read_all(&mut connection)
+    );
+}
+
+#[test]
+fn test_nack_resends_last_packet() {
+    let mut connection = Connection::new(TestStream::new(b"-"));
+    connection.send_packet(b"OK").unwrap();
+    read_all(&mut connection);
+    assert_eq!(b"$OK#9a$OK#9a".to_vec(), connection.get_stream().output);
+}
+
+#[test]
+fn test_no_ack_mode() {
+    let mut connection = Connection::new(TestStream::new(b"$g#67"));
+    connection.set_no_ack(true);
+    read_all(&mut connection);
+    assert!(connection.get_stream().output.is_empty());
+}
+
+#[test]
+fn test_truncated_packet() {
+    let mut connection = Connection::new(TestStream::new(b"$g#6"));
+    assert!(read_all(&mut connection).is_empty());
+}
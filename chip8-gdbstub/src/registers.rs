@@ -0,0 +1,169 @@
+use chip8::Chip8;
+
+/// A register as GDB sees it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Register {
+    pub name: &'static str,
+    pub bitsize: usize,
+    /// A GDB type name, so that I and PC are shown as addresses.
+    pub gdb_type: &'static str,
+}
+
+const fn register(name: &'static str, bitsize: usize, gdb_type: &'static str) -> Register {
+    Register {
+        name,
+        bitsize,
+        gdb_type,
+    }
+}
+
+/// The registers in the order GDB numbers them and lays them out in `g` packets: V0 to VF,
+/// then I, PC, the stack pointer and the delay and sound timers.
+pub const REGISTERS: [Register; 21] = [
+    register("v0", 8, "uint8"),
+    register("v1", 8, "uint8"),
+    register("v2", 8, "uint8"),
+    register("v3", 8, "uint8"),
+    register("v4", 8, "uint8"),
+    register("v5", 8, "uint8"),
+    register("v6", 8, "uint8"),
+    register("v7", 8, "uint8"),
+    register("v8", 8, "uint8"),
+    register("v9", 8, "uint8"),
+    register("va", 8, "uint8"),
+    register("vb", 8, "uint8"),
+    register("vc", 8, "uint8"),
+    register("vd", 8, "uint8"),
+    register("ve", 8, "uint8"),
+    register("vf", 8, "uint8"),
+    register("i", 16, "data_ptr"),
+    register("pc", 16, "code_ptr"),
+    register("sp", 8, "uint8"),
+    register("dt", 8, "uint8"),
+    register("st", 8, "uint8"),
+];
+
+const I: usize = 16;
+const PC: usize = 17;
+const SP: usize = 18;
+const DT: usize = 19;
+const ST: usize = 20;
+
+/// The last address in memory. The program counter wraps around to 0 after it.
+pub const MAX_PROGRAM_COUNTER: u16 = 0xFFF;
+
+/// @return the address, if it is in memory. Chip8 would wrap a larger one, but GDB asking
+/// for one is more likely a mistake.
+pub fn check_program_counter(addr: u16) -> Result<u16, String> {
+    if addr > MAX_PROGRAM_COUNTER {
+        return Err(format!("Program counter out of range: {:#x}", addr));
+    }
+    Ok(addr)
+}
+
+/// @return the target description GDB reads with `qXfer:features:read:target.xml`. GDB has
+/// no CHIP-8 architecture, so the description names none and only lists the registers; a
+/// GDB built for a single host architecture may reject them, so use `gdb-multiarch`.
+pub fn target_xml() -> String {
+    let mut xml = String::from("<?xml version=\"1.0\"?>\n");
+    xml += "<!DOCTYPE target SYSTEM \"gdb-target.dtd\">\n";
+    xml += "<target version=\"1.0\">\n";
+    xml += "  <feature name=\"org.chip8.cpu\">\n";
+    for (regnum, register) in REGISTERS.iter().enumerate() {
+        xml += &format!(
+            "    <reg name=\"{}\" bitsize=\"{}\" type=\"{}\" regnum=\"{}\"/>\n",
+            register.name, register.bitsize, register.gdb_type, regnum
+        );
+    }
+    xml += "  </feature>\n";
+    xml += "</target>\n";
+    xml
+}
+
+/// @return the value of a register in target byte order, which is little-endian as GDB
+/// assumes without a CHIP-8 architecture of its own, or None for an unknown register.
+pub fn read_register(chip8: &Chip8, regnum: usize) -> Option<Vec<u8>> {
+    let value = match regnum {
+        0..16 => chip8.get_register(regnum as u8) as u16,
+        I => chip8.get_pointer(),
+        PC => chip8.get_program_counter(),
+        SP => chip8.get_stack_pointer() as u16,
+        DT => chip8.get_delay_timer() as u16,
+        ST => chip8.get_sound_timer() as u16,
+        _ => return None,
+    };
+    let bytes = value.to_le_bytes();
+    Some(bytes[..REGISTERS[regnum].bitsize / 8].to_vec())
+}
+
+/// Write a register from bytes in target byte order.
+pub fn write_register(chip8: &mut Chip8, regnum: usize, bytes: &[u8]) -> Result<(), String> {
+    let register = REGISTERS
+        .get(regnum)
+        .ok_or(format!("Unknown register: {}", regnum))?;
+    if bytes.len() != register.bitsize / 8 {
+        return Err(format!(
+            "Expected {} bytes for {}, found {}.",
+            register.bitsize / 8,
+            register.name,
+            bytes.len()
+        ));
+    }
+    let value = match bytes {
+        [byte] => *byte as u16,
+        [low, high] => u16::from_le_bytes([*low, *high]),
+        _ => unreachable!(),
+    };
+    match regnum {
+        0..16 => chip8.set_register(regnum as u8, value as u8),
+        I => chip8.set_pointer(value),
+        PC => chip8.set_program_counter(check_program_counter(value)?),
+        // The stack pointer follows calls and returns, so it can only be written unchanged,
+        // as a `G` packet does.
+        SP if value as usize == chip8.get_stack_pointer() => {}
+        SP => return Err("The stack pointer cannot be written.".to_string()),
+        DT => chip8.set_delay_timer(value as u8),
+        _ => chip8.set_sound_timer(value as u8),
+    }
+    Ok(())
+}
+
+/// @return all the registers as one block, as in a `g` packet.
+pub fn read_registers(chip8: &Chip8) -> Vec<u8> {
+    (0..REGISTERS.len())
+        .flat_map(|regnum| read_register(chip8, regnum).unwrap())
+        .collect()
+}
+
+/// Write all the registers from one block, as in a `G` packet.
+pub fn write_registers(chip8: &mut Chip8, bytes: &[u8]) -> Result<(), String> {
+    let size: usize = REGISTERS.iter().map(|register| register.bitsize / 8).sum();
+    if bytes.len() != size {
+        return Err(format!(
+            "Expected {} bytes of registers, found {}.",
+            size,
+            bytes.len()
+        ));
+    }
+    // Check the registers that can fail before writing any.
+    let offset_of = |regnum: usize| -> usize {
+        REGISTERS[..regnum]
+            .iter()
+            .map(|register| register.bitsize / 8)
+            .sum()
+    };
+    if bytes[offset_of(SP)] as usize != chip8.get_stack_pointer() {
+        return Err("The stack pointer cannot be written.".to_string());
+    }
+    check_program_counter(u16::from_le_bytes([
+        bytes[offset_of(PC)],
+        bytes[offset_of(PC) + 1],
+    ]))?;
+    let mut offset = 0;
+    for (regnum, register) in REGISTERS.iter().enumerate() {
+        let len = register.bitsize / 8;
+        write_register(chip8, regnum, &bytes[offset..offset + len])?;
+        offset += len;
+    }
+    Ok(())
+}
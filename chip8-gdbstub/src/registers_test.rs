@@ -0,0 +1,89 @@
+use crate::registers::*;
+
+use chip8::Chip8;
+use chip8::logger::Logger;
+
+fn new_chip8() -> Chip8 {
+    let mut chip8 = Chip8::new(Logger::new_null_logger());
+    chip8.initialize(&[0x00, 0xE0]);
+    chip8
+}
+
+#[test]
+fn test_target_xml() {
+    let xml = target_xml();
+    assert!(xml.contains("<feature name=\"org.chip8.cpu\">\n"));
+    assert!(xml.contains("    <reg name=\"v0\" bitsize=\"8\" type=\"uint8\" regnum=\"0\"/>\n"));
+    assert!(xml.contains("    <reg name=\"vf\" bitsize=\"8\" type=\"uint8\" regnum=\"15\"/>\n"));
+    assert!(xml.contains("    <reg name=\"i\" bitsize=\"16\" type=\"data_ptr\" regnum=\"16\"/>\n"));
+    assert!(
+        xml.contains("    <reg name=\"pc\" bitsize=\"16\" type=\"code_ptr\" regnum=\"17\"/>\n")
+    );
+    assert!(xml.contains("    <reg name=\"st\" bitsize=\"8\" type=\"uint8\" regnum=\"20\"/>\n"));
+    assert!(xml.ends_with("</target>\n"));
+}
+
+#[test]
+fn test_read_registers() {
+    let mut chip8 = new_chip8();
+    chip8.set_register(0xA, 0x12);
+    chip8.set_pointer(0x345);
+    chip8.set_delay_timer(7);
+    let bytes = read_registers(&chip8);
+    assert_eq!(23, bytes.len());
+    assert_eq!(0x12, bytes[0xA]);
+    // I and PC are little-endian.
+    assert_eq!([0x45, 0x03, 0x00, 0x02], bytes[16..20]);
+    assert_eq!([0, 7, 0], bytes[20..]);
+    assert_eq!(Some(vec![0x00, 0x02]), read_register(&chip8, 17));
+    assert_eq!(None, read_register(&chip8, 21));
+}
+
+#[test]
+fn test_write_register() {
+    let mut chip8 = new_chip8();
+    write_register(&mut chip8, 3, &[0x42]).unwrap();
+    write_register(&mut chip8, 17, &[0x0A, 0x02]).unwrap();
+    write_register(&mut chip8, 20, &[0x05]).unwrap();
+    assert_eq!(0x42, chip8.get_register(3));
+    assert_eq!(0x20A, chip8.get_program_counter());
+    assert_eq!(5, chip8.get_sound_timer());
+    assert_eq!(
+        Err("Expected 2 bytes for i, found 1.".to_string()),
+        write_register(&mut chip8, 16, &[0x01])
+    );
+    assert_eq!(
+        Err("The stack pointer cannot be written.".to_string()),
+        write_register(&mut chip8, 18, &[0x01])
+    );
+    assert!(write_register(&mut chip8, 18, &[0x00]).is_ok());
+    assert_eq!(
+        Err("Program counter out of range: 0x1000".to_string()),
+        write_register(&mut chip8, 17, &[0x00, 0x10])
+    );
+    assert_eq!(0x20A, chip8.get_program_counter());
+}
+
+#[test]
+fn test_write_registers() {
+    let mut chip8 = new_chip8();
+    let mut bytes = read_registers(&chip8);
+    bytes[1] = 0x11;
+    bytes[16..18].copy_from_slice(&[0x00, 0x03]);
+    write_registers(&mut chip8, &bytes).unwrap();
+    assert_eq!(0x11, chip8.get_register(1));
+    assert_eq!(0x300, chip8.get_pointer());
+
+    // Nothing is written if the stack pointer changes.
+    bytes[1] = 0x22;
+    bytes[20] = 1;
+    assert!(write_registers(&mut chip8, &bytes).is_err());
+    assert_eq!(0x11, chip8.get_register(1));
+    assert!(write_registers(&mut chip8, &bytes[1..]).is_err());
+
+    // Nor if the program counter is out of range.
+    bytes[20] = 0;
+    bytes[18..20].copy_from_slice(&[0x00, 0x10]);
+    assert!(write_registers(&mut chip8, &bytes).is_err());
+    assert_eq!(0x11, chip8.get_register(1));
+}
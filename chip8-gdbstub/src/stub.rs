@@ -0,0 +1,386 @@
+use crate::packet::{decode_hex, encode_hex};
+use crate::registers;
+
+use chip8::Chip8;
+use chip8::Chip8Keys;
+use chip8::debugger::{Debugger, StopReason};
+use chip8::source_map::SourceMap;
+
+/// How many instructions `resume` runs between checks for an interrupt from GDB.
+const INTERRUPT_CHECK_INSTRUCTIONS: u32 = 1000;
+
+const SIGINT: u8 = 2;
+const SIGTRAP: u8 = 5;
+
+const MONITOR_HELP: &str = "Commands:
+  keys [K...]          Hold the hex keys K, or release all keys.
+  display              Show the display.
+  source [ADDR]        Show the source line of ADDR or PC. Needs --source_map.
+  line N               Show the address of source line N, for `break *ADDR`.
+  code-writes on|off   Stop after instructions that write over code which has run.
+  reset                Restart the program, keeping breakpoints.
+";
+
+/// What the server should do after a packet.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    Reply(String),
+    /// Reply `OK`, then stop sending and expecting acknowledgements.
+    StartNoAckMode,
+    /// Run the program with `resume` and reply with its stop.
+    Resume {
+        step: bool,
+    },
+    /// Reply `OK` and close the connection.
+    Detach,
+    /// Close the connection without a reply.
+    Kill,
+}
+
+/// Why the program stopped, with anything to show in GDB's console before the stop reply.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stop {
+    pub reply: String,
+    pub message: Option<String>,
+}
+
+/// A CHIP-8 program debugged over the GDB Remote Serial Protocol.
+///
+/// The stub answers one packet at a time with `handle_packet`, leaving the connection to
+/// the caller. Registers follow `registers::REGISTERS`, memory addresses are CHIP-8
+/// addresses and software breakpoints are kept by a `Debugger`. The program runs with a
+/// frame of `instructions_per_frame` instructions between timer ticks, as the frontends do,
+/// but without waiting for real time.
+pub struct GdbStub {
+    chip8: Chip8,
+    program: Vec<u8>,
+    debugger: Debugger,
+    source_map: Option<SourceMap>,
+    instructions_per_frame: u32,
+    /// Instructions run since the timers last ticked.
+    frame_instructions: u32,
+}
+
+impl GdbStub {
+    pub fn new(
+        mut chip8: Chip8,
+        program: &[u8],
+        source_map: Option<SourceMap>,
+        instructions_per_frame: u32,
+    ) -> Self {
+        chip8.initialize(program);
+        Self {
+            chip8,
+            program: program.to_vec(),
+            debugger: Debugger::new(),
+            source_map,
+            instructions_per_frame,
+            frame_instructions: 0,
+        }
+    }
+
+    pub fn get_chip8(&self) -> &Chip8 {
+        &self.chip8
+    }
+
+    /// @return what to do for a packet, with an empty reply for packets the stub does not
+    /// support, which tells GDB to do without them.
+    pub fn handle_packet(&mut self, packet: &[u8]) -> Action {
+        let packet = String::from_utf8_lossy(packet);
+        let reply = |result: Result<String, String>| match result {
+            Ok(reply) => Action::Reply(reply),
+            // GDB only shows the error number.
+            Err(_) => Action::Reply("E01".to_string()),
+        };
+        // Every command is one ASCII character, so anything else is an unsupported packet.
+        let Some((command, args)) = packet.split_at_checked(packet.len().min(1)) else {
+            return Action::Reply(String::new());
+        };
+        match command {
+            "?" => Action::Reply(self.get_stop_reply()),
+            "g" => Action::Reply(encode_hex(&registers::read_registers(&self.chip8))),
+            "G" => reply(
+                decode_hex(args)
+                    .and_then(|bytes| registers::write_registers(&mut self.chip8, &bytes))
+                    .map(|_| "OK".to_string()),
+            ),
+            "p" => reply(self.read_register(args)),
+            "P" => reply(self.write_register(args)),
+            "m" => reply(self.read_memory(args)),
+            "M" => reply(self.write_memory(args)),
+            "Z" | "z" => self.set_breakpoint(command == "Z", args),
+            "s" | "c" => {
+                if !args.is_empty() {
+                    match parse_hex(args).and_then(registers::check_program_counter) {
+                        Ok(addr) => self.chip8.set_program_counter(addr),
+                        Err(e) => return reply(Err(e)),
+                    }
+                }
+                Action::Resume {
+                    step: command == "s",
+                }
+            }
+            "H" => Action::Reply("OK".to_string()),
+            "D" => Action::Detach,
+            "k" => Action::Kill,
+            _ => self.handle_query(&packet),
+        }
+    }
+
+    fn handle_query(&mut self, packet: &str) -> Action {
+        let reply = match packet.split_once(':') {
+            Some(("qSupported", _)) => {
+                "PacketSize=1000;qXfer:features:read+;QStartNoAckMode+".to_string()
+            }
+            Some(("qXfer", args)) => match args.strip_prefix("features:read:target.xml:") {
+                Some(range) => match read_chunk(&registers::target_xml(), range) {
+                    Ok(chunk) => chunk,
+                    Err(_) => "E01".to_string(),
+                },
+                None => "E00".to_string(),
+            },
+            _ if packet == "QStartNoAckMode" => return Action::StartNoAckMode,
+            _ if packet == "qAttached" => "1".to_string(),
+            _ => match packet.strip_prefix("qRcmd,") {
+                Some(command) => match decode_hex(command) {
+                    Ok(command) => encode_hex(
+                        self.monitor(String::from_utf8_lossy(&command).trim())
+                            .as_bytes(),
+                    ),
+                    Err(_) => "E01".to_string(),
+                },
+                None => String::new(),
+            },
+        };
+        Action::Reply(reply)
+    }
+
+    /// @return the stop reply for the program as it is now.
+    fn get_stop_reply(&self) -> String {
+        if self.chip8.is_exited() {
+            "W00".to_string()
+        } else {
+            format!("S{:02x}", SIGTRAP)
+        }
+    }
+
+    fn read_register(&self, args: &str) -> Result<String, String> {
+        let regnum = parse_hex(args)? as usize;
+        let bytes = registers::read_register(&self.chip8, regnum)
+            .ok_or(format!("Unknown register: {}", args))?;
+        Ok(encode_hex(&bytes))
+    }
+
+    fn write_register(&mut self, args: &str) -> Result<String, String> {
+        let (regnum, value) = args
+            .split_once('=')
+            .ok_or(format!("Invalid register write: {}", args))?;
+        let bytes = decode_hex(value)?;
+        registers::write_register(&mut self.chip8, parse_hex(regnum)? as usize, &bytes)?;
+        Ok("OK".to_string())
+    }
+
+    fn read_memory(&self, args: &str) -> Result<String, String> {
+        let (addr, len) = parse_range(args)?;
+        let bytes = self.chip8.get_memory(addr, len);
+        if bytes.is_empty() && len > 0 {
+            return Err(format!("Address out of range: {:#x}", addr));
+        }
+        Ok(encode_hex(bytes))
+    }
+
+    fn write_memory(&mut self, args: &str) -> Result<String, String> {
+        let (range, data) = args
+            .split_once(':')
+            .ok_or(format!("Invalid memory write: {}", args))?;
+        let (addr, len) = parse_range(range)?;
+        let bytes = decode_hex(data)?;
+        if bytes.len() != len || self.chip8.get_memory(addr, len).len() != len {
+            return Err(format!("Invalid memory write: {}", args));
+        }
+        self.chip8.set_memory(addr, &bytes);
+        Ok("OK".to_string())
+    }
+
+    /// Set or clear a breakpoint from a `Z` or `z` packet. Software and hardware breakpoints
+    /// are the same, and watchpoints are not supported.
+    fn set_breakpoint(&mut self, set: bool, args: &str) -> Action {
+        let mut words = args.split(',');
+        if !matches!(words.next(), Some("0" | "1")) {
+            return Action::Reply(String::new());
+        }
+        let Some(Ok(addr)) = words.next().map(parse_hex) else {
+            return Action::Reply("E01".to_string());
+        };
+        if set {
+            self.debugger.add_breakpoint(addr);
+        } else {
+            self.debugger.remove_breakpoint(addr);
+        }
+        Action::Reply("OK".to_string())
+    }
+
+    /// Run the program until it stops at a breakpoint, exits or waits for a key, or for one
+    /// instruction if stepping. `interrupted` is checked now and then while running, and
+    /// stops the program when it returns true.
+    pub fn resume(&mut self, step: bool, mut interrupted: impl FnMut() -> bool) -> Stop {
+        let trap = |message: Option<String>| Stop {
+            reply: format!("S{:02x}", SIGTRAP),
+            message,
+        };
+        let mut count: u32 = 0;
+        loop {
+            if self.frame_instructions >= self.instructions_per_frame {
+                self.chip8.tick_timers();
+                self.frame_instructions = 0;
+            }
+            let pc = self.chip8.get_program_counter();
+            let reason = if step {
+                self.debugger.step(&mut self.chip8)
+            } else {
+                self.debugger.run(&mut self.chip8, 1)
+            };
+            // Every instruction but a jump to itself, which exits, moves the program counter.
+            if self.chip8.get_program_counter() != pc {
+                self.frame_instructions += 1;
+            }
+
+            match reason {
+                StopReason::Breakpoint { .. } => return trap(None),
+                StopReason::CodeModified { pc, addr } => {
+                    return trap(Some(format!(
+                        "{:#x}: Overwrote the instruction at {:#x}, which has already run.\n",
+                        pc, addr
+                    )));
+                }
+                StopReason::WaitingForKey => {
+                    return trap(Some(
+                        "Waiting for a key press. Press keys with `monitor keys`.\n".to_string(),
+                    ));
+                }
+                StopReason::Exited => {
+                    return Stop {
+                        reply: self.get_stop_reply(),
+                        message: None,
+                    };
+                }
+                StopReason::InstructionLimit if step => return trap(None),
+                StopReason::InstructionLimit => {}
+            }
+            count = count.wrapping_add(1);
+            if count.is_multiple_of(INTERRUPT_CHECK_INSTRUCTIONS) && interrupted() {
+                return Stop {
+                    reply: format!("S{:02x}", SIGINT),
+                    message: None,
+                };
+            }
+        }
+    }
+
+    /// Run a `monitor` command.
+    /// @return the output to show in GDB's console.
+    pub fn monitor(&mut self, command: &str) -> String {
+        let mut words = command.split_whitespace();
+        let name = words.next().unwrap_or_default();
+        let args: Vec<&str> = words.collect();
+        let result = match (name, &args[..]) {
+            ("help", []) => Ok(MONITOR_HELP.to_string()),
+            ("keys", keys) => self.monitor_keys(keys),
+            ("display", []) => Ok(self.chip8.get_display().to_text('#', '.')),
+            ("source", []) => self.monitor_source(self.chip8.get_program_counter()),
+            ("source", [addr]) => parse_addr(addr).and_then(|addr| self.monitor_source(addr)),
+            ("line", [line_num]) => self.monitor_line(line_num),
+            ("code-writes", [on_off]) => match *on_off {
+                "on" | "off" => {
+                    self.debugger.set_break_on_code_write(*on_off == "on");
+                    Ok(format!("Stopping on code writes is {}.\n", on_off))
+                }
+                _ => Err(format!("Expected on or off, found {}.", on_off)),
+            },
+            ("reset", []) => {
+                self.chip8.initialize(&self.program);
+                self.frame_instructions = 0;
+                Ok("Restarted the program.\n".to_string())
+            }
+            _ => Err(format!("Unknown command: {}. Try `monitor help`.", command)),
+        };
+        result.unwrap_or_else(|e| format!("{}\n", e))
+    }
+
+    fn monitor_keys(&mut self, words: &[&str]) -> Result<String, String> {
+        let mut keys: Chip8Keys = 0;
+        for word in words {
+            let key = u8::from_str_radix(word, 16)
+                .ok()
+                .filter(|key| *key <= 0xF)
+                .ok_or(format!("Invalid key: {}", word))?;
+            keys |= 1 << key;
+        }
+        self.chip8.set_keys(keys);
+        if words.is_empty() {
+            return Ok("Released all keys.\n".to_string());
+        }
+        Ok(format!(
+            "Holding keys {}.\n",
+            words.join(" ").to_uppercase()
+        ))
+    }
+
+    fn get_source_map(&self) -> Result<&SourceMap, String> {
+        self.source_map
+            .as_ref()
+            .ok_or("No source map. Start the stub with --source_map.".to_string())
+    }
+
+    fn monitor_source(&self, addr: u16) -> Result<String, String> {
+        let source_map = self.get_source_map()?;
+        let entry = source_map
+            .get_entry(addr)
+            .ok_or(format!("No source line for {:#x}.", addr))?;
+        Ok(format!(
+            "{:#x} is at {}:{}\n",
+            addr,
+            source_map.source_path.as_deref().unwrap_or_default(),
+            entry.line_num
+        ))
+    }
+
+    fn monitor_line(&self, line_num: &str) -> Result<String, String> {
+        let source_map = self.get_source_map()?;
+        let line_num: usize = line_num
+            .parse()
+            .map_err(|_| format!("Invalid line number: {}", line_num))?;
+        let addr = source_map
+            .find_addr(line_num)
+            .ok_or(format!("No code at or after line {}.", line_num))?;
+        let entry = source_map.get_entry(addr).unwrap();
+        Ok(format!("Line {} is at {:#x}\n", entry.line_num, addr))
+    }
+}
+
+fn parse_hex(text: &str) -> Result<u16, String> {
+    u16::from_str_radix(text, 16).map_err(|_| format!("Invalid hex: {}", text))
+}
+
+/// Parse an address typed in a `monitor` command, in hex with or without `0x`.
+fn parse_addr(text: &str) -> Result<u16, String> {
+    parse_hex(text.strip_prefix("0x").unwrap_or(text))
+}
+
+/// Parse the `addr,length` of a memory packet.
+fn parse_range(text: &str) -> Result<(u16, usize), String> {
+    let (addr, len) = text
+        .split_once(',')
+        .ok_or(format!("Invalid range: {}", text))?;
+    Ok((parse_hex(addr)?, parse_hex(len)? as usize))
+}
+
+/// @return the part of `text` an `offset,length` asks for, starting with `m` if there is
+/// more after it or `l` if it is the last.
+fn read_chunk(text: &str, range: &str) -> Result<String, String> {
+    let (offset, len) = parse_range(range)?;
+    let offset = (offset as usize).min(text.len());
+    let end = (offset + len).min(text.len());
+    let marker = if end == text.len() { 'l' } else { 'm' };
+    Ok(format!("{}{}", marker, &text[offset..end]))
+}
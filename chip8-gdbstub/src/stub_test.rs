@@ -0,0 +1,304 @@
+use crate::packet::encode_hex;
+use crate::stub::*;
+
+use chip8::Chip8;
+use chip8::logger::Logger;
+use chip8::source_map::SourceMap;
+use chip8_assembler::assemble;
+
+const PROGRAM: &str = "main:
+  LD V0, 1
+  ADD V0, 2
+loop:
+  ADD V1, 1
+  SE V1, 5
+  JP loop
+  EXIT
+";
+
+fn new_stub(text: &str) -> GdbStub {
+    let program = assemble(text, 0x200, 0x1000).unwrap();
+    GdbStub::new(Chip8::new(Logger::new_null_logger()), &program, None, 10)
+}
+
+fn reply(stub: &mut GdbStub, packet: &str) -> String {
+    match stub.handle_packet(packet.as_bytes()) {
+        Action::Reply(reply) => reply,
+        action => panic!("Expected a reply to {}, found {:?}", packet, action),
+    }
+}
+
+fn monitor(stub: &mut GdbStub, command: &str) -> String {
+    stub.monitor(command)
+}
+
+fn never() -> bool {
+    false
+}
+
+#[test]
+fn test_queries() {
+    let mut stub = new_stub(PROGRAM);
+    assert_eq!(
+        "PacketSize=1000;qXfer:features:read+;QStartNoAckMode+",
+        reply(&mut stub, "qSupported:multiprocess+;swbreak+")
+    );
+    assert_eq!("S05", reply(&mut stub, "?"));
+    assert_eq!("1", reply(&mut stub, "qAttached"));
+    assert_eq!("OK", reply(&mut stub, "Hg0"));
+    assert_eq!("", reply(&mut stub, "vMustReplyEmpty"));
+    assert_eq!(
+        Action::StartNoAckMode,
+        stub.handle_packet(b"QStartNoAckMode")
+    );
+    assert_eq!(Action::Detach, stub.handle_packet(b"D"));
+    assert_eq!(Action::Kill, stub.handle_packet(b"k"));
+}
+
+#[test]
+fn test_target_xml_in_chunks() {
+    let mut stub = new_stub(PROGRAM);
+    let first = reply(&mut stub, "qXfer:features:read:target.xml:0,a");
+    assert_eq!("m<?xml vers", first);
+    let last = reply(&mut stub, "qXfer:features:read:target.xml:a,1000");
+    assert!(last.starts_with("lion=\"1.0\"?>"));
+    assert!(last.ends_with("</target>\n"));
+    assert_eq!(
+        "E00",
+        reply(&mut stub, "qXfer:features:read:other.xml:0,10")
+    );
+}
+
+#[test]
+fn test_registers() {
+    let mut stub = new_stub(PROGRAM);
+    assert_eq!("0002", reply(&mut stub, "p11"));
+    assert_eq!("OK", reply(&mut stub, "P3=2a"));
+    assert_eq!(0x2A, stub.get_chip8().get_register(3));
+    assert_eq!("E01", reply(&mut stub, "P3=2a2a"));
+    assert_eq!("E01", reply(&mut stub, "p15"));
+    assert_eq!("OK", reply(&mut stub, "P11=fe0f"));
+    assert_eq!(0xFFE, stub.get_chip8().get_program_counter());
+    assert_eq!("OK", reply(&mut stub, "P11=ff0f"));
+    assert_eq!(0xFFF, stub.get_chip8().get_program_counter());
+    assert_eq!("E01", reply(&mut stub, "P11=0010"));
+    assert_eq!(0xFFF, stub.get_chip8().get_program_counter());
+    assert_eq!("OK", reply(&mut stub, "P11=0002"));
+
+    let registers = reply(&mut stub, "g");
+    assert_eq!(46, registers.len());
+    let registers = registers.replacen("00", "07", 1);
+    assert_eq!("OK", reply(&mut stub, &format!("G{}", registers)));
+    assert_eq!(7, stub.get_chip8().get_register(0));
+
+    // PC follows V0-VF and I.
+    let mut registers = registers;
+    registers.replace_range(36..40, "0010");
+    assert_eq!("E01", reply(&mut stub, &format!("G{}", registers)));
+    assert_eq!(0x200, stub.get_chip8().get_program_counter());
+}
+
+#[test]
+fn test_memory() {
+    let mut stub = new_stub(PROGRAM);
+    assert_eq!("60017002", reply(&mut stub, "m200,4"));
+    assert_eq!("OK", reply(&mut stub, "M300,2:abcd"));
+    assert_eq!(&[0xAB, 0xCD], stub.get_chip8().get_memory(0x300, 2));
+    assert_eq!("00", reply(&mut stub, "mfff,2"));
+    assert_eq!("E01", reply(&mut stub, "m1000,2"));
+    assert_eq!("E01", reply(&mut stub, "Mfff,2:abcd"));
+    assert_eq!("E01", reply(&mut stub, "M300,2:ab"));
+}
+
+#[test]
+fn test_step() {
+    let mut stub = new_stub(PROGRAM);
+    assert_eq!(Action::Resume { step: true }, stub.handle_packet(b"s"));
+    let stop = stub.resume(true, never);
+    assert_eq!("S05", stop.reply);
+    assert_eq!(0x202, stub.get_chip8().get_program_counter());
+    assert_eq!(1, stub.get_chip8().get_register(0));
+}
+
+#[test]
+fn test_continue_to_breakpoint_and_exit() {
+    let mut stub = new_stub(PROGRAM);
+    assert_eq!("OK", reply(&mut stub, "Z0,206,2"));
+    assert_eq!(Action::Resume { step: false }, stub.handle_packet(b"c"));
+    assert_eq!("S05", stub.resume(false, never).reply);
+    assert_eq!(0x206, stub.get_chip8().get_program_counter());
+    assert_eq!(1, stub.get_chip8().get_register(1));
+
+    // Continuing from a breakpoint runs the instruction there.
+    assert_eq!("S05", stub.resume(false, never).reply);
+    assert_eq!(2, stub.get_chip8().get_register(1));
+
+    assert_eq!("OK", reply(&mut stub, "z0,206,2"));
+    assert_eq!("W00", stub.resume(false, never).reply);
+    assert_eq!(5, stub.get_chip8().get_register(1));
+    assert_eq!("W00", reply(&mut stub, "?"));
+}
+
+#[test]
+fn test_unsupported_breakpoints() {
+    let mut stub = new_stub(PROGRAM);
+    assert_eq!("", reply(&mut stub, "Z2,300,1"));
+    assert_eq!("E01", reply(&mut stub, "Z0,xyz,2"));
+}
+
+#[test]
+fn test_continue_at_address() {
+    let mut stub = new_stub(PROGRAM);
+    assert_eq!(Action::Resume { step: true }, stub.handle_packet(b"s204"));
+    stub.resume(true, never);
+    assert_eq!(0x206, stub.get_chip8().get_program_counter());
+    assert_eq!(0, stub.get_chip8().get_register(0));
+}
+
+#[test]
+fn test_continue_at_an_address_out_of_range() {
+    let mut stub = new_stub(PROGRAM);
+    assert_eq!("E01", reply(&mut stub, "s1000"));
+    assert_eq!("E01", reply(&mut stub, "cffff"));
+    assert_eq!("E01", reply(&mut stub, "cxyz"));
+    assert_eq!(0x200, stub.get_chip8().get_program_counter());
+}
+
+#[test]
+fn test_step_past_the_end_of_memory() {
+    for (packet, stops) in [("sffe", [0x000, 0x002]), ("sfff", [0x001, 0x003])] {
+        let mut stub = new_stub(PROGRAM);
+        assert_eq!(
+            Action::Resume { step: true },
+            stub.handle_packet(packet.as_bytes())
+        );
+        for stop in stops {
+            assert_eq!("S05", stub.resume(true, never).reply);
+            assert_eq!(stop, stub.get_chip8().get_program_counter());
+            assert_eq!(Action::Resume { step: true }, stub.handle_packet(b"s"));
+        }
+    }
+}
+
+#[test]
+fn test_non_ascii_packet() {
+    let mut stub = new_stub(PROGRAM);
+    match stub.handle_packet(&[0xC3, 0xA9, b'x']) {
+        Action::Reply(reply) => assert_eq!("", reply),
+        action => panic!("Expected an empty reply, found {:?}", action),
+    }
+    match stub.handle_packet(&[0xFF]) {
+        Action::Reply(reply) => assert_eq!("", reply),
+        action => panic!("Expected an empty reply, found {:?}", action),
+    }
+}
+
+#[test]
+fn test_interrupt() {
+    let mut stub = new_stub("loop:\n  ADD V0, 1\n  JP loop");
+    let stop = stub.resume(false, || true);
+    assert_eq!("S02", stop.reply);
+    // Timers tick once a frame while running.
+    assert!(stub.get_chip8().get_register(0) > 0);
+}
+
+#[test]
+fn test_timers_tick_every_frame() {
+    let mut stub =
+        new_stub("LD V0, 10\nLD DT, V0\nloop:\n  LD V1, DT\n  SE V1, 0\n  JP loop\n  EXIT");
+    assert_eq!("W00", stub.resume(false, never).reply);
+    assert_eq!(0, stub.get_chip8().get_delay_timer());
+}
+
+#[test]
+fn test_waiting_for_key() {
+    let mut stub = new_stub("LD V2, K\nEXIT");
+    let stop = stub.resume(false, never);
+    assert_eq!("S05", stop.reply);
+    assert_eq!(
+        Some("Waiting for a key press. Press keys with `monitor keys`.\n".to_string()),
+        stop.message
+    );
+    assert_eq!("Holding keys A.\n", monitor(&mut stub, "keys a"));
+    assert_eq!(0xA, stub.get_chip8().get_register(2));
+    assert_eq!("W00", stub.resume(false, never).reply);
+    assert_eq!("Released all keys.\n", monitor(&mut stub, "keys"));
+    assert_eq!("Invalid key: 10\n", monitor(&mut stub, "keys 10"));
+}
+
+#[test]
+fn test_code_writes() {
+    let text = "main:
+  LD I, main
+  LD V0, 0
+  LD [I], V0
+  EXIT
+";
+    let mut stub = new_stub(text);
+    assert_eq!(
+        "Stopping on code writes is on.\n",
+        monitor(&mut stub, "code-writes on")
+    );
+    let stop = stub.resume(false, never);
+    assert_eq!("S05", stop.reply);
+    assert_eq!(
+        Some("0x204: Overwrote the instruction at 0x200, which has already run.\n".to_string()),
+        stop.message
+    );
+}
+
+#[test]
+fn test_monitor_over_packets() {
+    let mut stub = new_stub(PROGRAM);
+    let command = encode_hex(b"help");
+    let output = reply(&mut stub, &format!("qRcmd,{}", command));
+    assert!(output.starts_with(&encode_hex(b"Commands:\n")));
+    assert_eq!(
+        encode_hex(b"Unknown command: frobnicate. Try `monitor help`.\n"),
+        reply(&mut stub, &format!("qRcmd,{}", encode_hex(b"frobnicate")))
+    );
+}
+
+#[test]
+fn test_monitor_reset_and_display() {
+    let mut stub = new_stub("LD V0, 0\nLD F, V0\nDRW V0, V0, 5\nEXIT");
+    stub.resume(false, never);
+    assert!(stub.get_chip8().is_exited());
+    assert!(monitor(&mut stub, "display").starts_with("####....."));
+    assert_eq!("Restarted the program.\n", monitor(&mut stub, "reset"));
+    assert!(!stub.get_chip8().is_exited());
+    assert_eq!(0x200, stub.get_chip8().get_program_counter());
+    assert!(monitor(&mut stub, "display").starts_with("........."));
+}
+
+#[test]
+fn test_monitor_source() {
+    let program = assemble(PROGRAM, 0x200, 0x1000).unwrap();
+    let source_map = SourceMap::parse("source game.asm\n0x200 2 2\n0x202 3 2\n0x204 5 2").unwrap();
+    let mut stub = GdbStub::new(
+        Chip8::new(Logger::new_null_logger()),
+        &program,
+        Some(source_map),
+        10,
+    );
+    assert_eq!("0x200 is at game.asm:2\n", monitor(&mut stub, "source"));
+    assert_eq!(
+        "0x205 is at game.asm:5\n",
+        monitor(&mut stub, "source 0x205")
+    );
+    assert_eq!(
+        "No source line for 0x300.\n",
+        monitor(&mut stub, "source 300")
+    );
+    assert_eq!("Line 5 is at 0x204\n", monitor(&mut stub, "line 4"));
+    assert_eq!(
+        "No code at or after line 6.\n",
+        monitor(&mut stub, "line 6")
+    );
+
+    let mut stub = new_stub(PROGRAM);
+    assert_eq!(
+        "No source map. Start the stub with --source_map.\n",
+        monitor(&mut stub, "source")
+    );
+}
@@ -0,0 +1,113 @@
+use chip8::{DEFAULT_INSTRUCTIONS_PER_FRAME, MAX_INSTRUCTIONS_PER_FRAME};
+
+pub const USAGE: &str =
+    "Usage: chip8-headless [--frames=N] [--ipf=N] [--input=FILE] [--display=FILE]
+                      [--scale=N] [--state=FILE] [--trace=FILE] [--profile=FILE]
+                      [--symbols=FILE] [--coverage=FILE] [--lcov=FILE]
+                      [--source_map=FILE] [--disassembly=FILE] [--log_file=FILE] ROM
+
+Runs a ROM without a display or keyboard, for the given number of frames or until it exits.
+  --frames=N   Stop after N frames. Defaults to running until the program exits.
+  --ipf=N      Instructions per frame, from 1 to 10000. Defaults to 10.
+  --input=F    Input script of keys held per frame. See chip8::input_script.
+  --display=F  Write the final display to F as .png, .pbm or text. `-` writes text to stdout.
+  --scale=N    Size of a display pixel in PNG output, from 1 to 64. Defaults to 1.
+  --state=F    Write the final registers and memory to F as JSON. `-` writes to stdout.
+  --trace=F    Write a line per instruction with the registers before it ran to F, for
+               chip8-tracediff. See chip8::trace_file.
+  --profile=F  Write a report of where the instructions and estimated COSMAC VIP cycles
+               went to F. `-` writes to stdout.
+  --symbols=F  Name addresses in the profile with a symbol file from the assembler's -s.
+  --coverage=F Write a disassembly to F marking how often each instruction ran and each
+               byte was read as data, with the bytes no jump, call or skip reaches.
+               `-` writes to stdout.
+  --lcov=F     Write line coverage of the assembly source to F as an lcov tracefile.
+               Needs --source_map.
+  --source_map=F Source map from the assembler's -m, for --lcov.
+  --disassembly=F Write a disassembly of the ROM to F, marking the instructions the program
+               wrote over after running them. `-` writes to stdout.";
+
+const MAX_SCALE: u32 = 64;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Options {
+    pub rom_filepath: String,
+    pub frames: Option<u32>,
+    pub instructions_per_frame: u32,
+    pub input_filepath: Option<String>,
+    pub display_filepath: Option<String>,
+    pub scale: u32,
+    pub state_filepath: Option<String>,
+    pub trace_filepath: Option<String>,
+    pub profile_filepath: Option<String>,
+    pub symbols_filepath: Option<String>,
+    pub coverage_filepath: Option<String>,
+    pub lcov_filepath: Option<String>,
+    pub source_map_filepath: Option<String>,
+    pub disassembly_filepath: Option<String>,
+}
+
+/// @return None if help was requested.
+pub fn parse_args(args: &[String]) -> Result<Option<Options>, String> {
+    let mut rom_filepath: Option<String> = None;
+    let mut options = Options {
+        rom_filepath: String::new(),
+        frames: None,
+        instructions_per_frame: DEFAULT_INSTRUCTIONS_PER_FRAME,
+        input_filepath: None,
+        display_filepath: None,
+        scale: 1,
+        state_filepath: None,
+        trace_filepath: None,
+        profile_filepath: None,
+        symbols_filepath: None,
+        coverage_filepath: None,
+        lcov_filepath: None,
+        source_map_filepath: None,
+        disassembly_filepath: None,
+    };
+    let parse_number = |flag: &str, value: &str, min: u32, max: u32| {
+        value
+            .parse::<u32>()
+            .ok()
+            .filter(|number| (min..=max).contains(number))
+            .ok_or(format!("Invalid {}: {}", flag, value))
+    };
+
+    for arg in args {
+        match arg.split_once('=') {
+            _ if arg == "--help" => return Ok(None),
+            Some(("--frames", value)) => {
+                options.frames = Some(parse_number("frames", value, 0, u32::MAX)?)
+            }
+            Some(("--ipf", value)) => {
+                options.instructions_per_frame =
+                    parse_number("ipf", value, 1, MAX_INSTRUCTIONS_PER_FRAME)?
+            }
+            Some(("--input", value)) => options.input_filepath = Some(value.to_string()),
+            Some(("--display", value)) => options.display_filepath = Some(value.to_string()),
+            Some(("--scale", value)) => options.scale = parse_number("scale", value, 1, MAX_SCALE)?,
+            Some(("--state", value)) => options.state_filepath = Some(value.to_string()),
+            Some(("--trace", value)) => options.trace_filepath = Some(value.to_string()),
+            Some(("--profile", value)) => options.profile_filepath = Some(value.to_string()),
+            Some(("--symbols", value)) => options.symbols_filepath = Some(value.to_string()),
+            Some(("--coverage", value)) => options.coverage_filepath = Some(value.to_string()),
+            Some(("--lcov", value)) => options.lcov_filepath = Some(value.to_string()),
+            Some(("--source_map", value)) => options.source_map_filepath = Some(value.to_string()),
+            Some(("--disassembly", value)) => {
+                options.disassembly_filepath = Some(value.to_string())
+            }
+            // Read by logger::from_env_args.
+            Some(("--log_file", _)) => {}
+            _ if arg.starts_with("--") => return Err(format!("Unknown flag: {}", arg)),
+            _ if rom_filepath.is_none() => rom_filepath = Some(arg.clone()),
+            _ => return Err(format!("Unexpected argument: {}", arg)),
+        }
+    }
+
+    if options.lcov_filepath.is_some() && options.source_map_filepath.is_none() {
+        return Err("--lcov needs a source map from --source_map.".to_string());
+    }
+    options.rom_filepath = rom_filepath.ok_or("Expected a ROM file.")?;
+    Ok(Some(options))
+}
@@ -0,0 +1,98 @@
+use crate::cli::*;
+
+use chip8::{DEFAULT_INSTRUCTIONS_PER_FRAME, MAX_INSTRUCTIONS_PER_FRAME};
+
+fn args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+}
+
+fn options(command_line: &[&str]) -> Options {
+    parse_args(&args(command_line)).unwrap().unwrap()
+}
+
+#[test]
+fn test_defaults() {
+    let options = options(&["rom.ch8"]);
+    assert_eq!("rom.ch8", options.rom_filepath);
+    assert_eq!(None, options.frames);
+    assert_eq!(
+        DEFAULT_INSTRUCTIONS_PER_FRAME,
+        options.instructions_per_frame
+    );
+    assert_eq!(1, options.scale);
+    assert_eq!(None, options.display_filepath);
+    assert_eq!(None, options.lcov_filepath);
+}
+
+#[test]
+fn test_flags() {
+    let options = options(&[
+        "--frames=60",
+        "--ipf=15",
+        "--input=keys.txt",
+        "--display=out.png",
+        "--scale=4",
+        "--state=-",
+        "--trace=trace.txt",
+        "--profile=profile.txt",
+        "--symbols=game.sym",
+        "--coverage=coverage.txt",
+        "--lcov=game.lcov",
+        "--source_map=game.map",
+        "--disassembly=game.asm",
+        "--log_file=chip8.log",
+        "rom.ch8",
+    ]);
+    assert_eq!(Some(60), options.frames);
+    assert_eq!(15, options.instructions_per_frame);
+    assert_eq!(Some("keys.txt".to_string()), options.input_filepath);
+    assert_eq!(Some("out.png".to_string()), options.display_filepath);
+    assert_eq!(4, options.scale);
+    assert_eq!(Some("-".to_string()), options.state_filepath);
+    assert_eq!(Some("trace.txt".to_string()), options.trace_filepath);
+    assert_eq!(Some("profile.txt".to_string()), options.profile_filepath);
+    assert_eq!(Some("game.sym".to_string()), options.symbols_filepath);
+    assert_eq!(Some("coverage.txt".to_string()), options.coverage_filepath);
+    assert_eq!(Some("game.lcov".to_string()), options.lcov_filepath);
+    assert_eq!(Some("game.map".to_string()), options.source_map_filepath);
+    assert_eq!(Some("game.asm".to_string()), options.disassembly_filepath);
+    assert_eq!("rom.ch8", options.rom_filepath);
+}
+
+#[test]
+fn test_limits() {
+    assert_eq!(1, options(&["--ipf=1", "rom.ch8"]).instructions_per_frame);
+    assert_eq!(
+        MAX_INSTRUCTIONS_PER_FRAME,
+        options(&["--ipf=10000", "rom.ch8"]).instructions_per_frame
+    );
+    assert_eq!(64, options(&["--scale=64", "rom.ch8"]).scale);
+    assert_eq!(Some(0), options(&["--frames=0", "rom.ch8"]).frames);
+}
+
+#[test]
+fn test_help() {
+    assert_eq!(None, parse_args(&args(&["rom.ch8", "--help"])).unwrap());
+}
+
+#[test]
+fn test_errors() {
+    let error = |command_line: &[&str]| parse_args(&args(command_line)).unwrap_err();
+    assert_eq!("Expected a ROM file.", error(&[]));
+    assert_eq!("Invalid frames: ten", error(&["--frames=ten", "rom.ch8"]));
+    assert_eq!("Invalid ipf: -1", error(&["--ipf=-1", "rom.ch8"]));
+    // Zero instructions per frame would never reach the end of a program.
+    assert_eq!("Invalid ipf: 0", error(&["--ipf=0", "rom.ch8"]));
+    assert_eq!("Invalid ipf: 10001", error(&["--ipf=10001", "rom.ch8"]));
+    assert_eq!("Invalid scale: 0", error(&["--scale=0", "rom.ch8"]));
+    assert_eq!(
+        "Invalid scale: 100000",
+        error(&["--scale=100000", "rom.ch8"])
+    );
+    assert_eq!("Unknown flag: --fast", error(&["--fast", "rom.ch8"]));
+    assert_eq!("Unexpected argument: b.ch8", error(&["a.ch8", "b.ch8"]));
+    assert_eq!(
+        "--lcov needs a source map from --source_map.",
+        error(&["--lcov=game.lcov", "rom.ch8"])
+    );
+}
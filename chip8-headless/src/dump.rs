@@ -0,0 +1,135 @@
+use chip8::coverage::Coverage;
+use chip8::{Chip8, Chip8Display};
+use chip8_assembler::{ControlFlowGraph, instruction_to_assembly};
+use chip8_instructions::decode_instruction;
+
+use serde_json::json;
+use std::collections::BTreeSet;
+use std::fs;
+
+const MEMORY_BYTES: usize = 4096;
+const PROGRAM_MEM_ADDR: u16 = 0x200;
+
+/// Write the display as PNG, PBM or text depending on the file extension.
+pub fn write_display(display: &Chip8Display, filepath: &str, scale: u32) -> Result<(), String> {
+    if filepath == "-" {
+        print!("{}", display.to_text('#', '.'));
+        return Ok(());
+    }
+    let bytes = format_display(display, filepath, scale)
+        .map_err(|e| format!("Error writing {}: {}", filepath, e))?;
+    fs::write(filepath, bytes).map_err(|e| format!("Error writing {}: {}", filepath, e))
+}
+
+/// @return the display in the format for the file extension: PNG, PBM or text.
+pub fn format_display(
+    display: &Chip8Display,
+    filepath: &str,
+    scale: u32,
+) -> Result<Vec<u8>, String> {
+    if filepath.ends_with(".png") {
+        return encode_png(display, scale);
+    }
+    let text = if filepath.ends_with(".pbm") {
+        display.to_pbm()
+    } else {
+        display.to_text('#', '.')
+    };
+    Ok(text.into_bytes())
+}
+
+fn encode_png(display: &Chip8Display, scale: u32) -> Result<Vec<u8>, String> {
+    let scale = scale as usize;
+    let width = display.width * scale;
+    let height = display.height * scale;
+    let pixels: Vec<u8> = (0..height)
+        .flat_map(|i| (0..width).map(move |j| (i / scale, j / scale)))
+        .map(|(i, j)| if display.get_pixel(i, j) { 0xFF } else { 0x00 })
+        .collect();
+
+    let mut png = Vec::new();
+    let mut encoder = png::Encoder::new(&mut png, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(|e| e.to_string())?;
+    writer
+        .write_image_data(&pixels)
+        .map_err(|e| e.to_string())?;
+    writer.finish().map_err(|e| e.to_string())?;
+    Ok(png)
+}
+
+/// Write the registers, stack and memory as JSON.
+pub fn write_state(chip8: &Chip8, frames: u32, filepath: &str) -> Result<(), String> {
+    write_text(&format_state(chip8, frames), filepath)
+}
+
+/// @return the registers, stack and memory as JSON. Memory is a single hex string.
+pub fn format_state(chip8: &Chip8, frames: u32) -> String {
+    let state = json!({
+        "frames": frames,
+        "exited": chip8.is_exited(),
+        "v": chip8.get_registers(),
+        "i": chip8.get_pointer(),
+        "pc": chip8.get_program_counter(),
+        "sp": chip8.get_stack_pointer(),
+        "stack": chip8.get_stack(),
+        "dt": chip8.get_delay_timer(),
+        "st": chip8.get_sound_timer(),
+        "memory": chip8
+            .get_memory(0, MEMORY_BYTES)
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>(),
+    });
+    serde_json::to_string_pretty(&state).expect("state is serializable") + "\n"
+}
+
+/// Write a report to a file, or to stdout if the path is `-`.
+pub fn write_text(text: &str, filepath: &str) -> Result<(), String> {
+    if filepath == "-" {
+        print!("{}", text);
+        return Ok(());
+    }
+    fs::write(filepath, text).map_err(|e| format!("Error writing {}: {}", filepath, e))
+}
+
+/// Write the program's disassembly annotated with how often each part was used.
+pub fn write_coverage(coverage: &Coverage, program: &[u8], filepath: &str) -> Result<(), String> {
+    write_text(&format_coverage(coverage, program), filepath)
+}
+
+/// @return the program's disassembly annotated with how often each part was used, followed
+/// by a summary of the code that static analysis can reach.
+pub fn format_coverage(coverage: &Coverage, program: &[u8]) -> String {
+    let mut listing = coverage.annotate(program, PROGRAM_MEM_ADDR, |byte1, byte2| {
+        instruction_to_assembly(decode_instruction(byte1, byte2), &BTreeSet::new())
+    });
+
+    // Compare with the code that static analysis can reach.
+    let graph = ControlFlowGraph::new(program, PROGRAM_MEM_ADDR);
+    let reachable_addrs: Vec<u16> = (0..program.len() as u16)
+        .map(|offset| PROGRAM_MEM_ADDR + offset)
+        .filter(|addr| graph.is_reachable(*addr))
+        .collect();
+    let ran = reachable_addrs
+        .iter()
+        .filter(|addr| {
+            coverage.get_executions(**addr) > 0 || coverage.get_executions(*addr - 1) > 0
+        })
+        .count();
+    listing += &format!(
+        "\n{} of {} bytes are reachable code, and {} of those ran.\n",
+        reachable_addrs.len(),
+        program.len(),
+        ran
+    );
+    for range in graph.unreachable_ranges() {
+        listing += &format!(
+            "Unreachable: 0x{:03x}-0x{:03x}\n",
+            range.start,
+            range.end - 1
+        );
+    }
+    listing
+}
@@ -0,0 +1,87 @@
+use crate::dump::*;
+
+use chip8::Chip8;
+use chip8::logger::Logger;
+use chip8::logger::coverage_log_source::CoverageLogSource;
+
+// 0x200: LD V0, 0x00
+// 0x202: LD F, V0
+// 0x204: DRW V0, V0, 5   Draws the 0 digit in the top left corner.
+// 0x206: EXIT
+// 0x208: JP 0x200        Never reached.
+const PROGRAM: [u8; 10] = [0x60, 0x00, 0xF0, 0x29, 0xD0, 0x05, 0x00, 0xFD, 0x12, 0x00];
+
+fn run_program() -> Chip8 {
+    let mut chip8 = Chip8::new(Logger::new_null_logger());
+    chip8.initialize(&PROGRAM);
+    while !chip8.run_next_instruction() {}
+    chip8
+}
+
+#[test]
+fn test_format_display_as_text() {
+    let chip8 = run_program();
+    let text =
+        String::from_utf8(format_display(&chip8.get_display(), "out.txt", 1).unwrap()).unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(32, lines.len());
+    assert!(lines[0].starts_with("####...."));
+    assert!(lines[1].starts_with("#..#...."));
+    assert_eq!(64, lines[0].len());
+}
+
+#[test]
+fn test_format_display_as_pbm() {
+    let chip8 = run_program();
+    let pbm =
+        String::from_utf8(format_display(&chip8.get_display(), "out.pbm", 1).unwrap()).unwrap();
+    assert!(pbm.starts_with("P1\n64 32\n1 1 1 1 0 0 0 0"));
+}
+
+#[test]
+fn test_format_display_as_png() {
+    let chip8 = run_program();
+    let png = format_display(&chip8.get_display(), "out.png", 2).unwrap();
+    let mut reader = png::Decoder::new(png.as_slice()).read_info().unwrap();
+    let mut pixels = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut pixels).unwrap();
+    assert_eq!((128, 64), (info.width, info.height));
+    assert_eq!(png::ColorType::Grayscale, info.color_type);
+    // Each CHIP-8 pixel is a 2x2 square.
+    assert_eq!(&[0xFF; 8], &pixels[0..8]);
+    assert_eq!(&[0x00; 2], &pixels[8..10]);
+    assert_eq!(&[0xFF; 8], &pixels[128..136]);
+}
+
+#[test]
+fn test_format_state() {
+    let chip8 = run_program();
+    let state: serde_json::Value = serde_json::from_str(&format_state(&chip8, 3)).unwrap();
+    assert_eq!(3, state["frames"]);
+    assert_eq!(true, state["exited"]);
+    assert_eq!(0x208, state["pc"]);
+    assert_eq!(0, state["sp"]);
+    assert_eq!(16, state["v"].as_array().unwrap().len());
+    let memory = state["memory"].as_str().unwrap();
+    assert_eq!(2 * 4096, memory.len());
+    assert_eq!("6000f029", &memory[0x400..0x408]);
+}
+
+#[test]
+fn test_format_coverage() {
+    let (log_source, coverage) = CoverageLogSource::new();
+    let mut chip8 = Chip8::new(Logger::new(Box::new(log_source)));
+    chip8.initialize(&PROGRAM);
+    while !chip8.run_next_instruction() {}
+
+    let listing = format_coverage(&coverage.lock().unwrap(), &PROGRAM);
+    let lines: Vec<&str> = listing.lines().collect();
+    assert_eq!("         1  0x200  6000  LD V0, 0x00", lines[0]);
+    assert_eq!("         -  0x208  1200  JP 0x200", lines[4]);
+    assert_eq!("", lines[5]);
+    assert_eq!(
+        "8 of 10 bytes are reachable code, and 8 of those ran.",
+        lines[6]
+    );
+    assert_eq!("Unreachable: 0x208-0x209", lines[7]);
+}
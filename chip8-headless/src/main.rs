@@ -0,0 +1,179 @@
+mod cli;
+mod dump;
+
+#[cfg(test)]
+mod cli_test;
+
+#[cfg(test)]
+mod dump_test;
+
+#[cfg(test)]
+mod main_test;
+
+use chip8::Chip8;
+use chip8::MAX_PROGRAM_BYTES;
+use chip8::input_script::InputScript;
+use chip8::logger;
+use chip8::logger::coverage_log_source::CoverageLogSource;
+use chip8::logger::profiler_log_source::ProfilerLogSource;
+use chip8::logger::tee_log_source::TeeLogSource;
+use chip8::logger::trace_file_log_source::TraceFileLogSource;
+use chip8::logger::{LogSource, Logger};
+use chip8::source_map::SourceMap;
+use chip8::symbol_map::SymbolMap;
+use chip8_assembler::disassemble_with_modified_code;
+use cli::{Options, USAGE};
+
+use std::env;
+use std::fs;
+use std::fs::File;
+use std::io::BufWriter;
+use std::process::ExitCode;
+
+const PROFILE_TOP_ADDRESSES: usize = 20;
+const PROGRAM_MEM_ADDR: u16 = 0x200;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let options = match cli::parse_args(&args) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match run(&options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(options: &Options) -> Result<(), String> {
+    let program = fs::read(&options.rom_filepath)
+        .map_err(|e| format!("Error reading {}: {}", options.rom_filepath, e))?;
+    if program.len() > MAX_PROGRAM_BYTES {
+        return Err(format!(
+            "ROM too large: {} is {} bytes, and at most {} fit in memory.",
+            options.rom_filepath,
+            program.len(),
+            MAX_PROGRAM_BYTES
+        ));
+    }
+    let input_script = match options.input_filepath {
+        Some(ref input_filepath) => {
+            let text = fs::read_to_string(input_filepath)
+                .map_err(|e| format!("Error reading {}: {}", input_filepath, e))?;
+            InputScript::parse(&text).map_err(|e| format!("Error in {}: {}", input_filepath, e))?
+        }
+        None => InputScript::default(),
+    };
+
+    let read_file = |filepath: &str| {
+        fs::read_to_string(filepath).map_err(|e| format!("Error reading {}: {}", filepath, e))
+    };
+    let symbols = match options.symbols_filepath {
+        Some(ref symbols_filepath) => Some(
+            SymbolMap::parse(&read_file(symbols_filepath)?)
+                .map_err(|e| format!("Error in {}: {}", symbols_filepath, e))?,
+        ),
+        None => None,
+    };
+    let source_map = match options.source_map_filepath {
+        Some(ref source_map_filepath) => Some(
+            SourceMap::parse(&read_file(source_map_filepath)?)
+                .map_err(|e| format!("Error in {}: {}", source_map_filepath, e))?,
+        ),
+        None => None,
+    };
+
+    let mut log_sources = Vec::new();
+    if let Some(ref trace_filepath) = options.trace_filepath {
+        let file = File::create(trace_filepath)
+            .map_err(|e| format!("Error writing {}: {}", trace_filepath, e))?;
+        log_sources
+            .push(Box::new(TraceFileLogSource::new(BufWriter::new(file))) as Box<dyn LogSource>);
+    }
+    let mut profile = None;
+    if options.profile_filepath.is_some() {
+        let (log_source, shared_profile) = ProfilerLogSource::new();
+        log_sources.push(Box::new(log_source));
+        profile = Some(shared_profile);
+    }
+    let mut coverage = None;
+    if options.coverage_filepath.is_some() || options.lcov_filepath.is_some() {
+        let (log_source, shared_coverage) = CoverageLogSource::new();
+        log_sources.push(Box::new(log_source));
+        coverage = Some(shared_coverage);
+    }
+    let mut logger = logger::from_env_args();
+    if !log_sources.is_empty() {
+        log_sources.push(logger.into_log_source());
+        logger = Logger::new(Box::new(TeeLogSource::new(log_sources)));
+    }
+
+    let mut chip8 = Chip8::new(logger);
+    chip8.initialize(&program);
+    let frame = run_frames(
+        &mut chip8,
+        &input_script,
+        options.frames,
+        options.instructions_per_frame,
+    );
+
+    if let Some(ref display_filepath) = options.display_filepath {
+        dump::write_display(&chip8.get_display(), display_filepath, options.scale)?;
+    }
+    if let Some(ref state_filepath) = options.state_filepath {
+        dump::write_state(&chip8, frame, state_filepath)?;
+    }
+    if let (Some(profile_filepath), Some(profile)) = (&options.profile_filepath, profile) {
+        let report = profile
+            .lock()
+            .unwrap()
+            .report(symbols.as_ref(), PROFILE_TOP_ADDRESSES);
+        dump::write_text(&report, profile_filepath)?;
+    }
+    if let Some(coverage) = coverage {
+        let coverage = coverage.lock().unwrap();
+        if let Some(ref coverage_filepath) = options.coverage_filepath {
+            dump::write_coverage(&coverage, &program, coverage_filepath)?;
+        }
+        if let (Some(lcov_filepath), Some(source_map)) = (&options.lcov_filepath, &source_map) {
+            dump::write_text(&coverage.to_lcov(source_map), lcov_filepath)?;
+        }
+    }
+    if let Some(ref disassembly_filepath) = options.disassembly_filepath {
+        let disassembly =
+            disassemble_with_modified_code(&program, PROGRAM_MEM_ADDR, chip8.get_modified_code());
+        dump::write_text(&disassembly, disassembly_filepath)?;
+    }
+    Ok(())
+}
+
+/// Run frames with the keys from the input script until `frames` have run or the program
+/// exits.
+/// @return the number of frames run.
+fn run_frames(
+    chip8: &mut Chip8,
+    input_script: &InputScript,
+    frames: Option<u32>,
+    instructions_per_frame: u32,
+) -> u32 {
+    let mut frame: u32 = 0;
+    while frames.is_none_or(|frames| frame < frames) {
+        chip8.set_keys(input_script.keys_at(frame));
+        frame += 1;
+        if chip8.run_frame(instructions_per_frame) {
+            break;
+        }
+    }
+    frame
+}
@@ -0,0 +1,62 @@
+use crate::cli::parse_args;
+use crate::{run, run_frames};
+
+use chip8::Chip8;
+use chip8::MAX_PROGRAM_BYTES;
+use chip8::input_script::InputScript;
+use chip8::logger::Logger;
+
+use std::env;
+use std::fs;
+
+// 0x200: ADD V0, 0x01
+// 0x202: SKNP V1       V1 is 0, so this skips while key 0 is not held.
+// 0x204: EXIT
+// 0x206: JP 0x200
+const PROGRAM: [u8; 8] = [0x70, 0x01, 0xE1, 0xA1, 0x00, 0xFD, 0x12, 0x00];
+
+fn new_chip8() -> Chip8 {
+    let mut chip8 = Chip8::new(Logger::new_null_logger());
+    chip8.initialize(&PROGRAM);
+    chip8
+}
+
+#[test]
+fn test_runs_the_given_frames() {
+    let mut chip8 = new_chip8();
+    assert_eq!(
+        5,
+        run_frames(&mut chip8, &InputScript::default(), Some(5), 3)
+    );
+    assert!(!chip8.is_exited());
+    // ADD, SKNP and JP take a frame of 3 instructions per loop.
+    assert_eq!(5, chip8.get_register(0));
+}
+
+#[test]
+fn test_stops_when_the_program_exits() {
+    let mut chip8 = new_chip8();
+    let input_script = InputScript::parse("0 -\n4 0\n").unwrap();
+    assert_eq!(5, run_frames(&mut chip8, &input_script, None, 3));
+    assert!(chip8.is_exited());
+    assert_eq!(5, chip8.get_register(0));
+}
+
+#[test]
+fn test_rom_too_large() {
+    let rom_filepath = env::temp_dir().join("chip8_headless_test_rom_too_large.ch8");
+    let rom_filepath = rom_filepath.to_str().unwrap().to_string();
+    fs::write(&rom_filepath, vec![0; MAX_PROGRAM_BYTES + 1]).unwrap();
+    let options = parse_args(std::slice::from_ref(&rom_filepath))
+        .unwrap()
+        .unwrap();
+    let result = run(&options);
+    fs::remove_file(&rom_filepath).unwrap();
+    assert_eq!(
+        Err(format!(
+            "ROM too large: {} is 3585 bytes, and at most 3584 fit in memory.",
+            rom_filepath
+        )),
+        result
+    );
+}
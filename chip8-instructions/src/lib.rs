@@ -17,9 +17,9 @@ pub enum Instruction {
     XorReg { x: u8, y: u8 },
     AddReg { x: u8, y: u8 },
     SubReg { x: u8, y: u8 },
-    ShiftRight { x: u8 },
+    ShiftRight { x: u8, y: u8 },
     SubNegReg { x: u8, y: u8 },
-    ShiftLeft { x: u8 },
+    ShiftLeft { x: u8, y: u8 },
     SkipRegNotEqualsReg { x: u8, y: u8 },
     LoadImmToPointer { addr: u16 },
     JumpOffset { addr: u16 },
@@ -93,12 +93,18 @@ pub fn decode_instruction(byte1: u8, byte2: u8) -> Instruction {
             x: nibble2,
             y: nibble3,
         },
-        (0x8, _, _, 0x6) => Instruction::ShiftRight { x: nibble2 },
+        (0x8, _, _, 0x6) => Instruction::ShiftRight {
+            x: nibble2,
+            y: nibble3,
+        },
         (0x8, _, _, 0x7) => Instruction::SubNegReg {
             x: nibble2,
             y: nibble3,
         },
-        (0x8, _, _, 0xE) => Instruction::ShiftLeft { x: nibble2 },
+        (0x8, _, _, 0xE) => Instruction::ShiftLeft {
+            x: nibble2,
+            y: nibble3,
+        },
         (0x9, _, _, 0x0) => Instruction::SkipRegNotEqualsReg {
             x: nibble2,
             y: nibble3,
@@ -147,9 +153,9 @@ pub fn encode_instruction(instruction: Instruction) -> (u8, u8) {
         Instruction::XorReg { x, y } => (0x80 | x, (y << 4) | 0x3),
         Instruction::AddReg { x, y } => (0x80 | x, (y << 4) | 0x4),
         Instruction::SubReg { x, y } => (0x80 | x, (y << 4) | 0x5),
-        Instruction::ShiftRight { x } => (0x80 | x, 0x06),
+        Instruction::ShiftRight { x, y } => (0x80 | x, (y << 4) | 0x06),
         Instruction::SubNegReg { x, y } => (0x80 | x, (y << 4) | 0x7),
-        Instruction::ShiftLeft { x } => (0x80 | x, 0x0E),
+        Instruction::ShiftLeft { x, y } => (0x80 | x, (y << 4) | 0x0E),
         Instruction::SkipRegNotEqualsReg { x, y } => (0x90 | x, y << 4),
         Instruction::LoadImmToPointer { addr } => (0xA0 | (addr >> 8) as u8, addr as u8),
         Instruction::JumpOffset { addr } => (0xB0 | (addr >> 8) as u8, addr as u8),
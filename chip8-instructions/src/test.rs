@@ -153,10 +153,10 @@ fn test_encode_sub_reg() {
 
 #[test]
 fn test_encode_shift_right() {
-    let instruction = Instruction::ShiftRight { x: 0x5 };
+    let instruction = Instruction::ShiftRight { x: 0x5, y: 0x6 };
     let (byte1, byte2) = encode_instruction(instruction);
     assert_eq!(0x85, byte1);
-    assert_eq!(0x06, byte2);
+    assert_eq!(0x66, byte2);
 }
 
 #[test]
@@ -169,10 +169,10 @@ fn test_encode_sub_neg_reg() {
 
 #[test]
 fn test_encode_shift_left() {
-    let instruction = Instruction::ShiftLeft { x: 0x8 };
+    let instruction = Instruction::ShiftLeft { x: 0x8, y: 0x9 };
     let (byte1, byte2) = encode_instruction(instruction);
     assert_eq!(0x88, byte1);
-    assert_eq!(0x0E, byte2);
+    assert_eq!(0x9E, byte2);
 }
 
 #[test]
@@ -0,0 +1,2331 @@
+//! Recompiled from test_execute_flags.ch8 by chip8-recompiler. Do not edit.
+
+use chip8::Chip8;
+
+pub const ROM: [u8; 1041] = [
+    0x12, 0xA0, 0x60, 0x00, 0xE0, 0xA1, 0x12, 0x04, 0x70, 0x01, 0x40, 0x10, 0x00, 0xEE, 0x12, 0x04,
+    0xFC, 0x65, 0x22, 0x76, 0x41, 0x00, 0x00, 0xEE, 0x80, 0x10, 0x22, 0x76, 0x42, 0x00, 0x00, 0xEE,
+    0x80, 0x20, 0x22, 0x76, 0x43, 0x00, 0x00, 0xEE, 0x80, 0x30, 0x22, 0x76, 0x44, 0x00, 0x00, 0xEE,
+    0x80, 0x40, 0x22, 0x76, 0x45, 0x00, 0x00, 0xEE, 0x80, 0x50, 0x22, 0x76, 0x46, 0x00, 0x00, 0xEE,
+    0x80, 0x60, 0x22, 0x76, 0x47, 0x00, 0x00, 0xEE, 0x80, 0x70, 0x22, 0x76, 0x48, 0x00, 0x00, 0xEE,
+    0x80, 0x80, 0x22, 0x76, 0x49, 0x00, 0x00, 0xEE, 0x80, 0x90, 0x22, 0x76, 0x4A, 0x00, 0x00, 0xEE,
+    0x80, 0xA0, 0x22, 0x76, 0x4B, 0x00, 0x00, 0xEE, 0x80, 0xB0, 0x22, 0x76, 0x4C, 0x00, 0x00, 0xEE,
+    0x80, 0xC0, 0x22, 0x76, 0x00, 0xEE, 0xA5, 0x57, 0xF0, 0x1E, 0xDD, 0xE4, 0x7D, 0x04, 0x00, 0xEE,
+    0xA5, 0x5B, 0x8E, 0xD0, 0x8E, 0xEE, 0x8E, 0xEE, 0xFE, 0x1E, 0xDA, 0xB4, 0x7A, 0x05, 0x00, 0xEE,
+    0xA5, 0x58, 0x92, 0xC0, 0xA5, 0x55, 0x7B, 0x01, 0xDA, 0xB3, 0x7A, 0x04, 0x7B, 0xFF, 0x00, 0xEE,
+    0x00, 0xE0, 0x6A, 0x32, 0x6B, 0x1B, 0xA6, 0x09, 0xDA, 0xB4, 0x6A, 0x3A, 0xA6, 0x0D, 0xDA, 0xB4,
+    0x6D, 0x00, 0x6E, 0x00, 0xA5, 0xF7, 0x22, 0x10, 0x6A, 0x16, 0x6B, 0x00, 0x61, 0x0F, 0x6D, 0x01,
+    0x22, 0x80, 0x63, 0x0F, 0x6F, 0x14, 0x83, 0xF1, 0x6F, 0x00, 0x62, 0x32, 0x82, 0x11, 0x8E, 0xF0,
+    0x6C, 0x3F, 0x22, 0x90, 0x82, 0xE0, 0x6C, 0x00, 0x22, 0x90, 0x82, 0x30, 0x6C, 0x1F, 0x22, 0x90,
+    0x7A, 0x05, 0x6D, 0x02, 0x22, 0x80, 0x63, 0x0F, 0x6F, 0x14, 0x83, 0xF2, 0x6F, 0x00, 0x62, 0x32,
+    0x82, 0x12, 0x8E, 0xF0, 0x6C, 0x02, 0x22, 0x90, 0x82, 0xE0, 0x6C, 0x00, 0x22, 0x90, 0x82, 0x30,
+    0x6C, 0x04, 0x22, 0x90, 0x7B, 0x05, 0x6A, 0x00, 0x6D, 0x03, 0x22, 0x80, 0x63, 0x0F, 0x6F, 0x14,
+    0x83, 0xF3, 0x6F, 0x00, 0x62, 0x32, 0x82, 0x13, 0x8E, 0xF0, 0x6C, 0x3D, 0x22, 0x90, 0x82, 0xE0,
+    0x6C, 0x00, 0x22, 0x90, 0x82, 0x30, 0x6C, 0x1B, 0x22, 0x90, 0x7A, 0x05, 0x6D, 0x04, 0x22, 0x80,
+    0x6F, 0x14, 0x8F, 0x14, 0x84, 0xF0, 0x63, 0x0F, 0x6F, 0x14, 0x83, 0xF4, 0x6F, 0xAA, 0x62, 0x32,
+    0x82, 0x14, 0x8E, 0xF0, 0x6C, 0x41, 0x22, 0x90, 0x82, 0xE0, 0x6C, 0x00, 0x22, 0x90, 0x82, 0x30,
+    0x6C, 0x23, 0x22, 0x90, 0x82, 0x40, 0x6C, 0x00, 0x22, 0x90, 0x7A, 0x01, 0x6D, 0x05, 0x22, 0x80,
+    0x6F, 0x14, 0x8F, 0x15, 0x84, 0xF0, 0x63, 0x14, 0x6F, 0x0F, 0x83, 0xF5, 0x65, 0x0A, 0x6F, 0x0A,
+    0x85, 0xF5, 0x85, 0xF0, 0x6F, 0xAA, 0x62, 0x32, 0x82, 0x15, 0x35, 0x01, 0x6F, 0x02, 0x8E, 0xF0,
+    0x6C, 0x23, 0x22, 0x90, 0x82, 0xE0, 0x6C, 0x01, 0x22, 0x90, 0x82, 0x30, 0x6C, 0x05, 0x22, 0x90,
+    0x82, 0x40, 0x6C, 0x01, 0x22, 0x90, 0x7B, 0x05, 0x6A, 0x00, 0x6D, 0x06, 0x22, 0x80, 0x6F, 0x3C,
+    0x8F, 0xF6, 0x83, 0xF0, 0x6F, 0xAA, 0x62, 0x3C, 0x82, 0x26, 0x8E, 0xF0, 0x6C, 0x1E, 0x22, 0x90,
+    0x82, 0xE0, 0x6C, 0x00, 0x22, 0x90, 0x82, 0x30, 0x6C, 0x00, 0x22, 0x90, 0x7A, 0x05, 0x6D, 0x07,
+    0x22, 0x80, 0x6F, 0x0A, 0x8F, 0x17, 0x84, 0xF0, 0x63, 0x0F, 0x6F, 0x14, 0x83, 0xF7, 0x65, 0x0A,
+    0x6F, 0x0A, 0x85, 0xF7, 0x85, 0xF0, 0x6F, 0xAA, 0x62, 0x0F, 0x61, 0x32, 0x82, 0x17, 0x35, 0x01,
+    0x6F, 0x02, 0x8E, 0xF0, 0x6C, 0x23, 0x22, 0x90, 0x82, 0xE0, 0x6C, 0x01, 0x22, 0x90, 0x82, 0x30,
+    0x6C, 0x05, 0x22, 0x90, 0x82, 0x40, 0x6C, 0x01, 0x22, 0x90, 0x7A, 0x01, 0x6D, 0x0E, 0x22, 0x80,
+    0x6F, 0x32, 0x8F, 0xFE, 0x83, 0xF0, 0x6F, 0xAA, 0x62, 0x32, 0x82, 0x2E, 0x8E, 0xF0, 0x6C, 0x64,
+    0x22, 0x90, 0x82, 0xE0, 0x6C, 0x00, 0x22, 0x90, 0x82, 0x30, 0x6C, 0x00, 0x22, 0x90, 0x6D, 0x00,
+    0x6E, 0x10, 0xA5, 0xFD, 0x22, 0x10, 0x6A, 0x16, 0x6B, 0x10, 0x61, 0x64, 0x6D, 0x04, 0x22, 0x80,
+    0x6F, 0xC8, 0x8F, 0x14, 0x84, 0xF0, 0x63, 0x64, 0x6F, 0xC8, 0x83, 0xF4, 0x6F, 0xAA, 0x62, 0xC8,
+    0x82, 0x14, 0x8E, 0xF0, 0x6C, 0x2C, 0x22, 0x90, 0x82, 0xE0, 0x6C, 0x01, 0x22, 0x90, 0x82, 0x30,
+    0x6C, 0x2C, 0x22, 0x90, 0x82, 0x40, 0x6C, 0x01, 0x22, 0x90, 0x7A, 0x01, 0x6D, 0x05, 0x22, 0x80,
+    0x6F, 0x5F, 0x8F, 0x15, 0x84, 0xF0, 0x63, 0x5F, 0x6F, 0x64, 0x83, 0xF5, 0x6F, 0xAA, 0x62, 0x5F,
+    0x82, 0x15, 0x8E, 0xF0, 0x6C, 0xFB, 0x22, 0x90, 0x82, 0xE0, 0x6C, 0x00, 0x22, 0x90, 0x82, 0x30,
+    0x6C, 0xFB, 0x22, 0x90, 0x82, 0x40, 0x6C, 0x00, 0x22, 0x90, 0x7B, 0x05, 0x6A, 0x00, 0x6D, 0x06,
+    0x22, 0x80, 0x6F, 0x3D, 0x8F, 0xF6, 0x83, 0xF0, 0x6F, 0xAA, 0x62, 0x3D, 0x82, 0x26, 0x8E, 0xF0,
+    0x6C, 0x1E, 0x22, 0x90, 0x82, 0xE0, 0x6C, 0x01, 0x22, 0x90, 0x82, 0x30, 0x6C, 0x01, 0x22, 0x90,
+    0x7A, 0x05, 0x6D, 0x07, 0x22, 0x80, 0x6F, 0x69, 0x8F, 0x17, 0x84, 0xF0, 0x63, 0x69, 0x6F, 0x64,
+    0x83, 0xF7, 0x6F, 0xAA, 0x62, 0x69, 0x82, 0x17, 0x8E, 0xF0, 0x6C, 0xFB, 0x22, 0x90, 0x82, 0xE0,
+    0x6C, 0x00, 0x22, 0x90, 0x82, 0x30, 0x6C, 0xFB, 0x22, 0x90, 0x82, 0x40, 0x6C, 0x00, 0x22, 0x90,
+    0x7A, 0x01, 0x6D, 0x0E, 0x22, 0x80, 0x6F, 0xBC, 0x8F, 0xFE, 0x83, 0xF0, 0x6F, 0xAA, 0x62, 0xBC,
+    0x82, 0x2E, 0x8E, 0xF0, 0x6C, 0x78, 0x22, 0x90, 0x82, 0xE0, 0x6C, 0x01, 0x22, 0x90, 0x82, 0x30,
+    0x6C, 0x01, 0x22, 0x90, 0x6D, 0x00, 0x6E, 0x1B, 0xA6, 0x03, 0x22, 0x10, 0x6A, 0x16, 0x6B, 0x1B,
+    0x6D, 0x0F, 0x22, 0x80, 0x7A, 0xFF, 0x6D, 0x0E, 0x22, 0x80, 0xA5, 0x44, 0x61, 0x10, 0xF1, 0x1E,
+    0x60, 0xAA, 0xF0, 0x55, 0xA5, 0x54, 0xF0, 0x65, 0x82, 0x00, 0x6C, 0xAA, 0x22, 0x90, 0xA5, 0x44,
+    0x6F, 0x10, 0xFF, 0x1E, 0x60, 0x55, 0xF0, 0x55, 0xA5, 0x54, 0xF0, 0x65, 0x82, 0x00, 0x6C, 0x55,
+    0x22, 0x90, 0x15, 0x42, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0xA0, 0xC0, 0x80, 0xA0, 0x40, 0xA0, 0xE0, 0xA0, 0xA0, 0xE0, 0xC0,
+    0x40, 0x40, 0xE0, 0xE0, 0x20, 0xC0, 0xE0, 0xE0, 0x60, 0x20, 0xE0, 0xA0, 0xE0, 0x20, 0x20, 0xE0,
+    0xC0, 0x20, 0xC0, 0xE0, 0x80, 0xE0, 0xE0, 0xE0, 0x20, 0x20, 0x20, 0xE0, 0xE0, 0xA0, 0xE0, 0xE0,
+    0xE0, 0x20, 0xE0, 0x40, 0xA0, 0xE0, 0xA0, 0xC0, 0xE0, 0xA0, 0xE0, 0xE0, 0x80, 0x80, 0xE0, 0xC0,
+    0xA0, 0xA0, 0xC0, 0xE0, 0xC0, 0x80, 0xE0, 0xE0, 0x80, 0xC0, 0x80, 0x60, 0x80, 0xA0, 0x60, 0xA0,
+    0xE0, 0xA0, 0xA0, 0xE0, 0x40, 0x40, 0xE0, 0x60, 0x20, 0x20, 0xC0, 0xA0, 0xC0, 0xA0, 0xA0, 0x80,
+    0x80, 0x80, 0xE0, 0xE0, 0xE0, 0xA0, 0xA0, 0xC0, 0xA0, 0xA0, 0xA0, 0xE0, 0xA0, 0xA0, 0xE0, 0xC0,
+    0xA0, 0xC0, 0x80, 0x40, 0xA0, 0xE0, 0x60, 0xC0, 0xA0, 0xC0, 0xA0, 0x60, 0xC0, 0x20, 0xC0, 0xE0,
+    0x40, 0x40, 0x40, 0xA0, 0xA0, 0xA0, 0x60, 0xA0, 0xA0, 0xA0, 0x40, 0xA0, 0xA0, 0xE0, 0xE0, 0xA0,
+    0x40, 0xA0, 0xA0, 0xA0, 0xA0, 0x40, 0x40, 0xE0, 0x60, 0x80, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x48, 0x2C, 0x68, 0x68, 0x8C, 0x00, 0x34, 0x2C, 0x70,
+    0x70, 0x8C, 0x00, 0x64, 0x78, 0x48, 0x3C, 0x70, 0x00, 0x0A, 0xAE, 0xA2, 0x42, 0x38, 0x08, 0x30,
+    0xB8,
+];
+
+/// Run up to `num_instructions` instructions like `Chip8::run`, with compiled blocks in
+/// place of the interpreter wherever the program has not changed them.
+/// @return the number of instructions run.
+pub fn run(chip8: &mut Chip8, num_instructions: u32) -> u32 {
+    if chip8.is_logging_instructions() {
+        return chip8.run(num_instructions);
+    }
+    let mut count = 0;
+    while count < num_instructions && !chip8.is_exited() && !chip8.is_waiting_for_key() {
+        let budget = num_instructions - count;
+        let ran = match chip8.get_program_counter() {
+            0x200 => block_200(chip8, budget),
+            0x214 => block_214(chip8, budget),
+            0x218 => block_218(chip8, budget),
+            0x21C => block_21c(chip8, budget),
+            0x220 => block_220(chip8, budget),
+            0x224 => block_224(chip8, budget),
+            0x228 => block_228(chip8, budget),
+            0x22C => block_22c(chip8, budget),
+            0x230 => block_230(chip8, budget),
+            0x234 => block_234(chip8, budget),
+            0x238 => block_238(chip8, budget),
+            0x23C => block_23c(chip8, budget),
+            0x240 => block_240(chip8, budget),
+            0x244 => block_244(chip8, budget),
+            0x248 => block_248(chip8, budget),
+            0x24C => block_24c(chip8, budget),
+            0x250 => block_250(chip8, budget),
+            0x254 => block_254(chip8, budget),
+            0x258 => block_258(chip8, budget),
+            0x25C => block_25c(chip8, budget),
+            0x260 => block_260(chip8, budget),
+            0x264 => block_264(chip8, budget),
+            0x268 => block_268(chip8, budget),
+            0x26C => block_26c(chip8, budget),
+            0x270 => block_270(chip8, budget),
+            0x276 => block_276(chip8, budget),
+            0x27C => block_27c(chip8, budget),
+            0x280 => block_280(chip8, budget),
+            0x28C => block_28c(chip8, budget),
+            0x290 => block_290(chip8, budget),
+            0x294 => block_294(chip8, budget),
+            0x296 => block_296(chip8, budget),
+            0x29A => block_29a(chip8, budget),
+            0x2A2 => block_2a2(chip8, budget),
+            0x2AA => block_2aa(chip8, budget),
+            0x2B0 => block_2b0(chip8, budget),
+            0x2B8 => block_2b8(chip8, budget),
+            0x2C2 => block_2c2(chip8, budget),
+            0x2D4 => block_2d4(chip8, budget),
+            0x2DA => block_2da(chip8, budget),
+            0x2E0 => block_2e0(chip8, budget),
+            0x2E6 => block_2e6(chip8, budget),
+            0x2F8 => block_2f8(chip8, budget),
+            0x2FE => block_2fe(chip8, budget),
+            0x304 => block_304(chip8, budget),
+            0x30C => block_30c(chip8, budget),
+            0x31E => block_31e(chip8, budget),
+            0x324 => block_324(chip8, budget),
+            0x32A => block_32a(chip8, budget),
+            0x330 => block_330(chip8, budget),
+            0x348 => block_348(chip8, budget),
+            0x34E => block_34e(chip8, budget),
+            0x354 => block_354(chip8, budget),
+            0x35A => block_35a(chip8, budget),
+            0x360 => block_360(chip8, budget),
+            0x37C => block_37c(chip8, budget),
+            0x37E => block_37e(chip8, budget),
+            0x384 => block_384(chip8, budget),
+            0x38A => block_38a(chip8, budget),
+            0x390 => block_390(chip8, budget),
+            0x396 => block_396(chip8, budget),
+            0x39E => block_39e(chip8, budget),
+            0x3B0 => block_3b0(chip8, budget),
+            0x3B6 => block_3b6(chip8, budget),
+            0x3BC => block_3bc(chip8, budget),
+            0x3C2 => block_3c2(chip8, budget),
+            0x3E0 => block_3e0(chip8, budget),
+            0x3E2 => block_3e2(chip8, budget),
+            0x3E8 => block_3e8(chip8, budget),
+            0x3EE => block_3ee(chip8, budget),
+            0x3F4 => block_3f4(chip8, budget),
+            0x3FA => block_3fa(chip8, budget),
+            0x400 => block_400(chip8, budget),
+            0x412 => block_412(chip8, budget),
+            0x418 => block_418(chip8, budget),
+            0x41E => block_41e(chip8, budget),
+            0x426 => block_426(chip8, budget),
+            0x430 => block_430(chip8, budget),
+            0x448 => block_448(chip8, budget),
+            0x44E => block_44e(chip8, budget),
+            0x454 => block_454(chip8, budget),
+            0x45A => block_45a(chip8, budget),
+            0x460 => block_460(chip8, budget),
+            0x478 => block_478(chip8, budget),
+            0x47E => block_47e(chip8, budget),
+            0x484 => block_484(chip8, budget),
+            0x48A => block_48a(chip8, budget),
+            0x492 => block_492(chip8, budget),
+            0x4A4 => block_4a4(chip8, budget),
+            0x4AA => block_4aa(chip8, budget),
+            0x4B0 => block_4b0(chip8, budget),
+            0x4B6 => block_4b6(chip8, budget),
+            0x4CE => block_4ce(chip8, budget),
+            0x4D4 => block_4d4(chip8, budget),
+            0x4DA => block_4da(chip8, budget),
+            0x4E0 => block_4e0(chip8, budget),
+            0x4E6 => block_4e6(chip8, budget),
+            0x4F8 => block_4f8(chip8, budget),
+            0x4FE => block_4fe(chip8, budget),
+            0x504 => block_504(chip8, budget),
+            0x50C => block_50c(chip8, budget),
+            0x514 => block_514(chip8, budget),
+            0x51A => block_51a(chip8, budget),
+            0x524 => block_524(chip8, budget),
+            0x528 => block_528(chip8, budget),
+            0x52E => block_52e(chip8, budget),
+            0x538 => block_538(chip8, budget),
+            0x53C => block_53c(chip8, budget),
+            _ => 0,
+        };
+        // Fall back to the interpreter where there is no block, its code has changed
+        // or it has more instructions than are left to run.
+        count += if ran > 0 { ran } else { chip8.run(1) };
+    }
+    count
+}
+
+/// Run one frame like `Chip8::run_frame`.
+/// @return true if the program has exited.
+pub fn run_frame(chip8: &mut Chip8, num_instructions: u32) -> bool {
+    run(chip8, num_instructions);
+    if chip8.is_exited() {
+        return true;
+    }
+    chip8.tick_timers();
+    false
+}
+
+fn block_200(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x12, 0xA0];
+    if budget < 1 || chip8.get_memory(0x200, CODE.len()) != CODE {
+        return 0;
+    }
+    // 0x200: JP 0x2A0
+    chip8.set_program_counter(0x2A0);
+    1
+}
+
+fn block_214(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x41, 0x00];
+    if budget < 1 || chip8.get_memory(0x214, CODE.len()) != CODE {
+        return 0;
+    }
+    let v = *chip8.get_registers();
+    // 0x214: SNE V1, 0x00
+    chip8.set_program_counter(if v[0x1] != 0x00 { 0x218 } else { 0x216 });
+    1
+}
+
+fn block_218(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x80, 0x10, 0x22, 0x76];
+    if budget < 2 || chip8.get_memory(0x218, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x218: LD V0, V1
+    v[0x0] = v[0x1];
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x21A);
+    // 0x21A: CALL 0x276
+    chip8.run(1);
+    2
+}
+
+fn block_21c(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x42, 0x00];
+    if budget < 1 || chip8.get_memory(0x21C, CODE.len()) != CODE {
+        return 0;
+    }
+    let v = *chip8.get_registers();
+    // 0x21C: SNE V2, 0x00
+    chip8.set_program_counter(if v[0x2] != 0x00 { 0x220 } else { 0x21E });
+    1
+}
+
+fn block_220(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x80, 0x20, 0x22, 0x76];
+    if budget < 2 || chip8.get_memory(0x220, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x220: LD V0, V2
+    v[0x0] = v[0x2];
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x222);
+    // 0x222: CALL 0x276
+    chip8.run(1);
+    2
+}
+
+fn block_224(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x43, 0x00];
+    if budget < 1 || chip8.get_memory(0x224, CODE.len()) != CODE {
+        return 0;
+    }
+    let v = *chip8.get_registers();
+    // 0x224: SNE V3, 0x00
+    chip8.set_program_counter(if v[0x3] != 0x00 { 0x228 } else { 0x226 });
+    1
+}
+
+fn block_228(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x80, 0x30, 0x22, 0x76];
+    if budget < 2 || chip8.get_memory(0x228, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x228: LD V0, V3
+    v[0x0] = v[0x3];
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x22A);
+    // 0x22A: CALL 0x276
+    chip8.run(1);
+    2
+}
+
+fn block_22c(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x44, 0x00];
+    if budget < 1 || chip8.get_memory(0x22C, CODE.len()) != CODE {
+        return 0;
+    }
+    let v = *chip8.get_registers();
+    // 0x22C: SNE V4, 0x00
+    chip8.set_program_counter(if v[0x4] != 0x00 { 0x230 } else { 0x22E });
+    1
+}
+
+fn block_230(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x80, 0x40, 0x22, 0x76];
+    if budget < 2 || chip8.get_memory(0x230, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x230: LD V0, V4
+    v[0x0] = v[0x4];
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x232);
+    // 0x232: CALL 0x276
+    chip8.run(1);
+    2
+}
+
+fn block_234(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x45, 0x00];
+    if budget < 1 || chip8.get_memory(0x234, CODE.len()) != CODE {
+        return 0;
+    }
+    let v = *chip8.get_registers();
+    // 0x234: SNE V5, 0x00
+    chip8.set_program_counter(if v[0x5] != 0x00 { 0x238 } else { 0x236 });
+    1
+}
+
+fn block_238(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x80, 0x50, 0x22, 0x76];
+    if budget < 2 || chip8.get_memory(0x238, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x238: LD V0, V5
+    v[0x0] = v[0x5];
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x23A);
+    // 0x23A: CALL 0x276
+    chip8.run(1);
+    2
+}
+
+fn block_23c(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x46, 0x00];
+    if budget < 1 || chip8.get_memory(0x23C, CODE.len()) != CODE {
+        return 0;
+    }
+    let v = *chip8.get_registers();
+    // 0x23C: SNE V6, 0x00
+    chip8.set_program_counter(if v[0x6] != 0x00 { 0x240 } else { 0x23E });
+    1
+}
+
+fn block_240(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x80, 0x60, 0x22, 0x76];
+    if budget < 2 || chip8.get_memory(0x240, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x240: LD V0, V6
+    v[0x0] = v[0x6];
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x242);
+    // 0x242: CALL 0x276
+    chip8.run(1);
+    2
+}
+
+fn block_244(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x47, 0x00];
+    if budget < 1 || chip8.get_memory(0x244, CODE.len()) != CODE {
+        return 0;
+    }
+    let v = *chip8.get_registers();
+    // 0x244: SNE V7, 0x00
+    chip8.set_program_counter(if v[0x7] != 0x00 { 0x248 } else { 0x246 });
+    1
+}
+
+fn block_248(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x80, 0x70, 0x22, 0x76];
+    if budget < 2 || chip8.get_memory(0x248, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x248: LD V0, V7
+    v[0x0] = v[0x7];
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x24A);
+    // 0x24A: CALL 0x276
+    chip8.run(1);
+    2
+}
+
+fn block_24c(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x48, 0x00];
+    if budget < 1 || chip8.get_memory(0x24C, CODE.len()) != CODE {
+        return 0;
+    }
+    let v = *chip8.get_registers();
+    // 0x24C: SNE V8, 0x00
+    chip8.set_program_counter(if v[0x8] != 0x00 { 0x250 } else { 0x24E });
+    1
+}
+
+fn block_250(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x80, 0x80, 0x22, 0x76];
+    if budget < 2 || chip8.get_memory(0x250, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x250: LD V0, V8
+    v[0x0] = v[0x8];
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x252);
+    // 0x252: CALL 0x276
+    chip8.run(1);
+    2
+}
+
+fn block_254(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x49, 0x00];
+    if budget < 1 || chip8.get_memory(0x254, CODE.len()) != CODE {
+        return 0;
+    }
+    let v = *chip8.get_registers();
+    // 0x254: SNE V9, 0x00
+    chip8.set_program_counter(if v[0x9] != 0x00 { 0x258 } else { 0x256 });
+    1
+}
+
+fn block_258(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x80, 0x90, 0x22, 0x76];
+    if budget < 2 || chip8.get_memory(0x258, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x258: LD V0, V9
+    v[0x0] = v[0x9];
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x25A);
+    // 0x25A: CALL 0x276
+    chip8.run(1);
+    2
+}
+
+fn block_25c(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x4A, 0x00];
+    if budget < 1 || chip8.get_memory(0x25C, CODE.len()) != CODE {
+        return 0;
+    }
+    let v = *chip8.get_registers();
+    // 0x25C: SNE VA, 0x00
+    chip8.set_program_counter(if v[0xA] != 0x00 { 0x260 } else { 0x25E });
+    1
+}
+
+fn block_260(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x80, 0xA0, 0x22, 0x76];
+    if budget < 2 || chip8.get_memory(0x260, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x260: LD V0, VA
+    v[0x0] = v[0xA];
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x262);
+    // 0x262: CALL 0x276
+    chip8.run(1);
+    2
+}
+
+fn block_264(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x4B, 0x00];
+    if budget < 1 || chip8.get_memory(0x264, CODE.len()) != CODE {
+        return 0;
+    }
+    let v = *chip8.get_registers();
+    // 0x264: SNE VB, 0x00
+    chip8.set_program_counter(if v[0xB] != 0x00 { 0x268 } else { 0x266 });
+    1
+}
+
+fn block_268(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x80, 0xB0, 0x22, 0x76];
+    if budget < 2 || chip8.get_memory(0x268, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x268: LD V0, VB
+    v[0x0] = v[0xB];
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x26A);
+    // 0x26A: CALL 0x276
+    chip8.run(1);
+    2
+}
+
+fn block_26c(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x4C, 0x00];
+    if budget < 1 || chip8.get_memory(0x26C, CODE.len()) != CODE {
+        return 0;
+    }
+    let v = *chip8.get_registers();
+    // 0x26C: SNE VC, 0x00
+    chip8.set_program_counter(if v[0xC] != 0x00 { 0x270 } else { 0x26E });
+    1
+}
+
+fn block_270(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x80, 0xC0, 0x22, 0x76];
+    if budget < 2 || chip8.get_memory(0x270, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x270: LD V0, VC
+    v[0x0] = v[0xC];
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x272);
+    // 0x272: CALL 0x276
+    chip8.run(1);
+    2
+}
+
+fn block_276(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0xA5, 0x57, 0xF0, 0x1E, 0xDD, 0xE4];
+    if budget < 3 || chip8.get_memory(0x276, CODE.len()) != CODE {
+        return 0;
+    }
+    let v = *chip8.get_registers();
+    // 0x276: LD I, 0x557
+    chip8.set_pointer(0x557);
+    // 0x278: ADD I, V0
+    chip8.set_pointer(chip8.get_pointer() + v[0x0] as u16);
+    chip8.set_program_counter(0x27A);
+    // 0x27A: DRW VD, VE, 4
+    chip8.run(1);
+    3
+}
+
+fn block_27c(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x7D, 0x04, 0x00, 0xEE];
+    if budget < 2 || chip8.get_memory(0x27C, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x27C: ADD VD, 0x04
+    v[0xD] = v[0xD].wrapping_add(0x04);
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x27E);
+    // 0x27E: RET
+    chip8.run(1);
+    2
+}
+
+fn block_280(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 12] = [
+        0xA5, 0x5B, 0x8E, 0xD0, 0x8E, 0xEE, 0x8E, 0xEE, 0xFE, 0x1E, 0xDA, 0xB4,
+    ];
+    if budget < 6 || chip8.get_memory(0x280, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x280: LD I, 0x55B
+    chip8.set_pointer(0x55B);
+    // 0x282: LD VE, VD
+    v[0xE] = v[0xD];
+    // 0x284: SHL VE
+    {
+        let value = v[0xE];
+        v[0xE] = value << 1;
+        v[0xF] = value >> 7;
+    }
+    // 0x286: SHL VE
+    {
+        let value = v[0xE];
+        v[0xE] = value << 1;
+        v[0xF] = value >> 7;
+    }
+    // 0x288: ADD I, VE
+    chip8.set_pointer(chip8.get_pointer() + v[0xE] as u16);
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x28A);
+    // 0x28A: DRW VA, VB, 4
+    chip8.run(1);
+    6
+}
+
+fn block_28c(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x7A, 0x05, 0x00, 0xEE];
+    if budget < 2 || chip8.get_memory(0x28C, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x28C: ADD VA, 0x05
+    v[0xA] = v[0xA].wrapping_add(0x05);
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x28E);
+    // 0x28E: RET
+    chip8.run(1);
+    2
+}
+
+fn block_290(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0xA5, 0x58, 0x92, 0xC0];
+    if budget < 2 || chip8.get_memory(0x290, CODE.len()) != CODE {
+        return 0;
+    }
+    let v = *chip8.get_registers();
+    // 0x290: LD I, 0x558
+    chip8.set_pointer(0x558);
+    // 0x292: SNE V2, VC
+    chip8.set_program_counter(if v[0x2] != v[0xC] { 0x296 } else { 0x294 });
+    2
+}
+
+fn block_294(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0xA5, 0x55];
+    if budget < 1 || chip8.get_memory(0x294, CODE.len()) != CODE {
+        return 0;
+    }
+    // 0x294: LD I, 0x555
+    chip8.set_pointer(0x555);
+    chip8.set_program_counter(0x296);
+    1
+}
+
+fn block_296(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x7B, 0x01, 0xDA, 0xB3];
+    if budget < 2 || chip8.get_memory(0x296, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x296: ADD VB, 0x01
+    v[0xB] = v[0xB].wrapping_add(0x01);
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x298);
+    // 0x298: DRW VA, VB, 3
+    chip8.run(1);
+    2
+}
+
+fn block_29a(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x7A, 0x04, 0x7B, 0xFF, 0x00, 0xEE];
+    if budget < 3 || chip8.get_memory(0x29A, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x29A: ADD VA, 0x04
+    v[0xA] = v[0xA].wrapping_add(0x04);
+    // 0x29C: ADD VB, 0xFF
+    v[0xB] = v[0xB].wrapping_add(0xFF);
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x29E);
+    // 0x29E: RET
+    chip8.run(1);
+    3
+}
+
+fn block_2a2(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 8] = [0x6A, 0x32, 0x6B, 0x1B, 0xA6, 0x09, 0xDA, 0xB4];
+    if budget < 4 || chip8.get_memory(0x2A2, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x2A2: LD VA, 0x32
+    v[0xA] = 0x32;
+    // 0x2A4: LD VB, 0x1B
+    v[0xB] = 0x1B;
+    // 0x2A6: LD I, 0x609
+    chip8.set_pointer(0x609);
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x2A8);
+    // 0x2A8: DRW VA, VB, 4
+    chip8.run(1);
+    4
+}
+
+fn block_2aa(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x6A, 0x3A, 0xA6, 0x0D, 0xDA, 0xB4];
+    if budget < 3 || chip8.get_memory(0x2AA, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x2AA: LD VA, 0x3A
+    v[0xA] = 0x3A;
+    // 0x2AC: LD I, 0x60D
+    chip8.set_pointer(0x60D);
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x2AE);
+    // 0x2AE: DRW VA, VB, 4
+    chip8.run(1);
+    3
+}
+
+fn block_2b0(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 8] = [0x6D, 0x00, 0x6E, 0x00, 0xA5, 0xF7, 0x22, 0x10];
+    if budget < 4 || chip8.get_memory(0x2B0, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x2B0: LD VD, 0x00
+    v[0xD] = 0x00;
+    // 0x2B2: LD VE, 0x00
+    v[0xE] = 0x00;
+    // 0x2B4: LD I, 0x5F7
+    chip8.set_pointer(0x5F7);
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x2B6);
+    // 0x2B6: CALL 0x210
+    chip8.run(1);
+    4
+}
+
+fn block_2b8(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 10] = [0x6A, 0x16, 0x6B, 0x00, 0x61, 0x0F, 0x6D, 0x01, 0x22, 0x80];
+    if budget < 5 || chip8.get_memory(0x2B8, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x2B8: LD VA, 0x16
+    v[0xA] = 0x16;
+    // 0x2BA: LD VB, 0x00
+    v[0xB] = 0x00;
+    // 0x2BC: LD V1, 0x0F
+    v[0x1] = 0x0F;
+    // 0x2BE: LD VD, 0x01
+    v[0xD] = 0x01;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x2C0);
+    // 0x2C0: CALL 0x280
+    chip8.run(1);
+    5
+}
+
+fn block_2c2(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 18] = [
+        0x63, 0x0F, 0x6F, 0x14, 0x83, 0xF1, 0x6F, 0x00, 0x62, 0x32, 0x82, 0x11, 0x8E, 0xF0, 0x6C,
+        0x3F, 0x22, 0x90,
+    ];
+    if budget < 9 || chip8.get_memory(0x2C2, CODE.len()) != CODE {
+        return 0;
+    }
+    let quirks = chip8.get_quirks();
+    let mut v = *chip8.get_registers();
+    // 0x2C2: LD V3, 0x0F
+    v[0x3] = 0x0F;
+    // 0x2C4: LD VF, 0x14
+    v[0xF] = 0x14;
+    // 0x2C6: OR V3, VF
+    v[0x3] |= v[0xF];
+    if quirks.vf_reset {
+        v[0xF] = 0;
+    }
+    // 0x2C8: LD VF, 0x00
+    v[0xF] = 0x00;
+    // 0x2CA: LD V2, 0x32
+    v[0x2] = 0x32;
+    // 0x2CC: OR V2, V1
+    v[0x2] |= v[0x1];
+    if quirks.vf_reset {
+        v[0xF] = 0;
+    }
+    // 0x2CE: LD VE, VF
+    v[0xE] = v[0xF];
+    // 0x2D0: LD VC, 0x3F
+    v[0xC] = 0x3F;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x2D2);
+    // 0x2D2: CALL 0x290
+    chip8.run(1);
+    9
+}
+
+fn block_2d4(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0xE0, 0x6C, 0x00, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x2D4, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x2D4: LD V2, VE
+    v[0x2] = v[0xE];
+    // 0x2D6: LD VC, 0x00
+    v[0xC] = 0x00;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x2D8);
+    // 0x2D8: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_2da(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0x30, 0x6C, 0x1F, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x2DA, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x2DA: LD V2, V3
+    v[0x2] = v[0x3];
+    // 0x2DC: LD VC, 0x1F
+    v[0xC] = 0x1F;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x2DE);
+    // 0x2DE: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_2e0(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x7A, 0x05, 0x6D, 0x02, 0x22, 0x80];
+    if budget < 3 || chip8.get_memory(0x2E0, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x2E0: ADD VA, 0x05
+    v[0xA] = v[0xA].wrapping_add(0x05);
+    // 0x2E2: LD VD, 0x02
+    v[0xD] = 0x02;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x2E4);
+    // 0x2E4: CALL 0x280
+    chip8.run(1);
+    3
+}
+
+fn block_2e6(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 18] = [
+        0x63, 0x0F, 0x6F, 0x14, 0x83, 0xF2, 0x6F, 0x00, 0x62, 0x32, 0x82, 0x12, 0x8E, 0xF0, 0x6C,
+        0x02, 0x22, 0x90,
+    ];
+    if budget < 9 || chip8.get_memory(0x2E6, CODE.len()) != CODE {
+        return 0;
+    }
+    let quirks = chip8.get_quirks();
+    let mut v = *chip8.get_registers();
+    // 0x2E6: LD V3, 0x0F
+    v[0x3] = 0x0F;
+    // 0x2E8: LD VF, 0x14
+    v[0xF] = 0x14;
+    // 0x2EA: AND V3, VF
+    v[0x3] &= v[0xF];
+    if quirks.vf_reset {
+        v[0xF] = 0;
+    }
+    // 0x2EC: LD VF, 0x00
+    v[0xF] = 0x00;
+    // 0x2EE: LD V2, 0x32
+    v[0x2] = 0x32;
+    // 0x2F0: AND V2, V1
+    v[0x2] &= v[0x1];
+    if quirks.vf_reset {
+        v[0xF] = 0;
+    }
+    // 0x2F2: LD VE, VF
+    v[0xE] = v[0xF];
+    // 0x2F4: LD VC, 0x02
+    v[0xC] = 0x02;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x2F6);
+    // 0x2F6: CALL 0x290
+    chip8.run(1);
+    9
+}
+
+fn block_2f8(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0xE0, 0x6C, 0x00, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x2F8, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x2F8: LD V2, VE
+    v[0x2] = v[0xE];
+    // 0x2FA: LD VC, 0x00
+    v[0xC] = 0x00;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x2FC);
+    // 0x2FC: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_2fe(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0x30, 0x6C, 0x04, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x2FE, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x2FE: LD V2, V3
+    v[0x2] = v[0x3];
+    // 0x300: LD VC, 0x04
+    v[0xC] = 0x04;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x302);
+    // 0x302: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_304(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 8] = [0x7B, 0x05, 0x6A, 0x00, 0x6D, 0x03, 0x22, 0x80];
+    if budget < 4 || chip8.get_memory(0x304, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x304: ADD VB, 0x05
+    v[0xB] = v[0xB].wrapping_add(0x05);
+    // 0x306: LD VA, 0x00
+    v[0xA] = 0x00;
+    // 0x308: LD VD, 0x03
+    v[0xD] = 0x03;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x30A);
+    // 0x30A: CALL 0x280
+    chip8.run(1);
+    4
+}
+
+fn block_30c(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 18] = [
+        0x63, 0x0F, 0x6F, 0x14, 0x83, 0xF3, 0x6F, 0x00, 0x62, 0x32, 0x82, 0x13, 0x8E, 0xF0, 0x6C,
+        0x3D, 0x22, 0x90,
+    ];
+    if budget < 9 || chip8.get_memory(0x30C, CODE.len()) != CODE {
+        return 0;
+    }
+    let quirks = chip8.get_quirks();
+    let mut v = *chip8.get_registers();
+    // 0x30C: LD V3, 0x0F
+    v[0x3] = 0x0F;
+    // 0x30E: LD VF, 0x14
+    v[0xF] = 0x14;
+    // 0x310: XOR V3, VF
+    v[0x3] ^= v[0xF];
+    if quirks.vf_reset {
+        v[0xF] = 0;
+    }
+    // 0x312: LD VF, 0x00
+    v[0xF] = 0x00;
+    // 0x314: LD V2, 0x32
+    v[0x2] = 0x32;
+    // 0x316: XOR V2, V1
+    v[0x2] ^= v[0x1];
+    if quirks.vf_reset {
+        v[0xF] = 0;
+    }
+    // 0x318: LD VE, VF
+    v[0xE] = v[0xF];
+    // 0x31A: LD VC, 0x3D
+    v[0xC] = 0x3D;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x31C);
+    // 0x31C: CALL 0x290
+    chip8.run(1);
+    9
+}
+
+fn block_31e(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0xE0, 0x6C, 0x00, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x31E, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x31E: LD V2, VE
+    v[0x2] = v[0xE];
+    // 0x320: LD VC, 0x00
+    v[0xC] = 0x00;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x322);
+    // 0x322: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_324(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0x30, 0x6C, 0x1B, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x324, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x324: LD V2, V3
+    v[0x2] = v[0x3];
+    // 0x326: LD VC, 0x1B
+    v[0xC] = 0x1B;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x328);
+    // 0x328: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_32a(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x7A, 0x05, 0x6D, 0x04, 0x22, 0x80];
+    if budget < 3 || chip8.get_memory(0x32A, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x32A: ADD VA, 0x05
+    v[0xA] = v[0xA].wrapping_add(0x05);
+    // 0x32C: LD VD, 0x04
+    v[0xD] = 0x04;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x32E);
+    // 0x32E: CALL 0x280
+    chip8.run(1);
+    3
+}
+
+fn block_330(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 24] = [
+        0x6F, 0x14, 0x8F, 0x14, 0x84, 0xF0, 0x63, 0x0F, 0x6F, 0x14, 0x83, 0xF4, 0x6F, 0xAA, 0x62,
+        0x32, 0x82, 0x14, 0x8E, 0xF0, 0x6C, 0x41, 0x22, 0x90,
+    ];
+    if budget < 12 || chip8.get_memory(0x330, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x330: LD VF, 0x14
+    v[0xF] = 0x14;
+    // 0x332: ADD VF, V1
+    {
+        let (sum, carry) = v[0xF].overflowing_add(v[0x1]);
+        v[0xF] = sum;
+        v[0xF] = carry as u8;
+    }
+    // 0x334: LD V4, VF
+    v[0x4] = v[0xF];
+    // 0x336: LD V3, 0x0F
+    v[0x3] = 0x0F;
+    // 0x338: LD VF, 0x14
+    v[0xF] = 0x14;
+    // 0x33A: ADD V3, VF
+    {
+        let (sum, carry) = v[0x3].overflowing_add(v[0xF]);
+        v[0x3] = sum;
+        v[0xF] = carry as u8;
+    }
+    // 0x33C: LD VF, 0xAA
+    v[0xF] = 0xAA;
+    // 0x33E: LD V2, 0x32
+    v[0x2] = 0x32;
+    // 0x340: ADD V2, V1
+    {
+        let (sum, carry) = v[0x2].overflowing_add(v[0x1]);
+        v[0x2] = sum;
+        v[0xF] = carry as u8;
+    }
+    // 0x342: LD VE, VF
+    v[0xE] = v[0xF];
+    // 0x344: LD VC, 0x41
+    v[0xC] = 0x41;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x346);
+    // 0x346: CALL 0x290
+    chip8.run(1);
+    12
+}
+
+fn block_348(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0xE0, 0x6C, 0x00, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x348, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x348: LD V2, VE
+    v[0x2] = v[0xE];
+    // 0x34A: LD VC, 0x00
+    v[0xC] = 0x00;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x34C);
+    // 0x34C: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_34e(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0x30, 0x6C, 0x23, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x34E, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x34E: LD V2, V3
+    v[0x2] = v[0x3];
+    // 0x350: LD VC, 0x23
+    v[0xC] = 0x23;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x352);
+    // 0x352: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_354(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0x40, 0x6C, 0x00, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x354, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x354: LD V2, V4
+    v[0x2] = v[0x4];
+    // 0x356: LD VC, 0x00
+    v[0xC] = 0x00;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x358);
+    // 0x358: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_35a(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x7A, 0x01, 0x6D, 0x05, 0x22, 0x80];
+    if budget < 3 || chip8.get_memory(0x35A, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x35A: ADD VA, 0x01
+    v[0xA] = v[0xA].wrapping_add(0x01);
+    // 0x35C: LD VD, 0x05
+    v[0xD] = 0x05;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x35E);
+    // 0x35E: CALL 0x280
+    chip8.run(1);
+    3
+}
+
+fn block_360(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 28] = [
+        0x6F, 0x14, 0x8F, 0x15, 0x84, 0xF0, 0x63, 0x14, 0x6F, 0x0F, 0x83, 0xF5, 0x65, 0x0A, 0x6F,
+        0x0A, 0x85, 0xF5, 0x85, 0xF0, 0x6F, 0xAA, 0x62, 0x32, 0x82, 0x15, 0x35, 0x01,
+    ];
+    if budget < 14 || chip8.get_memory(0x360, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x360: LD VF, 0x14
+    v[0xF] = 0x14;
+    // 0x362: SUB VF, V1
+    {
+        let (x, y) = (v[0xF], v[0x1]);
+        v[0xF] = x.wrapping_sub(y);
+        v[0xF] = (x >= y) as u8;
+    }
+    // 0x364: LD V4, VF
+    v[0x4] = v[0xF];
+    // 0x366: LD V3, 0x14
+    v[0x3] = 0x14;
+    // 0x368: LD VF, 0x0F
+    v[0xF] = 0x0F;
+    // 0x36A: SUB V3, VF
+    {
+        let (x, y) = (v[0x3], v[0xF]);
+        v[0x3] = x.wrapping_sub(y);
+        v[0xF] = (x >= y) as u8;
+    }
+    // 0x36C: LD V5, 0x0A
+    v[0x5] = 0x0A;
+    // 0x36E: LD VF, 0x0A
+    v[0xF] = 0x0A;
+    // 0x370: SUB V5, VF
+    {
+        let (x, y) = (v[0x5], v[0xF]);
+        v[0x5] = x.wrapping_sub(y);
+        v[0xF] = (x >= y) as u8;
+    }
+    // 0x372: LD V5, VF
+    v[0x5] = v[0xF];
+    // 0x374: LD VF, 0xAA
+    v[0xF] = 0xAA;
+    // 0x376: LD V2, 0x32
+    v[0x2] = 0x32;
+    // 0x378: SUB V2, V1
+    {
+        let (x, y) = (v[0x2], v[0x1]);
+        v[0x2] = x.wrapping_sub(y);
+        v[0xF] = (x >= y) as u8;
+    }
+    // 0x37A: SE V5, 0x01
+    chip8.set_registers(&v);
+    chip8.set_program_counter(if v[0x5] == 0x01 { 0x37E } else { 0x37C });
+    14
+}
+
+fn block_37c(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x6F, 0x02];
+    if budget < 1 || chip8.get_memory(0x37C, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x37C: LD VF, 0x02
+    v[0xF] = 0x02;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x37E);
+    1
+}
+
+fn block_37e(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x8E, 0xF0, 0x6C, 0x23, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x37E, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x37E: LD VE, VF
+    v[0xE] = v[0xF];
+    // 0x380: LD VC, 0x23
+    v[0xC] = 0x23;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x382);
+    // 0x382: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_384(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0xE0, 0x6C, 0x01, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x384, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x384: LD V2, VE
+    v[0x2] = v[0xE];
+    // 0x386: LD VC, 0x01
+    v[0xC] = 0x01;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x388);
+    // 0x388: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_38a(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0x30, 0x6C, 0x05, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x38A, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x38A: LD V2, V3
+    v[0x2] = v[0x3];
+    // 0x38C: LD VC, 0x05
+    v[0xC] = 0x05;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x38E);
+    // 0x38E: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_390(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0x40, 0x6C, 0x01, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x390, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x390: LD V2, V4
+    v[0x2] = v[0x4];
+    // 0x392: LD VC, 0x01
+    v[0xC] = 0x01;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x394);
+    // 0x394: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_396(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 8] = [0x7B, 0x05, 0x6A, 0x00, 0x6D, 0x06, 0x22, 0x80];
+    if budget < 4 || chip8.get_memory(0x396, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x396: ADD VB, 0x05
+    v[0xB] = v[0xB].wrapping_add(0x05);
+    // 0x398: LD VA, 0x00
+    v[0xA] = 0x00;
+    // 0x39A: LD VD, 0x06
+    v[0xD] = 0x06;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x39C);
+    // 0x39C: CALL 0x280
+    chip8.run(1);
+    4
+}
+
+fn block_39e(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 18] = [
+        0x6F, 0x3C, 0x8F, 0xF6, 0x83, 0xF0, 0x6F, 0xAA, 0x62, 0x3C, 0x82, 0x26, 0x8E, 0xF0, 0x6C,
+        0x1E, 0x22, 0x90,
+    ];
+    if budget < 9 || chip8.get_memory(0x39E, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x39E: LD VF, 0x3C
+    v[0xF] = 0x3C;
+    // 0x3A0: SHR VF
+    {
+        let value = v[0xF];
+        v[0xF] = value >> 1;
+        v[0xF] = value & 0x1;
+    }
+    // 0x3A2: LD V3, VF
+    v[0x3] = v[0xF];
+    // 0x3A4: LD VF, 0xAA
+    v[0xF] = 0xAA;
+    // 0x3A6: LD V2, 0x3C
+    v[0x2] = 0x3C;
+    // 0x3A8: SHR V2
+    {
+        let value = v[0x2];
+        v[0x2] = value >> 1;
+        v[0xF] = value & 0x1;
+    }
+    // 0x3AA: LD VE, VF
+    v[0xE] = v[0xF];
+    // 0x3AC: LD VC, 0x1E
+    v[0xC] = 0x1E;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x3AE);
+    // 0x3AE: CALL 0x290
+    chip8.run(1);
+    9
+}
+
+fn block_3b0(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0xE0, 0x6C, 0x00, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x3B0, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x3B0: LD V2, VE
+    v[0x2] = v[0xE];
+    // 0x3B2: LD VC, 0x00
+    v[0xC] = 0x00;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x3B4);
+    // 0x3B4: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_3b6(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0x30, 0x6C, 0x00, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x3B6, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x3B6: LD V2, V3
+    v[0x2] = v[0x3];
+    // 0x3B8: LD VC, 0x00
+    v[0xC] = 0x00;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x3BA);
+    // 0x3BA: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_3bc(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x7A, 0x05, 0x6D, 0x07, 0x22, 0x80];
+    if budget < 3 || chip8.get_memory(0x3BC, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x3BC: ADD VA, 0x05
+    v[0xA] = v[0xA].wrapping_add(0x05);
+    // 0x3BE: LD VD, 0x07
+    v[0xD] = 0x07;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x3C0);
+    // 0x3C0: CALL 0x280
+    chip8.run(1);
+    3
+}
+
+fn block_3c2(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 30] = [
+        0x6F, 0x0A, 0x8F, 0x17, 0x84, 0xF0, 0x63, 0x0F, 0x6F, 0x14, 0x83, 0xF7, 0x65, 0x0A, 0x6F,
+        0x0A, 0x85, 0xF7, 0x85, 0xF0, 0x6F, 0xAA, 0x62, 0x0F, 0x61, 0x32, 0x82, 0x17, 0x35, 0x01,
+    ];
+    if budget < 15 || chip8.get_memory(0x3C2, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x3C2: LD VF, 0x0A
+    v[0xF] = 0x0A;
+    // 0x3C4: SUBN VF, V1
+    {
+        let (x, y) = (v[0xF], v[0x1]);
+        v[0xF] = y.wrapping_sub(x);
+        v[0xF] = (y >= x) as u8;
+    }
+    // 0x3C6: LD V4, VF
+    v[0x4] = v[0xF];
+    // 0x3C8: LD V3, 0x0F
+    v[0x3] = 0x0F;
+    // 0x3CA: LD VF, 0x14
+    v[0xF] = 0x14;
+    // 0x3CC: SUBN V3, VF
+    {
+        let (x, y) = (v[0x3], v[0xF]);
+        v[0x3] = y.wrapping_sub(x);
+        v[0xF] = (y >= x) as u8;
+    }
+    // 0x3CE: LD V5, 0x0A
+    v[0x5] = 0x0A;
+    // 0x3D0: LD VF, 0x0A
+    v[0xF] = 0x0A;
+    // 0x3D2: SUBN V5, VF
+    {
+        let (x, y) = (v[0x5], v[0xF]);
+        v[0x5] = y.wrapping_sub(x);
+        v[0xF] = (y >= x) as u8;
+    }
+    // 0x3D4: LD V5, VF
+    v[0x5] = v[0xF];
+    // 0x3D6: LD VF, 0xAA
+    v[0xF] = 0xAA;
+    // 0x3D8: LD V2, 0x0F
+    v[0x2] = 0x0F;
+    // 0x3DA: LD V1, 0x32
+    v[0x1] = 0x32;
+    // 0x3DC: SUBN V2, V1
+    {
+        let (x, y) = (v[0x2], v[0x1]);
+        v[0x2] = y.wrapping_sub(x);
+        v[0xF] = (y >= x) as u8;
+    }
+    // 0x3DE: SE V5, 0x01
+    chip8.set_registers(&v);
+    chip8.set_program_counter(if v[0x5] == 0x01 { 0x3E2 } else { 0x3E0 });
+    15
+}
+
+fn block_3e0(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x6F, 0x02];
+    if budget < 1 || chip8.get_memory(0x3E0, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x3E0: LD VF, 0x02
+    v[0xF] = 0x02;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x3E2);
+    1
+}
+
+fn block_3e2(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x8E, 0xF0, 0x6C, 0x23, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x3E2, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x3E2: LD VE, VF
+    v[0xE] = v[0xF];
+    // 0x3E4: LD VC, 0x23
+    v[0xC] = 0x23;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x3E6);
+    // 0x3E6: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_3e8(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0xE0, 0x6C, 0x01, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x3E8, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x3E8: LD V2, VE
+    v[0x2] = v[0xE];
+    // 0x3EA: LD VC, 0x01
+    v[0xC] = 0x01;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x3EC);
+    // 0x3EC: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_3ee(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0x30, 0x6C, 0x05, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x3EE, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x3EE: LD V2, V3
+    v[0x2] = v[0x3];
+    // 0x3F0: LD VC, 0x05
+    v[0xC] = 0x05;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x3F2);
+    // 0x3F2: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_3f4(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0x40, 0x6C, 0x01, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x3F4, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x3F4: LD V2, V4
+    v[0x2] = v[0x4];
+    // 0x3F6: LD VC, 0x01
+    v[0xC] = 0x01;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x3F8);
+    // 0x3F8: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_3fa(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x7A, 0x01, 0x6D, 0x0E, 0x22, 0x80];
+    if budget < 3 || chip8.get_memory(0x3FA, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x3FA: ADD VA, 0x01
+    v[0xA] = v[0xA].wrapping_add(0x01);
+    // 0x3FC: LD VD, 0x0E
+    v[0xD] = 0x0E;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x3FE);
+    // 0x3FE: CALL 0x280
+    chip8.run(1);
+    3
+}
+
+fn block_400(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 18] = [
+        0x6F, 0x32, 0x8F, 0xFE, 0x83, 0xF0, 0x6F, 0xAA, 0x62, 0x32, 0x82, 0x2E, 0x8E, 0xF0, 0x6C,
+        0x64, 0x22, 0x90,
+    ];
+    if budget < 9 || chip8.get_memory(0x400, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x400: LD VF, 0x32
+    v[0xF] = 0x32;
+    // 0x402: SHL VF
+    {
+        let value = v[0xF];
+        v[0xF] = value << 1;
+        v[0xF] = value >> 7;
+    }
+    // 0x404: LD V3, VF
+    v[0x3] = v[0xF];
+    // 0x406: LD VF, 0xAA
+    v[0xF] = 0xAA;
+    // 0x408: LD V2, 0x32
+    v[0x2] = 0x32;
+    // 0x40A: SHL V2
+    {
+        let value = v[0x2];
+        v[0x2] = value << 1;
+        v[0xF] = value >> 7;
+    }
+    // 0x40C: LD VE, VF
+    v[0xE] = v[0xF];
+    // 0x40E: LD VC, 0x64
+    v[0xC] = 0x64;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x410);
+    // 0x410: CALL 0x290
+    chip8.run(1);
+    9
+}
+
+fn block_412(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0xE0, 0x6C, 0x00, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x412, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x412: LD V2, VE
+    v[0x2] = v[0xE];
+    // 0x414: LD VC, 0x00
+    v[0xC] = 0x00;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x416);
+    // 0x416: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_418(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0x30, 0x6C, 0x00, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x418, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x418: LD V2, V3
+    v[0x2] = v[0x3];
+    // 0x41A: LD VC, 0x00
+    v[0xC] = 0x00;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x41C);
+    // 0x41C: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_41e(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 8] = [0x6D, 0x00, 0x6E, 0x10, 0xA5, 0xFD, 0x22, 0x10];
+    if budget < 4 || chip8.get_memory(0x41E, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x41E: LD VD, 0x00
+    v[0xD] = 0x00;
+    // 0x420: LD VE, 0x10
+    v[0xE] = 0x10;
+    // 0x422: LD I, 0x5FD
+    chip8.set_pointer(0x5FD);
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x424);
+    // 0x424: CALL 0x210
+    chip8.run(1);
+    4
+}
+
+fn block_426(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 10] = [0x6A, 0x16, 0x6B, 0x10, 0x61, 0x64, 0x6D, 0x04, 0x22, 0x80];
+    if budget < 5 || chip8.get_memory(0x426, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x426: LD VA, 0x16
+    v[0xA] = 0x16;
+    // 0x428: LD VB, 0x10
+    v[0xB] = 0x10;
+    // 0x42A: LD V1, 0x64
+    v[0x1] = 0x64;
+    // 0x42C: LD VD, 0x04
+    v[0xD] = 0x04;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x42E);
+    // 0x42E: CALL 0x280
+    chip8.run(1);
+    5
+}
+
+fn block_430(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 24] = [
+        0x6F, 0xC8, 0x8F, 0x14, 0x84, 0xF0, 0x63, 0x64, 0x6F, 0xC8, 0x83, 0xF4, 0x6F, 0xAA, 0x62,
+        0xC8, 0x82, 0x14, 0x8E, 0xF0, 0x6C, 0x2C, 0x22, 0x90,
+    ];
+    if budget < 12 || chip8.get_memory(0x430, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x430: LD VF, 0xC8
+    v[0xF] = 0xC8;
+    // 0x432: ADD VF, V1
+    {
+        let (sum, carry) = v[0xF].overflowing_add(v[0x1]);
+        v[0xF] = sum;
+        v[0xF] = carry as u8;
+    }
+    // 0x434: LD V4, VF
+    v[0x4] = v[0xF];
+    // 0x436: LD V3, 0x64
+    v[0x3] = 0x64;
+    // 0x438: LD VF, 0xC8
+    v[0xF] = 0xC8;
+    // 0x43A: ADD V3, VF
+    {
+        let (sum, carry) = v[0x3].overflowing_add(v[0xF]);
+        v[0x3] = sum;
+        v[0xF] = carry as u8;
+    }
+    // 0x43C: LD VF, 0xAA
+    v[0xF] = 0xAA;
+    // 0x43E: LD V2, 0xC8
+    v[0x2] = 0xC8;
+    // 0x440: ADD V2, V1
+    {
+        let (sum, carry) = v[0x2].overflowing_add(v[0x1]);
+        v[0x2] = sum;
+        v[0xF] = carry as u8;
+    }
+    // 0x442: LD VE, VF
+    v[0xE] = v[0xF];
+    // 0x444: LD VC, 0x2C
+    v[0xC] = 0x2C;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x446);
+    // 0x446: CALL 0x290
+    chip8.run(1);
+    12
+}
+
+fn block_448(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0xE0, 0x6C, 0x01, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x448, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x448: LD V2, VE
+    v[0x2] = v[0xE];
+    // 0x44A: LD VC, 0x01
+    v[0xC] = 0x01;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x44C);
+    // 0x44C: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_44e(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0x30, 0x6C, 0x2C, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x44E, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x44E: LD V2, V3
+    v[0x2] = v[0x3];
+    // 0x450: LD VC, 0x2C
+    v[0xC] = 0x2C;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x452);
+    // 0x452: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_454(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0x40, 0x6C, 0x01, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x454, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x454: LD V2, V4
+    v[0x2] = v[0x4];
+    // 0x456: LD VC, 0x01
+    v[0xC] = 0x01;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x458);
+    // 0x458: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_45a(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x7A, 0x01, 0x6D, 0x05, 0x22, 0x80];
+    if budget < 3 || chip8.get_memory(0x45A, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x45A: ADD VA, 0x01
+    v[0xA] = v[0xA].wrapping_add(0x01);
+    // 0x45C: LD VD, 0x05
+    v[0xD] = 0x05;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x45E);
+    // 0x45E: CALL 0x280
+    chip8.run(1);
+    3
+}
+
+fn block_460(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 24] = [
+        0x6F, 0x5F, 0x8F, 0x15, 0x84, 0xF0, 0x63, 0x5F, 0x6F, 0x64, 0x83, 0xF5, 0x6F, 0xAA, 0x62,
+        0x5F, 0x82, 0x15, 0x8E, 0xF0, 0x6C, 0xFB, 0x22, 0x90,
+    ];
+    if budget < 12 || chip8.get_memory(0x460, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x460: LD VF, 0x5F
+    v[0xF] = 0x5F;
+    // 0x462: SUB VF, V1
+    {
+        let (x, y) = (v[0xF], v[0x1]);
+        v[0xF] = x.wrapping_sub(y);
+        v[0xF] = (x >= y) as u8;
+    }
+    // 0x464: LD V4, VF
+    v[0x4] = v[0xF];
+    // 0x466: LD V3, 0x5F
+    v[0x3] = 0x5F;
+    // 0x468: LD VF, 0x64
+    v[0xF] = 0x64;
+    // 0x46A: SUB V3, VF
+    {
+        let (x, y) = (v[0x3], v[0xF]);
+        v[0x3] = x.wrapping_sub(y);
+        v[0xF] = (x >= y) as u8;
+    }
+    // 0x46C: LD VF, 0xAA
+    v[0xF] = 0xAA;
+    // 0x46E: LD V2, 0x5F
+    v[0x2] = 0x5F;
+    // 0x470: SUB V2, V1
+    {
+        let (x, y) = (v[0x2], v[0x1]);
+        v[0x2] = x.wrapping_sub(y);
+        v[0xF] = (x >= y) as u8;
+    }
+    // 0x472: LD VE, VF
+    v[0xE] = v[0xF];
+    // 0x474: LD VC, 0xFB
+    v[0xC] = 0xFB;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x476);
+    // 0x476: CALL 0x290
+    chip8.run(1);
+    12
+}
+
+fn block_478(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0xE0, 0x6C, 0x00, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x478, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x478: LD V2, VE
+    v[0x2] = v[0xE];
+    // 0x47A: LD VC, 0x00
+    v[0xC] = 0x00;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x47C);
+    // 0x47C: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_47e(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0x30, 0x6C, 0xFB, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x47E, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x47E: LD V2, V3
+    v[0x2] = v[0x3];
+    // 0x480: LD VC, 0xFB
+    v[0xC] = 0xFB;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x482);
+    // 0x482: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_484(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0x40, 0x6C, 0x00, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x484, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x484: LD V2, V4
+    v[0x2] = v[0x4];
+    // 0x486: LD VC, 0x00
+    v[0xC] = 0x00;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x488);
+    // 0x488: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_48a(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 8] = [0x7B, 0x05, 0x6A, 0x00, 0x6D, 0x06, 0x22, 0x80];
+    if budget < 4 || chip8.get_memory(0x48A, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x48A: ADD VB, 0x05
+    v[0xB] = v[0xB].wrapping_add(0x05);
+    // 0x48C: LD VA, 0x00
+    v[0xA] = 0x00;
+    // 0x48E: LD VD, 0x06
+    v[0xD] = 0x06;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x490);
+    // 0x490: CALL 0x280
+    chip8.run(1);
+    4
+}
+
+fn block_492(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 18] = [
+        0x6F, 0x3D, 0x8F, 0xF6, 0x83, 0xF0, 0x6F, 0xAA, 0x62, 0x3D, 0x82, 0x26, 0x8E, 0xF0, 0x6C,
+        0x1E, 0x22, 0x90,
+    ];
+    if budget < 9 || chip8.get_memory(0x492, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x492: LD VF, 0x3D
+    v[0xF] = 0x3D;
+    // 0x494: SHR VF
+    {
+        let value = v[0xF];
+        v[0xF] = value >> 1;
+        v[0xF] = value & 0x1;
+    }
+    // 0x496: LD V3, VF
+    v[0x3] = v[0xF];
+    // 0x498: LD VF, 0xAA
+    v[0xF] = 0xAA;
+    // 0x49A: LD V2, 0x3D
+    v[0x2] = 0x3D;
+    // 0x49C: SHR V2
+    {
+        let value = v[0x2];
+        v[0x2] = value >> 1;
+        v[0xF] = value & 0x1;
+    }
+    // 0x49E: LD VE, VF
+    v[0xE] = v[0xF];
+    // 0x4A0: LD VC, 0x1E
+    v[0xC] = 0x1E;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x4A2);
+    // 0x4A2: CALL 0x290
+    chip8.run(1);
+    9
+}
+
+fn block_4a4(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0xE0, 0x6C, 0x01, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x4A4, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x4A4: LD V2, VE
+    v[0x2] = v[0xE];
+    // 0x4A6: LD VC, 0x01
+    v[0xC] = 0x01;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x4A8);
+    // 0x4A8: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_4aa(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0x30, 0x6C, 0x01, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x4AA, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x4AA: LD V2, V3
+    v[0x2] = v[0x3];
+    // 0x4AC: LD VC, 0x01
+    v[0xC] = 0x01;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x4AE);
+    // 0x4AE: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_4b0(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x7A, 0x05, 0x6D, 0x07, 0x22, 0x80];
+    if budget < 3 || chip8.get_memory(0x4B0, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x4B0: ADD VA, 0x05
+    v[0xA] = v[0xA].wrapping_add(0x05);
+    // 0x4B2: LD VD, 0x07
+    v[0xD] = 0x07;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x4B4);
+    // 0x4B4: CALL 0x280
+    chip8.run(1);
+    3
+}
+
+fn block_4b6(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 24] = [
+        0x6F, 0x69, 0x8F, 0x17, 0x84, 0xF0, 0x63, 0x69, 0x6F, 0x64, 0x83, 0xF7, 0x6F, 0xAA, 0x62,
+        0x69, 0x82, 0x17, 0x8E, 0xF0, 0x6C, 0xFB, 0x22, 0x90,
+    ];
+    if budget < 12 || chip8.get_memory(0x4B6, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x4B6: LD VF, 0x69
+    v[0xF] = 0x69;
+    // 0x4B8: SUBN VF, V1
+    {
+        let (x, y) = (v[0xF], v[0x1]);
+        v[0xF] = y.wrapping_sub(x);
+        v[0xF] = (y >= x) as u8;
+    }
+    // 0x4BA: LD V4, VF
+    v[0x4] = v[0xF];
+    // 0x4BC: LD V3, 0x69
+    v[0x3] = 0x69;
+    // 0x4BE: LD VF, 0x64
+    v[0xF] = 0x64;
+    // 0x4C0: SUBN V3, VF
+    {
+        let (x, y) = (v[0x3], v[0xF]);
+        v[0x3] = y.wrapping_sub(x);
+        v[0xF] = (y >= x) as u8;
+    }
+    // 0x4C2: LD VF, 0xAA
+    v[0xF] = 0xAA;
+    // 0x4C4: LD V2, 0x69
+    v[0x2] = 0x69;
+    // 0x4C6: SUBN V2, V1
+    {
+        let (x, y) = (v[0x2], v[0x1]);
+        v[0x2] = y.wrapping_sub(x);
+        v[0xF] = (y >= x) as u8;
+    }
+    // 0x4C8: LD VE, VF
+    v[0xE] = v[0xF];
+    // 0x4CA: LD VC, 0xFB
+    v[0xC] = 0xFB;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x4CC);
+    // 0x4CC: CALL 0x290
+    chip8.run(1);
+    12
+}
+
+fn block_4ce(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0xE0, 0x6C, 0x00, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x4CE, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x4CE: LD V2, VE
+    v[0x2] = v[0xE];
+    // 0x4D0: LD VC, 0x00
+    v[0xC] = 0x00;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x4D2);
+    // 0x4D2: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_4d4(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0x30, 0x6C, 0xFB, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x4D4, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x4D4: LD V2, V3
+    v[0x2] = v[0x3];
+    // 0x4D6: LD VC, 0xFB
+    v[0xC] = 0xFB;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x4D8);
+    // 0x4D8: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_4da(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0x40, 0x6C, 0x00, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x4DA, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x4DA: LD V2, V4
+    v[0x2] = v[0x4];
+    // 0x4DC: LD VC, 0x00
+    v[0xC] = 0x00;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x4DE);
+    // 0x4DE: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_4e0(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x7A, 0x01, 0x6D, 0x0E, 0x22, 0x80];
+    if budget < 3 || chip8.get_memory(0x4E0, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x4E0: ADD VA, 0x01
+    v[0xA] = v[0xA].wrapping_add(0x01);
+    // 0x4E2: LD VD, 0x0E
+    v[0xD] = 0x0E;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x4E4);
+    // 0x4E4: CALL 0x280
+    chip8.run(1);
+    3
+}
+
+fn block_4e6(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 18] = [
+        0x6F, 0xBC, 0x8F, 0xFE, 0x83, 0xF0, 0x6F, 0xAA, 0x62, 0xBC, 0x82, 0x2E, 0x8E, 0xF0, 0x6C,
+        0x78, 0x22, 0x90,
+    ];
+    if budget < 9 || chip8.get_memory(0x4E6, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x4E6: LD VF, 0xBC
+    v[0xF] = 0xBC;
+    // 0x4E8: SHL VF
+    {
+        let value = v[0xF];
+        v[0xF] = value << 1;
+        v[0xF] = value >> 7;
+    }
+    // 0x4EA: LD V3, VF
+    v[0x3] = v[0xF];
+    // 0x4EC: LD VF, 0xAA
+    v[0xF] = 0xAA;
+    // 0x4EE: LD V2, 0xBC
+    v[0x2] = 0xBC;
+    // 0x4F0: SHL V2
+    {
+        let value = v[0x2];
+        v[0x2] = value << 1;
+        v[0xF] = value >> 7;
+    }
+    // 0x4F2: LD VE, VF
+    v[0xE] = v[0xF];
+    // 0x4F4: LD VC, 0x78
+    v[0xC] = 0x78;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x4F6);
+    // 0x4F6: CALL 0x290
+    chip8.run(1);
+    9
+}
+
+fn block_4f8(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0xE0, 0x6C, 0x01, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x4F8, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x4F8: LD V2, VE
+    v[0x2] = v[0xE];
+    // 0x4FA: LD VC, 0x01
+    v[0xC] = 0x01;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x4FC);
+    // 0x4FC: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_4fe(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0x30, 0x6C, 0x01, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x4FE, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x4FE: LD V2, V3
+    v[0x2] = v[0x3];
+    // 0x500: LD VC, 0x01
+    v[0xC] = 0x01;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x502);
+    // 0x502: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_504(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 8] = [0x6D, 0x00, 0x6E, 0x1B, 0xA6, 0x03, 0x22, 0x10];
+    if budget < 4 || chip8.get_memory(0x504, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x504: LD VD, 0x00
+    v[0xD] = 0x00;
+    // 0x506: LD VE, 0x1B
+    v[0xE] = 0x1B;
+    // 0x508: LD I, 0x603
+    chip8.set_pointer(0x603);
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x50A);
+    // 0x50A: CALL 0x210
+    chip8.run(1);
+    4
+}
+
+fn block_50c(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 8] = [0x6A, 0x16, 0x6B, 0x1B, 0x6D, 0x0F, 0x22, 0x80];
+    if budget < 4 || chip8.get_memory(0x50C, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x50C: LD VA, 0x16
+    v[0xA] = 0x16;
+    // 0x50E: LD VB, 0x1B
+    v[0xB] = 0x1B;
+    // 0x510: LD VD, 0x0F
+    v[0xD] = 0x0F;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x512);
+    // 0x512: CALL 0x280
+    chip8.run(1);
+    4
+}
+
+fn block_514(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x7A, 0xFF, 0x6D, 0x0E, 0x22, 0x80];
+    if budget < 3 || chip8.get_memory(0x514, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x514: ADD VA, 0xFF
+    v[0xA] = v[0xA].wrapping_add(0xFF);
+    // 0x516: LD VD, 0x0E
+    v[0xD] = 0x0E;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x518);
+    // 0x518: CALL 0x280
+    chip8.run(1);
+    3
+}
+
+fn block_51a(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 10] = [0xA5, 0x44, 0x61, 0x10, 0xF1, 0x1E, 0x60, 0xAA, 0xF0, 0x55];
+    if budget < 5 || chip8.get_memory(0x51A, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x51A: LD I, 0x544
+    chip8.set_pointer(0x544);
+    // 0x51C: LD V1, 0x10
+    v[0x1] = 0x10;
+    // 0x51E: ADD I, V1
+    chip8.set_pointer(chip8.get_pointer() + v[0x1] as u16);
+    // 0x520: LD V0, 0xAA
+    v[0x0] = 0xAA;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x522);
+    // 0x522: LD [I], V0
+    chip8.run(1);
+    5
+}
+
+fn block_524(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0xA5, 0x54, 0xF0, 0x65];
+    if budget < 2 || chip8.get_memory(0x524, CODE.len()) != CODE {
+        return 0;
+    }
+    // 0x524: LD I, 0x554
+    chip8.set_pointer(0x554);
+    chip8.set_program_counter(0x526);
+    // 0x526: LD V0, [I]
+    chip8.run(1);
+    2
+}
+
+fn block_528(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0x00, 0x6C, 0xAA, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x528, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x528: LD V2, V0
+    v[0x2] = v[0x0];
+    // 0x52A: LD VC, 0xAA
+    v[0xC] = 0xAA;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x52C);
+    // 0x52C: CALL 0x290
+    chip8.run(1);
+    3
+}
+
+fn block_52e(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 10] = [0xA5, 0x44, 0x6F, 0x10, 0xFF, 0x1E, 0x60, 0x55, 0xF0, 0x55];
+    if budget < 5 || chip8.get_memory(0x52E, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x52E: LD I, 0x544
+    chip8.set_pointer(0x544);
+    // 0x530: LD VF, 0x10
+    v[0xF] = 0x10;
+    // 0x532: ADD I, VF
+    chip8.set_pointer(chip8.get_pointer() + v[0xF] as u16);
+    // 0x534: LD V0, 0x55
+    v[0x0] = 0x55;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x536);
+    // 0x536: LD [I], V0
+    chip8.run(1);
+    5
+}
+
+fn block_538(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0xA5, 0x54, 0xF0, 0x65];
+    if budget < 2 || chip8.get_memory(0x538, CODE.len()) != CODE {
+        return 0;
+    }
+    // 0x538: LD I, 0x554
+    chip8.set_pointer(0x554);
+    chip8.set_program_counter(0x53A);
+    // 0x53A: LD V0, [I]
+    chip8.run(1);
+    2
+}
+
+fn block_53c(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x82, 0x00, 0x6C, 0x55, 0x22, 0x90];
+    if budget < 3 || chip8.get_memory(0x53C, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x53C: LD V2, V0
+    v[0x2] = v[0x0];
+    // 0x53E: LD VC, 0x55
+    v[0xC] = 0x55;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x540);
+    // 0x540: CALL 0x290
+    chip8.run(1);
+    3
+}
@@ -0,0 +1,856 @@
+//! Recompiled from test_execute_key_press.ch8 by chip8-recompiler. Do not edit.
+
+use chip8::Chip8;
+
+pub const ROM: [u8; 270] = [
+    0x00, 0xE0, 0x60, 0x00, 0xE0, 0x9E, 0x12, 0x12, 0x60, 0x00, 0x61, 0x00, 0x62, 0x00, 0xF0, 0x29,
+    0xD1, 0x25, 0x60, 0x01, 0xE0, 0x9E, 0x12, 0x22, 0x60, 0x01, 0x61, 0x05, 0x62, 0x00, 0xF0, 0x29,
+    0xD1, 0x25, 0x60, 0x02, 0xE0, 0x9E, 0x12, 0x32, 0x60, 0x02, 0x61, 0x0A, 0x62, 0x00, 0xF0, 0x29,
+    0xD1, 0x25, 0x60, 0x03, 0xE0, 0x9E, 0x12, 0x42, 0x60, 0x03, 0x61, 0x0F, 0x62, 0x00, 0xF0, 0x29,
+    0xD1, 0x25, 0x60, 0x04, 0xE0, 0x9E, 0x12, 0x52, 0x60, 0x04, 0x61, 0x00, 0x62, 0x06, 0xF0, 0x29,
+    0xD1, 0x25, 0x60, 0x05, 0xE0, 0x9E, 0x12, 0x62, 0x60, 0x05, 0x61, 0x05, 0x62, 0x06, 0xF0, 0x29,
+    0xD1, 0x25, 0x60, 0x06, 0xE0, 0x9E, 0x12, 0x72, 0x60, 0x06, 0x61, 0x0A, 0x62, 0x06, 0xF0, 0x29,
+    0xD1, 0x25, 0x60, 0x07, 0xE0, 0x9E, 0x12, 0x82, 0x60, 0x07, 0x61, 0x0F, 0x62, 0x06, 0xF0, 0x29,
+    0xD1, 0x25, 0x60, 0x08, 0xE0, 0x9E, 0x12, 0x92, 0x60, 0x08, 0x61, 0x00, 0x62, 0x0C, 0xF0, 0x29,
+    0xD1, 0x25, 0x60, 0x09, 0xE0, 0x9E, 0x12, 0xA2, 0x60, 0x09, 0x61, 0x05, 0x62, 0x0C, 0xF0, 0x29,
+    0xD1, 0x25, 0x60, 0x0A, 0xE0, 0x9E, 0x12, 0xB2, 0x60, 0x0A, 0x61, 0x0A, 0x62, 0x0C, 0xF0, 0x29,
+    0xD1, 0x25, 0x60, 0x0B, 0xE0, 0x9E, 0x12, 0xC2, 0x60, 0x0B, 0x61, 0x0F, 0x62, 0x0C, 0xF0, 0x29,
+    0xD1, 0x25, 0x60, 0x0C, 0xE0, 0x9E, 0x12, 0xD2, 0x60, 0x0C, 0x61, 0x00, 0x62, 0x12, 0xF0, 0x29,
+    0xD1, 0x25, 0x60, 0x0D, 0xE0, 0x9E, 0x12, 0xE2, 0x60, 0x0D, 0x61, 0x05, 0x62, 0x12, 0xF0, 0x29,
+    0xD1, 0x25, 0x60, 0x0E, 0xE0, 0x9E, 0x12, 0xF2, 0x60, 0x0E, 0x61, 0x0A, 0x62, 0x12, 0xF0, 0x29,
+    0xD1, 0x25, 0x60, 0x0F, 0xE0, 0x9E, 0x13, 0x02, 0x60, 0x0F, 0x61, 0x0F, 0x62, 0x12, 0xF0, 0x29,
+    0xD1, 0x25, 0x61, 0x3C, 0xF1, 0x15, 0xF1, 0x07, 0x41, 0x00, 0x12, 0x00, 0x13, 0x06,
+];
+
+/// Run up to `num_instructions` instructions like `Chip8::run`, with compiled blocks in
+/// place of the interpreter wherever the program has not changed them.
+/// @return the number of instructions run.
+pub fn run(chip8: &mut Chip8, num_instructions: u32) -> u32 {
+    if chip8.is_logging_instructions() {
+        return chip8.run(num_instructions);
+    }
+    let mut count = 0;
+    while count < num_instructions && !chip8.is_exited() && !chip8.is_waiting_for_key() {
+        let budget = num_instructions - count;
+        let ran = match chip8.get_program_counter() {
+            0x202 => block_202(chip8, budget),
+            0x206 => block_206(chip8, budget),
+            0x208 => block_208(chip8, budget),
+            0x212 => block_212(chip8, budget),
+            0x216 => block_216(chip8, budget),
+            0x218 => block_218(chip8, budget),
+            0x222 => block_222(chip8, budget),
+            0x226 => block_226(chip8, budget),
+            0x228 => block_228(chip8, budget),
+            0x232 => block_232(chip8, budget),
+            0x236 => block_236(chip8, budget),
+            0x238 => block_238(chip8, budget),
+            0x242 => block_242(chip8, budget),
+            0x246 => block_246(chip8, budget),
+            0x248 => block_248(chip8, budget),
+            0x252 => block_252(chip8, budget),
+            0x256 => block_256(chip8, budget),
+            0x258 => block_258(chip8, budget),
+            0x262 => block_262(chip8, budget),
+            0x266 => block_266(chip8, budget),
+            0x268 => block_268(chip8, budget),
+            0x272 => block_272(chip8, budget),
+            0x276 => block_276(chip8, budget),
+            0x278 => block_278(chip8, budget),
+            0x282 => block_282(chip8, budget),
+            0x286 => block_286(chip8, budget),
+            0x288 => block_288(chip8, budget),
+            0x292 => block_292(chip8, budget),
+            0x296 => block_296(chip8, budget),
+            0x298 => block_298(chip8, budget),
+            0x2A2 => block_2a2(chip8, budget),
+            0x2A6 => block_2a6(chip8, budget),
+            0x2A8 => block_2a8(chip8, budget),
+            0x2B2 => block_2b2(chip8, budget),
+            0x2B6 => block_2b6(chip8, budget),
+            0x2B8 => block_2b8(chip8, budget),
+            0x2C2 => block_2c2(chip8, budget),
+            0x2C6 => block_2c6(chip8, budget),
+            0x2C8 => block_2c8(chip8, budget),
+            0x2D2 => block_2d2(chip8, budget),
+            0x2D6 => block_2d6(chip8, budget),
+            0x2D8 => block_2d8(chip8, budget),
+            0x2E2 => block_2e2(chip8, budget),
+            0x2E6 => block_2e6(chip8, budget),
+            0x2E8 => block_2e8(chip8, budget),
+            0x2F2 => block_2f2(chip8, budget),
+            0x2F6 => block_2f6(chip8, budget),
+            0x2F8 => block_2f8(chip8, budget),
+            0x302 => block_302(chip8, budget),
+            0x308 => block_308(chip8, budget),
+            0x30A => block_30a(chip8, budget),
+            0x30C => block_30c(chip8, budget),
+            _ => 0,
+        };
+        // Fall back to the interpreter where there is no block, its code has changed
+        // or it has more instructions than are left to run.
+        count += if ran > 0 { ran } else { chip8.run(1) };
+    }
+    count
+}
+
+/// Run one frame like `Chip8::run_frame`.
+/// @return true if the program has exited.
+pub fn run_frame(chip8: &mut Chip8, num_instructions: u32) -> bool {
+    run(chip8, num_instructions);
+    if chip8.is_exited() {
+        return true;
+    }
+    chip8.tick_timers();
+    false
+}
+
+fn block_202(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x60, 0x00, 0xE0, 0x9E];
+    if budget < 2 || chip8.get_memory(0x202, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x202: LD V0, 0x00
+    v[0x0] = 0x00;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x204);
+    // 0x204: SKP V0
+    chip8.run(1);
+    2
+}
+
+fn block_206(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x12, 0x12];
+    if budget < 1 || chip8.get_memory(0x206, CODE.len()) != CODE {
+        return 0;
+    }
+    // 0x206: JP 0x212
+    chip8.set_program_counter(0x212);
+    1
+}
+
+fn block_208(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 8] = [0x60, 0x00, 0x61, 0x00, 0x62, 0x00, 0xF0, 0x29];
+    if budget < 4 || chip8.get_memory(0x208, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x208: LD V0, 0x00
+    v[0x0] = 0x00;
+    // 0x20A: LD V1, 0x00
+    v[0x1] = 0x00;
+    // 0x20C: LD V2, 0x00
+    v[0x2] = 0x00;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x20E);
+    // 0x20E: LD F, V0
+    chip8.run(1);
+    4
+}
+
+fn block_212(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x60, 0x01, 0xE0, 0x9E];
+    if budget < 2 || chip8.get_memory(0x212, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x212: LD V0, 0x01
+    v[0x0] = 0x01;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x214);
+    // 0x214: SKP V0
+    chip8.run(1);
+    2
+}
+
+fn block_216(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x12, 0x22];
+    if budget < 1 || chip8.get_memory(0x216, CODE.len()) != CODE {
+        return 0;
+    }
+    // 0x216: JP 0x222
+    chip8.set_program_counter(0x222);
+    1
+}
+
+fn block_218(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 8] = [0x60, 0x01, 0x61, 0x05, 0x62, 0x00, 0xF0, 0x29];
+    if budget < 4 || chip8.get_memory(0x218, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x218: LD V0, 0x01
+    v[0x0] = 0x01;
+    // 0x21A: LD V1, 0x05
+    v[0x1] = 0x05;
+    // 0x21C: LD V2, 0x00
+    v[0x2] = 0x00;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x21E);
+    // 0x21E: LD F, V0
+    chip8.run(1);
+    4
+}
+
+fn block_222(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x60, 0x02, 0xE0, 0x9E];
+    if budget < 2 || chip8.get_memory(0x222, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x222: LD V0, 0x02
+    v[0x0] = 0x02;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x224);
+    // 0x224: SKP V0
+    chip8.run(1);
+    2
+}
+
+fn block_226(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x12, 0x32];
+    if budget < 1 || chip8.get_memory(0x226, CODE.len()) != CODE {
+        return 0;
+    }
+    // 0x226: JP 0x232
+    chip8.set_program_counter(0x232);
+    1
+}
+
+fn block_228(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 8] = [0x60, 0x02, 0x61, 0x0A, 0x62, 0x00, 0xF0, 0x29];
+    if budget < 4 || chip8.get_memory(0x228, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x228: LD V0, 0x02
+    v[0x0] = 0x02;
+    // 0x22A: LD V1, 0x0A
+    v[0x1] = 0x0A;
+    // 0x22C: LD V2, 0x00
+    v[0x2] = 0x00;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x22E);
+    // 0x22E: LD F, V0
+    chip8.run(1);
+    4
+}
+
+fn block_232(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x60, 0x03, 0xE0, 0x9E];
+    if budget < 2 || chip8.get_memory(0x232, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x232: LD V0, 0x03
+    v[0x0] = 0x03;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x234);
+    // 0x234: SKP V0
+    chip8.run(1);
+    2
+}
+
+fn block_236(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x12, 0x42];
+    if budget < 1 || chip8.get_memory(0x236, CODE.len()) != CODE {
+        return 0;
+    }
+    // 0x236: JP 0x242
+    chip8.set_program_counter(0x242);
+    1
+}
+
+fn block_238(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 8] = [0x60, 0x03, 0x61, 0x0F, 0x62, 0x00, 0xF0, 0x29];
+    if budget < 4 || chip8.get_memory(0x238, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x238: LD V0, 0x03
+    v[0x0] = 0x03;
+    // 0x23A: LD V1, 0x0F
+    v[0x1] = 0x0F;
+    // 0x23C: LD V2, 0x00
+    v[0x2] = 0x00;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x23E);
+    // 0x23E: LD F, V0
+    chip8.run(1);
+    4
+}
+
+fn block_242(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x60, 0x04, 0xE0, 0x9E];
+    if budget < 2 || chip8.get_memory(0x242, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x242: LD V0, 0x04
+    v[0x0] = 0x04;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x244);
+    // 0x244: SKP V0
+    chip8.run(1);
+    2
+}
+
+fn block_246(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x12, 0x52];
+    if budget < 1 || chip8.get_memory(0x246, CODE.len()) != CODE {
+        return 0;
+    }
+    // 0x246: JP 0x252
+    chip8.set_program_counter(0x252);
+    1
+}
+
+fn block_248(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 8] = [0x60, 0x04, 0x61, 0x00, 0x62, 0x06, 0xF0, 0x29];
+    if budget < 4 || chip8.get_memory(0x248, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x248: LD V0, 0x04
+    v[0x0] = 0x04;
+    // 0x24A: LD V1, 0x00
+    v[0x1] = 0x00;
+    // 0x24C: LD V2, 0x06
+    v[0x2] = 0x06;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x24E);
+    // 0x24E: LD F, V0
+    chip8.run(1);
+    4
+}
+
+fn block_252(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x60, 0x05, 0xE0, 0x9E];
+    if budget < 2 || chip8.get_memory(0x252, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x252: LD V0, 0x05
+    v[0x0] = 0x05;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x254);
+    // 0x254: SKP V0
+    chip8.run(1);
+    2
+}
+
+fn block_256(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x12, 0x62];
+    if budget < 1 || chip8.get_memory(0x256, CODE.len()) != CODE {
+        return 0;
+    }
+    // 0x256: JP 0x262
+    chip8.set_program_counter(0x262);
+    1
+}
+
+fn block_258(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 8] = [0x60, 0x05, 0x61, 0x05, 0x62, 0x06, 0xF0, 0x29];
+    if budget < 4 || chip8.get_memory(0x258, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x258: LD V0, 0x05
+    v[0x0] = 0x05;
+    // 0x25A: LD V1, 0x05
+    v[0x1] = 0x05;
+    // 0x25C: LD V2, 0x06
+    v[0x2] = 0x06;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x25E);
+    // 0x25E: LD F, V0
+    chip8.run(1);
+    4
+}
+
+fn block_262(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x60, 0x06, 0xE0, 0x9E];
+    if budget < 2 || chip8.get_memory(0x262, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x262: LD V0, 0x06
+    v[0x0] = 0x06;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x264);
+    // 0x264: SKP V0
+    chip8.run(1);
+    2
+}
+
+fn block_266(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x12, 0x72];
+    if budget < 1 || chip8.get_memory(0x266, CODE.len()) != CODE {
+        return 0;
+    }
+    // 0x266: JP 0x272
+    chip8.set_program_counter(0x272);
+    1
+}
+
+fn block_268(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 8] = [0x60, 0x06, 0x61, 0x0A, 0x62, 0x06, 0xF0, 0x29];
+    if budget < 4 || chip8.get_memory(0x268, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x268: LD V0, 0x06
+    v[0x0] = 0x06;
+    // 0x26A: LD V1, 0x0A
+    v[0x1] = 0x0A;
+    // 0x26C: LD V2, 0x06
+    v[0x2] = 0x06;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x26E);
+    // 0x26E: LD F, V0
+    chip8.run(1);
+    4
+}
+
+fn block_272(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x60, 0x07, 0xE0, 0x9E];
+    if budget < 2 || chip8.get_memory(0x272, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x272: LD V0, 0x07
+    v[0x0] = 0x07;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x274);
+    // 0x274: SKP V0
+    chip8.run(1);
+    2
+}
+
+fn block_276(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x12, 0x82];
+    if budget < 1 || chip8.get_memory(0x276, CODE.len()) != CODE {
+        return 0;
+    }
+    // 0x276: JP 0x282
+    chip8.set_program_counter(0x282);
+    1
+}
+
+fn block_278(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 8] = [0x60, 0x07, 0x61, 0x0F, 0x62, 0x06, 0xF0, 0x29];
+    if budget < 4 || chip8.get_memory(0x278, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x278: LD V0, 0x07
+    v[0x0] = 0x07;
+    // 0x27A: LD V1, 0x0F
+    v[0x1] = 0x0F;
+    // 0x27C: LD V2, 0x06
+    v[0x2] = 0x06;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x27E);
+    // 0x27E: LD F, V0
+    chip8.run(1);
+    4
+}
+
+fn block_282(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x60, 0x08, 0xE0, 0x9E];
+    if budget < 2 || chip8.get_memory(0x282, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x282: LD V0, 0x08
+    v[0x0] = 0x08;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x284);
+    // 0x284: SKP V0
+    chip8.run(1);
+    2
+}
+
+fn block_286(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x12, 0x92];
+    if budget < 1 || chip8.get_memory(0x286, CODE.len()) != CODE {
+        return 0;
+    }
+    // 0x286: JP 0x292
+    chip8.set_program_counter(0x292);
+    1
+}
+
+fn block_288(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 8] = [0x60, 0x08, 0x61, 0x00, 0x62, 0x0C, 0xF0, 0x29];
+    if budget < 4 || chip8.get_memory(0x288, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x288: LD V0, 0x08
+    v[0x0] = 0x08;
+    // 0x28A: LD V1, 0x00
+    v[0x1] = 0x00;
+    // 0x28C: LD V2, 0x0C
+    v[0x2] = 0x0C;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x28E);
+    // 0x28E: LD F, V0
+    chip8.run(1);
+    4
+}
+
+fn block_292(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x60, 0x09, 0xE0, 0x9E];
+    if budget < 2 || chip8.get_memory(0x292, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x292: LD V0, 0x09
+    v[0x0] = 0x09;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x294);
+    // 0x294: SKP V0
+    chip8.run(1);
+    2
+}
+
+fn block_296(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x12, 0xA2];
+    if budget < 1 || chip8.get_memory(0x296, CODE.len()) != CODE {
+        return 0;
+    }
+    // 0x296: JP 0x2A2
+    chip8.set_program_counter(0x2A2);
+    1
+}
+
+fn block_298(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 8] = [0x60, 0x09, 0x61, 0x05, 0x62, 0x0C, 0xF0, 0x29];
+    if budget < 4 || chip8.get_memory(0x298, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x298: LD V0, 0x09
+    v[0x0] = 0x09;
+    // 0x29A: LD V1, 0x05
+    v[0x1] = 0x05;
+    // 0x29C: LD V2, 0x0C
+    v[0x2] = 0x0C;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x29E);
+    // 0x29E: LD F, V0
+    chip8.run(1);
+    4
+}
+
+fn block_2a2(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x60, 0x0A, 0xE0, 0x9E];
+    if budget < 2 || chip8.get_memory(0x2A2, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x2A2: LD V0, 0x0A
+    v[0x0] = 0x0A;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x2A4);
+    // 0x2A4: SKP V0
+    chip8.run(1);
+    2
+}
+
+fn block_2a6(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x12, 0xB2];
+    if budget < 1 || chip8.get_memory(0x2A6, CODE.len()) != CODE {
+        return 0;
+    }
+    // 0x2A6: JP 0x2B2
+    chip8.set_program_counter(0x2B2);
+    1
+}
+
+fn block_2a8(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 8] = [0x60, 0x0A, 0x61, 0x0A, 0x62, 0x0C, 0xF0, 0x29];
+    if budget < 4 || chip8.get_memory(0x2A8, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x2A8: LD V0, 0x0A
+    v[0x0] = 0x0A;
+    // 0x2AA: LD V1, 0x0A
+    v[0x1] = 0x0A;
+    // 0x2AC: LD V2, 0x0C
+    v[0x2] = 0x0C;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x2AE);
+    // 0x2AE: LD F, V0
+    chip8.run(1);
+    4
+}
+
+fn block_2b2(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x60, 0x0B, 0xE0, 0x9E];
+    if budget < 2 || chip8.get_memory(0x2B2, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x2B2: LD V0, 0x0B
+    v[0x0] = 0x0B;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x2B4);
+    // 0x2B4: SKP V0
+    chip8.run(1);
+    2
+}
+
+fn block_2b6(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x12, 0xC2];
+    if budget < 1 || chip8.get_memory(0x2B6, CODE.len()) != CODE {
+        return 0;
+    }
+    // 0x2B6: JP 0x2C2
+    chip8.set_program_counter(0x2C2);
+    1
+}
+
+fn block_2b8(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 8] = [0x60, 0x0B, 0x61, 0x0F, 0x62, 0x0C, 0xF0, 0x29];
+    if budget < 4 || chip8.get_memory(0x2B8, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x2B8: LD V0, 0x0B
+    v[0x0] = 0x0B;
+    // 0x2BA: LD V1, 0x0F
+    v[0x1] = 0x0F;
+    // 0x2BC: LD V2, 0x0C
+    v[0x2] = 0x0C;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x2BE);
+    // 0x2BE: LD F, V0
+    chip8.run(1);
+    4
+}
+
+fn block_2c2(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x60, 0x0C, 0xE0, 0x9E];
+    if budget < 2 || chip8.get_memory(0x2C2, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x2C2: LD V0, 0x0C
+    v[0x0] = 0x0C;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x2C4);
+    // 0x2C4: SKP V0
+    chip8.run(1);
+    2
+}
+
+fn block_2c6(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x12, 0xD2];
+    if budget < 1 || chip8.get_memory(0x2C6, CODE.len()) != CODE {
+        return 0;
+    }
+    // 0x2C6: JP 0x2D2
+    chip8.set_program_counter(0x2D2);
+    1
+}
+
+fn block_2c8(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 8] = [0x60, 0x0C, 0x61, 0x00, 0x62, 0x12, 0xF0, 0x29];
+    if budget < 4 || chip8.get_memory(0x2C8, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x2C8: LD V0, 0x0C
+    v[0x0] = 0x0C;
+    // 0x2CA: LD V1, 0x00
+    v[0x1] = 0x00;
+    // 0x2CC: LD V2, 0x12
+    v[0x2] = 0x12;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x2CE);
+    // 0x2CE: LD F, V0
+    chip8.run(1);
+    4
+}
+
+fn block_2d2(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x60, 0x0D, 0xE0, 0x9E];
+    if budget < 2 || chip8.get_memory(0x2D2, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x2D2: LD V0, 0x0D
+    v[0x0] = 0x0D;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x2D4);
+    // 0x2D4: SKP V0
+    chip8.run(1);
+    2
+}
+
+fn block_2d6(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x12, 0xE2];
+    if budget < 1 || chip8.get_memory(0x2D6, CODE.len()) != CODE {
+        return 0;
+    }
+    // 0x2D6: JP 0x2E2
+    chip8.set_program_counter(0x2E2);
+    1
+}
+
+fn block_2d8(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 8] = [0x60, 0x0D, 0x61, 0x05, 0x62, 0x12, 0xF0, 0x29];
+    if budget < 4 || chip8.get_memory(0x2D8, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x2D8: LD V0, 0x0D
+    v[0x0] = 0x0D;
+    // 0x2DA: LD V1, 0x05
+    v[0x1] = 0x05;
+    // 0x2DC: LD V2, 0x12
+    v[0x2] = 0x12;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x2DE);
+    // 0x2DE: LD F, V0
+    chip8.run(1);
+    4
+}
+
+fn block_2e2(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x60, 0x0E, 0xE0, 0x9E];
+    if budget < 2 || chip8.get_memory(0x2E2, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x2E2: LD V0, 0x0E
+    v[0x0] = 0x0E;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x2E4);
+    // 0x2E4: SKP V0
+    chip8.run(1);
+    2
+}
+
+fn block_2e6(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x12, 0xF2];
+    if budget < 1 || chip8.get_memory(0x2E6, CODE.len()) != CODE {
+        return 0;
+    }
+    // 0x2E6: JP 0x2F2
+    chip8.set_program_counter(0x2F2);
+    1
+}
+
+fn block_2e8(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 8] = [0x60, 0x0E, 0x61, 0x0A, 0x62, 0x12, 0xF0, 0x29];
+    if budget < 4 || chip8.get_memory(0x2E8, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x2E8: LD V0, 0x0E
+    v[0x0] = 0x0E;
+    // 0x2EA: LD V1, 0x0A
+    v[0x1] = 0x0A;
+    // 0x2EC: LD V2, 0x12
+    v[0x2] = 0x12;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x2EE);
+    // 0x2EE: LD F, V0
+    chip8.run(1);
+    4
+}
+
+fn block_2f2(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x60, 0x0F, 0xE0, 0x9E];
+    if budget < 2 || chip8.get_memory(0x2F2, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x2F2: LD V0, 0x0F
+    v[0x0] = 0x0F;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x2F4);
+    // 0x2F4: SKP V0
+    chip8.run(1);
+    2
+}
+
+fn block_2f6(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x13, 0x02];
+    if budget < 1 || chip8.get_memory(0x2F6, CODE.len()) != CODE {
+        return 0;
+    }
+    // 0x2F6: JP 0x302
+    chip8.set_program_counter(0x302);
+    1
+}
+
+fn block_2f8(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 8] = [0x60, 0x0F, 0x61, 0x0F, 0x62, 0x12, 0xF0, 0x29];
+    if budget < 4 || chip8.get_memory(0x2F8, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x2F8: LD V0, 0x0F
+    v[0x0] = 0x0F;
+    // 0x2FA: LD V1, 0x0F
+    v[0x1] = 0x0F;
+    // 0x2FC: LD V2, 0x12
+    v[0x2] = 0x12;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x2FE);
+    // 0x2FE: LD F, V0
+    chip8.run(1);
+    4
+}
+
+fn block_302(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 4] = [0x61, 0x3C, 0xF1, 0x15];
+    if budget < 2 || chip8.get_memory(0x302, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x302: LD V1, 0x3C
+    v[0x1] = 0x3C;
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x304);
+    // 0x304: LD DT, V1
+    chip8.run(1);
+    2
+}
+
+fn block_308(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x41, 0x00];
+    if budget < 1 || chip8.get_memory(0x308, CODE.len()) != CODE {
+        return 0;
+    }
+    let v = *chip8.get_registers();
+    // 0x308: SNE V1, 0x00
+    chip8.set_program_counter(if v[0x1] != 0x00 { 0x30C } else { 0x30A });
+    1
+}
+
+fn block_30a(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x12, 0x00];
+    if budget < 1 || chip8.get_memory(0x30A, CODE.len()) != CODE {
+        return 0;
+    }
+    // 0x30A: JP 0x200
+    chip8.set_program_counter(0x200);
+    1
+}
+
+fn block_30c(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 2] = [0x13, 0x06];
+    if budget < 1 || chip8.get_memory(0x30C, CODE.len()) != CODE {
+        return 0;
+    }
+    // 0x30C: JP 0x306
+    chip8.set_program_counter(0x306);
+    1
+}
@@ -0,0 +1,104 @@
+use crate::recompile::recompile;
+
+use chip8::golden::{BLESS_ENV_VAR, RomTest};
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+#[path = "golden/execute_flags.rs"]
+mod execute_flags;
+
+#[path = "golden/execute_key_press.rs"]
+mod execute_key_press;
+
+/// Panic if recompiling the ROM does not give the checked in module, or overwrite the
+/// module if `CHIP8_BLESS` is set.
+fn assert_recompiled(rom_path: &str, module_path: &str, rom: &[u8]) {
+    let program = fs::read(rom_path).unwrap();
+    assert_eq!(program, rom);
+    let rom_name = Path::new(rom_path).file_name().unwrap().to_string_lossy();
+    let module = recompile(&program, &rom_name);
+    if env::var_os(BLESS_ENV_VAR).is_some() {
+        fs::write(module_path, module).unwrap();
+        return;
+    }
+    assert!(
+        fs::read_to_string(module_path).unwrap() == module,
+        "{} is out of date. Set {}=1 to update it.",
+        module_path,
+        BLESS_ENV_VAR
+    );
+}
+
+#[test]
+fn test_recompiled_modules_are_up_to_date() {
+    assert_recompiled(
+        "../chip8/src/test_execute_flags.ch8",
+        "./src/golden/execute_flags.rs",
+        &execute_flags::ROM,
+    );
+    assert_recompiled(
+        "../chip8/src/test_execute_key_press.ch8",
+        "./src/golden/execute_key_press.rs",
+        &execute_key_press::ROM,
+    );
+}
+
+#[test]
+fn test_recompiled_state_matches_interpreter() {
+    let test = RomTest {
+        frames: 1000,
+        ..RomTest::new(
+            "../chip8/src/test_execute_flags.ch8",
+            "../chip8/src/golden/flags.txt",
+        )
+    };
+    let interpreted = test.run();
+    let recompiled = test.run_with(execute_flags::run_frame);
+    assert_eq!(interpreted.get_registers(), recompiled.get_registers());
+    assert_eq!(interpreted.get_pointer(), recompiled.get_pointer());
+    assert_eq!(
+        interpreted.get_program_counter(),
+        recompiled.get_program_counter()
+    );
+    assert_eq!(interpreted.get_stack(), recompiled.get_stack());
+}
+
+#[test]
+fn test_execute_flags() {
+    RomTest {
+        frames: 1000,
+        ..RomTest::new(
+            "../chip8/src/test_execute_flags.ch8",
+            "../chip8/src/golden/flags.txt",
+        )
+    }
+    .assert_golden_with(execute_flags::run_frame);
+}
+
+#[test]
+fn test_execute_key_press_0() {
+    RomTest {
+        input_script: "0 0",
+        frames: 10,
+        ..RomTest::new(
+            "../chip8/src/test_execute_key_press.ch8",
+            "../chip8/src/golden/key_press_0.txt",
+        )
+    }
+    .assert_golden_with(execute_key_press::run_frame);
+}
+
+#[test]
+fn test_execute_key_press_all() {
+    RomTest {
+        input_script: "0 0 1 2 3 4 5 6 7 8 9 A B C D E F",
+        frames: 20,
+        ..RomTest::new(
+            "../chip8/src/test_execute_key_press.ch8",
+            "../chip8/src/golden/key_press_all.txt",
+        )
+    }
+    .assert_golden_with(execute_key_press::run_frame);
+}
@@ -0,0 +1,85 @@
+mod recompile;
+
+#[cfg(test)]
+mod golden_test;
+
+#[cfg(test)]
+mod recompile_test;
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage: chip8-recompiler [--out=FILE] ROM
+
+Turns a ROM into a Rust module that runs it with the instructions of each block compiled to
+Rust, using chip8::Chip8 for the rest. Blocks are found by following the program's control
+flow, and the interpreter runs any code it could not find or that the program overwrites.
+  --out=FILE  Where to write the module, or - for stdout. Defaults to stdout.
+
+The module has the ROM as `ROM` and `run` and `run_frame` functions to call in place of
+`Chip8::run` and `Chip8::run_frame` after initializing a Chip8 with `ROM`.";
+
+struct Options {
+    rom_filepath: String,
+    out_filepath: String,
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args() {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(&options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// @return None if help was requested.
+fn parse_args() -> Result<Option<Options>, String> {
+    let mut filepaths = Vec::new();
+    let mut out_filepath = String::from("-");
+    for arg in env::args().skip(1) {
+        match arg.split_once('=') {
+            _ if arg == "--help" => return Ok(None),
+            Some(("--out", value)) => out_filepath = value.to_string(),
+            _ if arg.starts_with("--") => return Err(format!("Unknown flag: {}", arg)),
+            _ => filepaths.push(arg),
+        }
+    }
+
+    let [rom_filepath]: [String; 1] = filepaths.try_into().map_err(|_| "Expected one ROM.")?;
+    Ok(Some(Options {
+        rom_filepath,
+        out_filepath,
+    }))
+}
+
+fn run(options: &Options) -> Result<(), String> {
+    let program = fs::read(&options.rom_filepath)
+        .map_err(|e| format!("Error reading {}: {}", options.rom_filepath, e))?;
+    let rom_name = Path::new(&options.rom_filepath)
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+    let module = recompile::recompile(&program, &rom_name);
+    if options.out_filepath == "-" {
+        print!("{}", module);
+        return Ok(());
+    }
+    fs::write(&options.out_filepath, module)
+        .map_err(|e| format!("Error writing {}: {}", options.out_filepath, e))
+}
@@ -0,0 +1,363 @@
+use chip8_assembler::{ControlFlowGraph, instruction_to_assembly};
+use chip8_instructions::Instruction;
+
+use std::collections::BTreeSet;
+
+pub const PROGRAM_MEM_ADDR: u16 = 0x200;
+
+/// Instructions compiled into one function: a run of instructions turned into Rust, then
+/// at most one instruction left to the interpreter, which ends it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Block {
+    pub start: u16,
+    pub compiled: Vec<(u16, Instruction)>,
+    pub interpreted: Option<(u16, Instruction)>,
+}
+
+impl Block {
+    fn new(start: u16) -> Self {
+        Self {
+            start,
+            compiled: Vec::new(),
+            interpreted: None,
+        }
+    }
+
+    /// @return the number of instructions.
+    pub fn num_instructions(&self) -> usize {
+        self.compiled.len() + self.interpreted.iter().count()
+    }
+}
+
+/// The widest line in a generated module and the widest array kept on one line, matching
+/// rustfmt.
+const MAX_WIDTH: usize = 100;
+const MAX_ARRAY_WIDTH: usize = 60;
+
+/// @return the declaration of a byte array constant, with the bytes on one line if they
+/// fit or wrapped in rows the way rustfmt lays them out.
+fn byte_array(declaration: &str, bytes: &[u8]) -> String {
+    let declaration = format!("{}: [u8; {}] = [", declaration, bytes.len());
+    let bytes: Vec<String> = bytes.iter().map(|byte| format!("0x{:02X}", byte)).collect();
+    let line = format!("{}{}];\n", declaration, bytes.join(", "));
+    if bytes.join(", ").len() <= MAX_ARRAY_WIDTH && line.trim_end().len() <= MAX_WIDTH {
+        return line;
+    }
+    let indent = declaration.len() - declaration.trim_start().len() + 4;
+    let per_row = (MAX_WIDTH - indent + 1) / "0x00, ".len();
+    let mut text = format!("{}\n", declaration);
+    for row in bytes.chunks(per_row) {
+        text += &format!("{}{},\n", " ".repeat(indent), row.join(", "));
+    }
+    text += &format!("{}];\n", " ".repeat(indent - 4));
+    text
+}
+
+/// @return the blocks of a program, split from the basic blocks of its control-flow graph
+/// after each instruction left to the interpreter. Unreachable code is left out.
+pub fn find_blocks(program: &[u8]) -> Vec<Block> {
+    let graph = ControlFlowGraph::new(program, PROGRAM_MEM_ADDR);
+    let mut blocks = Vec::new();
+    for basic_block in graph.get_blocks() {
+        let mut block = Block::new(basic_block.start);
+        for addr in (basic_block.start..basic_block.end).step_by(2) {
+            let instruction = graph.get_instruction(addr).unwrap();
+            if is_compiled(addr, instruction) {
+                block.compiled.push((addr, instruction));
+                continue;
+            }
+            block.interpreted = Some((addr, instruction));
+            // A block that would only run the interpreter is left to the dispatch loop.
+            if !block.compiled.is_empty() {
+                blocks.push(block);
+            }
+            block = Block::new(addr + 2);
+        }
+        if !block.compiled.is_empty() {
+            blocks.push(block);
+        }
+    }
+    blocks
+}
+
+/// @return true if the instruction is turned into Rust. Instructions that touch the
+/// display, keys, timers, stack or memory, jump indirectly or stop the program are run by
+/// the interpreter.
+fn is_compiled(addr: u16, instruction: Instruction) -> bool {
+    match instruction {
+        // A jump to itself is how programs stop, which the interpreter detects.
+        Instruction::Jump { addr: target } => target != addr,
+        Instruction::SkipRegEqualsImm { .. }
+        | Instruction::SkipRegNotEqualsImm { .. }
+        | Instruction::SkipRegEqualsReg { .. }
+        | Instruction::SkipRegNotEqualsReg { .. }
+        | Instruction::LoadImmToReg { .. }
+        | Instruction::AddImmToReg { .. }
+        | Instruction::LoadRegToReg { .. }
+        | Instruction::OrReg { .. }
+        | Instruction::AndReg { .. }
+        | Instruction::XorReg { .. }
+        | Instruction::AddReg { .. }
+        | Instruction::SubReg { .. }
+        | Instruction::ShiftRight { .. }
+        | Instruction::SubNegReg { .. }
+        | Instruction::ShiftLeft { .. }
+        | Instruction::LoadImmToPointer { .. }
+        | Instruction::AddRegToPointer { .. } => true,
+        _ => false,
+    }
+}
+
+/// What the Rust for an instruction uses from the processor.
+#[derive(Debug, Default, Clone, Copy)]
+struct Uses {
+    reads_registers: bool,
+    writes_registers: bool,
+    quirks: bool,
+}
+
+/// @return the Rust statements for a compiled instruction, working on the local copy `v`
+/// of the registers. Jumps and skips have none, as they only
+/// decide where the block goes next.
+fn compile_instruction(instruction: Instruction, uses: &mut Uses) -> Vec<String> {
+    let reg = |x: u8| format!("v[0x{:X}]", x);
+    let vf = reg(0xF);
+    let mut writes_registers = true;
+    let lines = match instruction {
+        Instruction::LoadImmToReg { x, byte } => vec![format!("{} = 0x{:02X};", reg(x), byte)],
+        Instruction::AddImmToReg { x, byte } => vec![format!(
+            "{} = {}.wrapping_add(0x{:02X});",
+            reg(x),
+            reg(x),
+            byte
+        )],
+        Instruction::LoadRegToReg { x, y } => vec![format!("{} = {};", reg(x), reg(y))],
+        Instruction::OrReg { x, y }
+        | Instruction::AndReg { x, y }
+        | Instruction::XorReg { x, y } => {
+            let op = match instruction {
+                Instruction::OrReg { .. } => "|=",
+                Instruction::AndReg { .. } => "&=",
+                _ => "^=",
+            };
+            uses.quirks = true;
+            vec![
+                format!("{} {} {};", reg(x), op, reg(y)),
+                "if quirks.vf_reset {".to_string(),
+                format!("    {} = 0;", vf),
+                "}".to_string(),
+            ]
+        }
+        Instruction::AddReg { x, y } => vec![
+            format!("let (sum, carry) = {}.overflowing_add({});", reg(x), reg(y)),
+            format!("{} = sum;", reg(x)),
+            format!("{} = carry as u8;", vf),
+        ],
+        Instruction::SubReg { x, y } | Instruction::SubNegReg { x, y } => {
+            let (from, amount) = match instruction {
+                Instruction::SubReg { .. } => ("x", "y"),
+                _ => ("y", "x"),
+            };
+            vec![
+                format!("let (x, y) = ({}, {});", reg(x), reg(y)),
+                format!("{} = {}.wrapping_sub({});", reg(x), from, amount),
+                format!("{} = ({} >= {}) as u8;", vf, from, amount),
+            ]
+        }
+        Instruction::ShiftRight { x, y } | Instruction::ShiftLeft { x, y } => {
+            let value = if x == y {
+                format!("let value = {};", reg(x))
+            } else {
+                uses.quirks = true;
+                format!(
+                    "let value = if quirks.shift_uses_vy {{ {} }} else {{ {} }};",
+                    reg(y),
+                    reg(x)
+                )
+            };
+            let (shifted, flag) = match instruction {
+                Instruction::ShiftRight { .. } => ("value >> 1", "value & 0x1"),
+                _ => ("value << 1", "value >> 7"),
+            };
+            vec![
+                value,
+                format!("{} = {};", reg(x), shifted),
+                format!("{} = {};", vf, flag),
+            ]
+        }
+        Instruction::LoadImmToPointer { addr } => {
+            writes_registers = false;
+            vec![format!("chip8.set_pointer(0x{:03X});", addr)]
+        }
+        Instruction::AddRegToPointer { x } => {
+            writes_registers = false;
+            uses.reads_registers = true;
+            vec![format!(
+                "chip8.set_pointer(chip8.get_pointer() + {} as u16);",
+                reg(x)
+            )]
+        }
+        _ => {
+            writes_registers = false;
+            Vec::new()
+        }
+    };
+    uses.writes_registers |= writes_registers;
+    lines
+}
+
+/// @return an expression for the address a block's last compiled instruction goes to.
+fn next_addr(addr: u16, instruction: Instruction, uses: &mut Uses) -> String {
+    let reg = |x: u8| format!("v[0x{:X}]", x);
+    let skip = |condition: String| {
+        format!(
+            "if {} {{ 0x{:03X} }} else {{ 0x{:03X} }}",
+            condition,
+            addr + 4,
+            addr + 2
+        )
+    };
+    let next = match instruction {
+        Instruction::Jump { addr } => return format!("0x{:03X}", addr),
+        Instruction::SkipRegEqualsImm { x, byte } => skip(format!("{} == 0x{:02X}", reg(x), byte)),
+        Instruction::SkipRegNotEqualsImm { x, byte } => {
+            skip(format!("{} != 0x{:02X}", reg(x), byte))
+        }
+        Instruction::SkipRegEqualsReg { x, y } => skip(format!("{} == {}", reg(x), reg(y))),
+        Instruction::SkipRegNotEqualsReg { x, y } => skip(format!("{} != {}", reg(x), reg(y))),
+        _ => return format!("0x{:03X}", addr + 2),
+    };
+    uses.reads_registers = true;
+    next
+}
+
+fn compile_block(program: &[u8], block: &Block, labels: &BTreeSet<u16>) -> String {
+    let mut uses = Uses::default();
+    let mut body = String::new();
+    for (addr, instruction) in block.compiled.iter() {
+        body += &format!(
+            "    // 0x{:03X}: {}\n",
+            addr,
+            instruction_to_assembly(*instruction, labels)
+        );
+        let lines = compile_instruction(*instruction, &mut uses);
+        // Statements with locals of their own get a scope.
+        let scoped = lines.iter().any(|line| line.starts_with("let "));
+        if scoped {
+            body += "    {\n";
+        }
+        for line in lines.iter() {
+            body += &format!("    {}{}\n", if scoped { "    " } else { "" }, line);
+        }
+        if scoped {
+            body += "    }\n";
+        }
+    }
+    let (last_addr, last) = *block.compiled.last().unwrap();
+    let next = match block.interpreted {
+        Some((addr, _)) => format!("0x{:03X}", addr),
+        None => next_addr(last_addr, last, &mut uses),
+    };
+
+    let offset = (block.start - PROGRAM_MEM_ADDR) as usize;
+    let code = &program[offset..offset + 2 * block.num_instructions()];
+
+    let mut text = format!(
+        "fn block_{:03x}(chip8: &mut Chip8, budget: u32) -> u32 {{\n",
+        block.start
+    );
+    text += &byte_array("    const CODE", code);
+    text += &format!(
+        "    if budget < {} || chip8.get_memory(0x{:03X}, CODE.len()) != CODE {{\n        return 0;\n    }}\n",
+        block.num_instructions(),
+        block.start
+    );
+    if uses.quirks {
+        text += "    let quirks = chip8.get_quirks();\n";
+    }
+    if uses.writes_registers {
+        text += "    let mut v = *chip8.get_registers();\n";
+    } else if uses.reads_registers {
+        text += "    let v = *chip8.get_registers();\n";
+    }
+    text += &body;
+    if uses.writes_registers {
+        text += "    chip8.set_registers(&v);\n";
+    }
+    text += &format!("    chip8.set_program_counter({});\n", next);
+    if let Some((addr, instruction)) = block.interpreted {
+        text += &format!(
+            "    // 0x{:03X}: {}\n",
+            addr,
+            instruction_to_assembly(instruction, labels)
+        );
+        text += "    chip8.run(1);\n";
+    }
+    text += &format!("    {}\n}}\n", block.num_instructions());
+    text
+}
+
+/// @return a Rust module that runs the program with its blocks compiled to Rust.
+///
+/// The module has the program as `ROM` and `run` and `run_frame` functions that take the
+/// place of `Chip8::run` and `Chip8::run_frame`. A loop dispatches on the program counter
+/// to a function per block. Each block first checks that its code is still in memory, and
+/// anything that is not compiled or has changed runs in the interpreter of `Chip8`, so a
+/// recompiled program behaves the same as an interpreted one. Compiled instructions are not
+/// marked as executed, so only interpreted code is reported when the program overwrites it.
+pub fn recompile(program: &[u8], rom_name: &str) -> String {
+    let blocks = find_blocks(program);
+    let labels: BTreeSet<u16> = BTreeSet::new();
+
+    let mut text = format!(
+        "//! Recompiled from {} by chip8-recompiler. Do not edit.\n\n",
+        rom_name
+    );
+    text += "use chip8::Chip8;\n\n";
+    text += &byte_array("pub const ROM", program);
+    text += "\n";
+
+    text += "/// Run up to `num_instructions` instructions like `Chip8::run`, with compiled blocks in\n";
+    text += "/// place of the interpreter wherever the program has not changed them.\n";
+    text += "/// @return the number of instructions run.\n";
+    text += "pub fn run(chip8: &mut Chip8, num_instructions: u32) -> u32 {\n";
+    text += "    if chip8.is_logging_instructions() {\n";
+    text += "        return chip8.run(num_instructions);\n";
+    text += "    }\n";
+    text += "    let mut count = 0;\n";
+    text += "    while count < num_instructions && !chip8.is_exited() && !chip8.is_waiting_for_key() {\n";
+    text += "        let budget = num_instructions - count;\n";
+    text += "        let ran = match chip8.get_program_counter() {\n";
+    for block in blocks.iter() {
+        text += &format!(
+            "            0x{:03X} => block_{:03x}(chip8, budget),\n",
+            block.start, block.start
+        );
+    }
+    text += "            _ => 0,\n";
+    text += "        };\n";
+    text +=
+        "        // Fall back to the interpreter where there is no block, its code has changed\n";
+    text += "        // or it has more instructions than are left to run.\n";
+    text += "        count += if ran > 0 { ran } else { chip8.run(1) };\n";
+    text += "    }\n";
+    text += "    count\n";
+    text += "}\n\n";
+
+    text += "/// Run one frame like `Chip8::run_frame`.\n";
+    text += "/// @return true if the program has exited.\n";
+    text += "pub fn run_frame(chip8: &mut Chip8, num_instructions: u32) -> bool {\n";
+    text += "    run(chip8, num_instructions);\n";
+    text += "    if chip8.is_exited() {\n";
+    text += "        return true;\n";
+    text += "    }\n";
+    text += "    chip8.tick_timers();\n";
+    text += "    false\n";
+    text += "}\n";
+
+    for block in blocks.iter() {
+        text += "\n";
+        text += &compile_block(program, block, &labels);
+    }
+    text
+}
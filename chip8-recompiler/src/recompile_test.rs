@@ -0,0 +1,129 @@
+use crate::recompile::*;
+
+use chip8_assembler::assemble;
+use chip8_instructions::Instruction;
+
+fn program(text: &str) -> Vec<u8> {
+    assemble(text, PROGRAM_MEM_ADDR, 0x1000).unwrap()
+}
+
+#[test]
+fn test_blocks_end_after_interpreted_instructions() {
+    let blocks = find_blocks(&program("LD V0, 1\nCLS\nLD V1, 2\nDRW V0, V1, 1\nEXIT"));
+    assert_eq!(
+        vec![
+            Block {
+                start: 0x200,
+                compiled: vec![(0x200, Instruction::LoadImmToReg { x: 0, byte: 1 })],
+                interpreted: Some((0x202, Instruction::ClearDisplay)),
+            },
+            Block {
+                start: 0x204,
+                compiled: vec![(0x204, Instruction::LoadImmToReg { x: 1, byte: 2 })],
+                interpreted: Some((
+                    0x206,
+                    Instruction::Draw {
+                        x: 0,
+                        y: 1,
+                        nibble: 1,
+                    }
+                )),
+            },
+        ],
+        blocks
+    );
+}
+
+#[test]
+fn test_blocks_follow_control_flow() {
+    let text = "main:
+  CLS
+loop:
+  ADD V0, 1
+  SE V0, 0
+  JP loop
+  JP main
+  LD V1, 1
+";
+    let blocks = find_blocks(&program(text));
+    let starts: Vec<u16> = blocks.iter().map(|block| block.start).collect();
+    // The block of only CLS is left to the interpreter, and LD V1 is unreachable.
+    assert_eq!(vec![0x202, 0x206, 0x208], starts);
+    assert_eq!(2, blocks[0].num_instructions());
+}
+
+#[test]
+fn test_jump_to_itself_is_interpreted() {
+    let blocks = find_blocks(&program("LD V0, 1\nend:\n  JP end"));
+    assert_eq!(1, blocks.len());
+    assert_eq!(None, blocks[0].interpreted);
+    assert_eq!(1, blocks[0].num_instructions());
+}
+
+#[test]
+fn test_recompile_dispatches_to_blocks() {
+    let module = recompile(&program("LD V0, 1\nCLS\nJP 0x200"), "test.ch8");
+    assert!(module.starts_with("//! Recompiled from test.ch8 by chip8-recompiler."));
+    assert!(module.contains("pub const ROM: [u8; 6] = [0x60, 0x01, 0x00, 0xE0, 0x12, 0x00];\n"));
+    assert!(module.contains("            0x200 => block_200(chip8, budget),\n"));
+    assert!(module.contains("            0x204 => block_204(chip8, budget),\n"));
+    assert!(
+        module.contains("pub fn run_frame(chip8: &mut Chip8, num_instructions: u32) -> bool {")
+    );
+}
+
+#[test]
+fn test_recompile_block() {
+    let module = recompile(&program("LD V0, 1\nADD V0, V1\nCLS\nEXIT"), "test.ch8");
+    let expected = "fn block_200(chip8: &mut Chip8, budget: u32) -> u32 {
+    const CODE: [u8; 6] = [0x60, 0x01, 0x80, 0x14, 0x00, 0xE0];
+    if budget < 3 || chip8.get_memory(0x200, CODE.len()) != CODE {
+        return 0;
+    }
+    let mut v = *chip8.get_registers();
+    // 0x200: LD V0, 0x01
+    v[0x0] = 0x01;
+    // 0x202: ADD V0, V1
+    {
+        let (sum, carry) = v[0x0].overflowing_add(v[0x1]);
+        v[0x0] = sum;
+        v[0xF] = carry as u8;
+    }
+    chip8.set_registers(&v);
+    chip8.set_program_counter(0x204);
+    // 0x204: CLS
+    chip8.run(1);
+    3
+}
+";
+    assert!(module.ends_with(expected), "{}", module);
+}
+
+#[test]
+fn test_recompile_skip() {
+    let module = recompile(
+        &program("loop:\n  SNE V0, V1\n  JP loop\n  EXIT"),
+        "test.ch8",
+    );
+    assert!(module.contains("    let v = *chip8.get_registers();\n"));
+    assert!(module.contains(
+        "    chip8.set_program_counter(if v[0x0] != v[0x1] { 0x204 } else { 0x202 });\n"
+    ));
+}
+
+#[test]
+fn test_recompile_quirks() {
+    let module = recompile(&program("OR V0, V1\nSHR V2, V3\nSHL V4\nEXIT"), "test.ch8");
+    assert!(module.contains("    let quirks = chip8.get_quirks();\n"));
+    assert!(module.contains("    if quirks.vf_reset {\n        v[0xF] = 0;\n    }\n"));
+    assert!(module.contains("let value = if quirks.shift_uses_vy { v[0x3] } else { v[0x2] };\n"));
+    assert!(module.contains("let value = v[0x4];\n"));
+}
+
+#[test]
+fn test_recompile_pointer() {
+    let module = recompile(&program("LD I, 0x300\nADD I, V2\nEXIT"), "test.ch8");
+    assert!(module.contains("    chip8.set_pointer(0x300);\n"));
+    assert!(module.contains("    chip8.set_pointer(chip8.get_pointer() + v[0x2] as u16);\n"));
+    assert!(!module.contains("let mut v"));
+}
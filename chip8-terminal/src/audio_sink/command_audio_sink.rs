@@ -0,0 +1,49 @@
+use super::{AudioSink, to_pcm16};
+
+use std::io::Write;
+use std::process::{Child, ChildStdin, Command, Stdio};
+
+/// Streams 16-bit mono PCM into the stdin of an external player.
+pub struct CommandAudioSink {
+    child: Child,
+    stdin: ChildStdin,
+}
+
+impl CommandAudioSink {
+    pub fn new(command: &mut Command) -> std::io::Result<Self> {
+        let mut child = command.stdin(Stdio::piped()).spawn()?;
+        let stdin = child.stdin.take().expect("stdin is piped");
+        Ok(Self { child, stdin })
+    }
+
+    pub fn new_aplay(sample_rate: u32) -> std::io::Result<Self> {
+        Self::new(Command::new("aplay").args([
+            "-q",
+            "-t",
+            "raw",
+            "-f",
+            "S16_LE",
+            "-c",
+            "1",
+            "-r",
+            sample_rate.to_string().as_str(),
+        ]))
+    }
+}
+
+impl AudioSink for CommandAudioSink {
+    fn write(&mut self, samples: &[f32]) {
+        let bytes: Vec<u8> = samples
+            .iter()
+            .flat_map(|sample| to_pcm16(*sample).to_le_bytes())
+            .collect();
+        let _ = self.stdin.write_all(&bytes);
+    }
+}
+
+impl Drop for CommandAudioSink {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
@@ -0,0 +1,45 @@
+pub mod command_audio_sink;
+pub mod wav_file_sink;
+
+#[cfg(test)]
+mod wav_file_sink_test;
+
+use command_audio_sink::CommandAudioSink;
+use wav_file_sink::WavFileSink;
+
+use std::fs::File;
+
+pub const SAMPLE_RATE: u32 = 44100;
+
+/// Destination for the mono samples rendered from the sound timer.
+pub trait AudioSink {
+    fn write(&mut self, samples: &[f32]);
+}
+
+/// Convert a sample in `-1.0..=1.0` to signed 16-bit PCM.
+pub fn to_pcm16(sample: f32) -> i16 {
+    (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16
+}
+
+/// Create the audio sinks selected on the command line:
+/// - `wav_file_path` writes the audio to a WAV file.
+/// - `play_audio` plays the audio through `aplay`.
+pub fn create(
+    wav_file_path: Option<&str>,
+    play_audio: bool,
+) -> Result<Vec<Box<dyn AudioSink>>, String> {
+    let mut sinks: Vec<Box<dyn AudioSink>> = Vec::new();
+    if let Some(wav_file_path) = wav_file_path {
+        let write_error = |e: std::io::Error| format!("Error writing {}: {}", wav_file_path, e);
+        let file = File::create(wav_file_path).map_err(write_error)?;
+        let sink = WavFileSink::new(file, SAMPLE_RATE).map_err(write_error)?;
+        sinks.push(Box::new(sink));
+    }
+    if play_audio {
+        match CommandAudioSink::new_aplay(SAMPLE_RATE) {
+            Ok(sink) => sinks.push(Box::new(sink)),
+            Err(err) => eprintln!("Failed to start aplay, continuing without sound: {}", err),
+        }
+    }
+    Ok(sinks)
+}
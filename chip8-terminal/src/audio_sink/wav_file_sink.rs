@@ -0,0 +1,74 @@
+use super::{AudioSink, to_pcm16};
+
+use std::io::{BufWriter, Seek, SeekFrom, Write};
+
+const HEADER_BYTES: u32 = 44;
+const CHANNELS: u16 = 1;
+const BITS_PER_SAMPLE: u16 = 16;
+/// The most sample bytes a WAV file can hold, as the RIFF chunk size after the first 8
+/// bytes of the header is 32 bits.
+const MAX_DATA_BYTES: u32 = u32::MAX - (HEADER_BYTES - 8);
+
+/// Writes 16-bit mono PCM to a WAV file. The sizes in the header are filled in as samples
+/// are written, so the file is valid even if the process is interrupted between writes.
+pub struct WavFileSink<W: Write + Seek> {
+    writer: BufWriter<W>,
+    data_bytes: u32,
+}
+
+impl<W: Write + Seek> WavFileSink<W> {
+    pub fn new(writer: W, sample_rate: u32) -> std::io::Result<Self> {
+        let mut writer = BufWriter::new(writer);
+        let block_align = CHANNELS * BITS_PER_SAMPLE / 8;
+        writer.write_all(b"RIFF")?;
+        writer.write_all(&(HEADER_BYTES - 8).to_le_bytes())?;
+        writer.write_all(b"WAVE")?;
+        writer.write_all(b"fmt ")?;
+        writer.write_all(&16u32.to_le_bytes())?;
+        // PCM format.
+        writer.write_all(&1u16.to_le_bytes())?;
+        writer.write_all(&CHANNELS.to_le_bytes())?;
+        writer.write_all(&sample_rate.to_le_bytes())?;
+        writer.write_all(&(sample_rate * block_align as u32).to_le_bytes())?;
+        writer.write_all(&block_align.to_le_bytes())?;
+        writer.write_all(&BITS_PER_SAMPLE.to_le_bytes())?;
+        writer.write_all(b"data")?;
+        writer.write_all(&0u32.to_le_bytes())?;
+        writer.flush()?;
+        Ok(Self {
+            writer,
+            data_bytes: 0,
+        })
+    }
+
+    fn write_samples(&mut self, samples: &[f32]) -> std::io::Result<()> {
+        let samples = &samples[..samples_that_fit(self.data_bytes, samples.len())];
+        for sample in samples {
+            self.writer.write_all(&to_pcm16(*sample).to_le_bytes())?;
+        }
+        self.data_bytes += 2 * samples.len() as u32;
+
+        self.writer.flush()?;
+        let writer = self.writer.get_mut();
+        let writer_pos = writer.stream_position()?;
+        writer.seek(SeekFrom::Start(4))?;
+        writer.write_all(&(HEADER_BYTES - 8 + self.data_bytes).to_le_bytes())?;
+        writer.seek(SeekFrom::Start(HEADER_BYTES as u64 - 4))?;
+        writer.write_all(&self.data_bytes.to_le_bytes())?;
+        writer.seek(SeekFrom::Start(writer_pos))?;
+        Ok(())
+    }
+}
+
+/// @return how many of the samples fit in a file already holding `data_bytes` of samples.
+/// Samples past the 4 GiB limit of a WAV file are dropped.
+pub fn samples_that_fit(data_bytes: u32, num_samples: usize) -> usize {
+    let room = (MAX_DATA_BYTES - data_bytes.min(MAX_DATA_BYTES)) as usize / 2;
+    num_samples.min(room)
+}
+
+impl<W: Write + Seek> AudioSink for WavFileSink<W> {
+    fn write(&mut self, samples: &[f32]) {
+        let _ = self.write_samples(samples);
+    }
+}
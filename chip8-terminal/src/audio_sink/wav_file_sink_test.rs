@@ -0,0 +1,68 @@
+use super::AudioSink;
+use super::wav_file_sink::*;
+
+use std::cell::RefCell;
+use std::io::{Cursor, Seek, SeekFrom, Write};
+use std::rc::Rc;
+
+/// A file in memory that can still be read after the sink that owns it is dropped.
+#[derive(Clone, Default)]
+struct SharedFile(Rc<RefCell<Cursor<Vec<u8>>>>);
+
+impl Write for SharedFile {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for SharedFile {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        self.0.borrow_mut().seek(pos)
+    }
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes(bytes[offset..offset + 2].try_into().unwrap())
+}
+
+#[test]
+fn test_header() {
+    let file = SharedFile::default();
+    let mut sink = WavFileSink::new(file.clone(), 8000).unwrap();
+    sink.write(&[0.0, 1.0, -1.0]);
+    sink.write(&[0.5]);
+
+    let bytes = file.0.borrow().get_ref().clone();
+    assert_eq!(44 + 8, bytes.len());
+    assert_eq!(b"RIFF", &bytes[0..4]);
+    assert_eq!(36 + 8, read_u32(&bytes, 4));
+    assert_eq!(b"WAVEfmt ", &bytes[8..16]);
+    assert_eq!(16, read_u32(&bytes, 16));
+    assert_eq!(1, read_u16(&bytes, 20));
+    assert_eq!(1, read_u16(&bytes, 22));
+    assert_eq!(8000, read_u32(&bytes, 24));
+    assert_eq!(16000, read_u32(&bytes, 28));
+    assert_eq!(2, read_u16(&bytes, 32));
+    assert_eq!(16, read_u16(&bytes, 34));
+    assert_eq!(b"data", &bytes[36..40]);
+    assert_eq!(8, read_u32(&bytes, 40));
+    assert_eq!(i16::MAX, i16::from_le_bytes([bytes[46], bytes[47]]));
+    assert_eq!(-i16::MAX, i16::from_le_bytes([bytes[48], bytes[49]]));
+}
+
+#[test]
+fn test_samples_that_fit() {
+    assert_eq!(100, samples_that_fit(0, 100));
+    let max_data_bytes = u32::MAX - 36;
+    assert_eq!(1, samples_that_fit(max_data_bytes - 3, 100));
+    assert_eq!(0, samples_that_fit(max_data_bytes - 1, 100));
+    assert_eq!(0, samples_that_fit(u32::MAX, 100));
+}
@@ -0,0 +1,303 @@
+use chip8::logger::{
+    ALL_LOG_CATEGORIES, LogCategories, LogCategory, LogLevel, parse_log_categories,
+};
+use chip8::quirks::Quirks;
+use chip8::{DEFAULT_INSTRUCTIONS_PER_FRAME, MAX_INSTRUCTIONS_PER_FRAME};
+
+use crate::config_file::ConfigFile;
+use crate::input::InputKind;
+use crate::renderer::PixelMode;
+
+pub const USAGE: &str = "Usage: chip8-terminal [OPTIONS] ROM
+
+Plays a ROM in the terminal. Press Ctrl+C to quit.
+
+Options:
+  --config=FILE      Read options from FILE. See below for the format.
+  --ipf=N            Instructions per frame, from 1 to 10000. Defaults to 10.
+  --quirks=PRESET    Quirks preset: default, cosmac, schip or xochip. Defaults to default.
+  --seed=N           Seed for the random number generator, in decimal or 0x hex.
+  --pixels=MODE      half packs 1x2 pixels per character, braille packs 2x4. Defaults to
+                     half.
+  --scale=N          Draw each pixel as an NxN square, from 1 to 8. Defaults to 1.
+  --on_color=RRGGBB  Color of lit pixels.
+  --off_color=RRGGBB Color of unlit pixels.
+  --keymap=FILE      Key map file. See keymap.example.cfg.
+  --input=BACKEND    terminal reads key events from the terminal. device_query reads the
+                     keyboard from X11, if built with the device_query feature. Defaults to
+                     terminal.
+  --key_release_ms=N Time after the last key press or repeat that a key is released, from 1
+                     to 2000, for terminals that do not report releases. Defaults to 300.
+  --log_file=FILE    Append log messages to FILE.
+  --log_level=LEVEL  error, warn, info, debug or trace. Defaults to trace.
+  --log_categories=C Comma separated categories to log: general, cpu, display, input and
+                     timers, or all. Defaults to all.
+  --log_format=F     text, or json for one JSON object per line. Defaults to text.
+  --trace_dump=FILE  Keep the last instructions run with their registers in memory, and
+                     write them to FILE on an unknown instruction and on exit.
+  --trace_buffer=N   Number of instructions kept for --trace_dump, from 1 to 1000000.
+                     Defaults to 1000.
+  --headless         Run without reading the keyboard or drawing each frame, as fast as
+                     possible, then print the final display.
+  --frames=N         Stop after N frames.
+  --debug            Show the registers and timers below the display.
+  --play=FILE        Replay a movie instead of reading the keyboard.
+  --record=FILE      Record the keys pressed to a movie.
+  --wav_file=FILE    Write the audio to a WAV file.
+  --play_audio       Play the audio through aplay.
+  --help             Print this message.
+
+The config file has a [default] section and [rom HASH] sections, where HASH is the ROM's
+hash as written in movie files. Each line is an option without the leading dashes, such as
+`ipf = 30` or `headless = true`. Options in a ROM's section override the default section,
+and options on the command line override both.";
+
+const MAX_SCALE: u32 = 8;
+const DEFAULT_KEY_RELEASE_MS: u32 = 300;
+const MAX_KEY_RELEASE_MS: u32 = 2000;
+const DEFAULT_TRACE_BUFFER: u32 = 1000;
+const MAX_TRACE_BUFFER: u32 = 1_000_000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Options {
+    pub instructions_per_frame: u32,
+    pub quirks: Quirks,
+    pub seed: Option<u64>,
+    pub pixels: PixelMode,
+    pub scale: u32,
+    /// Colors as 0xRRGGBB. The terminal's own colors are used when None.
+    pub on_color: Option<u32>,
+    pub off_color: Option<u32>,
+    pub keymap_filepath: Option<String>,
+    pub input: InputKind,
+    pub key_release_ms: u32,
+    pub log_filepath: Option<String>,
+    pub log_level: LogLevel,
+    pub log_categories: LogCategories,
+    pub log_json: bool,
+    pub trace_dump_filepath: Option<String>,
+    pub trace_buffer: u32,
+    pub headless: bool,
+    pub frames: Option<u32>,
+    pub debug: bool,
+    pub play_filepath: Option<String>,
+    pub record_filepath: Option<String>,
+    pub wav_filepath: Option<String>,
+    pub play_audio: bool,
+}
+
+/// The ROM, config file and options given on the command line. Options are applied after
+/// the config file is read, so they can override it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandLine {
+    pub rom_filepath: String,
+    pub config_filepath: Option<String>,
+    flags: Vec<(String, String)>,
+}
+
+/// Options read from a config file in the format of `ConfigFile`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Config {
+    config_file: ConfigFile,
+}
+
+/// Options that are set without a value on the command line.
+const SWITCHES: [&str; 3] = ["headless", "debug", "play_audio"];
+
+/// @return None if help was requested.
+pub fn parse_args(args: &[String]) -> Result<Option<CommandLine>, String> {
+    let mut rom_filepath: Option<String> = None;
+    let mut config_filepath: Option<String> = None;
+    let mut flags = Vec::new();
+    for arg in args {
+        let Some(flag) = arg.strip_prefix("--") else {
+            if rom_filepath.is_some() {
+                return Err(format!("Unexpected argument: {}", arg));
+            }
+            rom_filepath = Some(arg.clone());
+            continue;
+        };
+        let (name, value) = match flag.split_once('=') {
+            Some((name, value)) => (name, value),
+            None if SWITCHES.contains(&flag) => (flag, "true"),
+            None if flag == "help" => return Ok(None),
+            None => {
+                return Err(format!(
+                    "Expected a value for --{}, as --{}=VALUE.",
+                    flag, flag
+                ));
+            }
+        };
+        if name == "config" {
+            config_filepath = Some(value.to_string());
+        } else {
+            // Check the name and value now so mistakes are reported before the ROM is read.
+            Options::default().set(name, value)?;
+            flags.push((name.to_string(), value.to_string()));
+        }
+    }
+
+    Ok(Some(CommandLine {
+        rom_filepath: rom_filepath.ok_or("Expected a ROM file.")?,
+        config_filepath,
+        flags,
+    }))
+}
+
+impl CommandLine {
+    /// @return the options for a ROM: the defaults, then the config file's default section,
+    /// then its sections for the ROM, then the command line.
+    pub fn to_options(&self, config: &Config, program: &[u8]) -> Result<Options, String> {
+        let mut options = Options::default();
+        for entry in config.config_file.entries_for(program) {
+            options
+                .set(&entry.name, &entry.value)
+                .expect("entries are checked when parsed");
+        }
+        for (name, value) in self.flags.iter() {
+            options.set(name, value)?;
+        }
+
+        if options.play_filepath.is_some() && options.record_filepath.is_some() {
+            return Err("Cannot both play and record a movie.".to_string());
+        }
+        if options.headless && options.record_filepath.is_some() {
+            return Err("Cannot record a movie without a keyboard in headless mode.".to_string());
+        }
+        Ok(options)
+    }
+}
+
+impl Config {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let config_file = ConfigFile::parse(text, |name, value| {
+            if name == "config" {
+                return Err("A config file cannot include another.".to_string());
+            }
+            Options::default().set(name, value)
+        })?;
+        Ok(Self { config_file })
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            instructions_per_frame: DEFAULT_INSTRUCTIONS_PER_FRAME,
+            quirks: Quirks::default(),
+            seed: None,
+            pixels: PixelMode::HalfBlock,
+            scale: 1,
+            on_color: None,
+            off_color: None,
+            keymap_filepath: None,
+            input: InputKind::Terminal,
+            key_release_ms: DEFAULT_KEY_RELEASE_MS,
+            log_filepath: None,
+            log_level: LogLevel::Trace,
+            log_categories: ALL_LOG_CATEGORIES,
+            log_json: false,
+            trace_dump_filepath: None,
+            trace_buffer: DEFAULT_TRACE_BUFFER,
+            headless: false,
+            frames: None,
+            debug: false,
+            play_filepath: None,
+            record_filepath: None,
+            wav_filepath: None,
+            play_audio: false,
+        }
+    }
+}
+
+impl Options {
+    /// Set an option by its name on the command line, without the leading dashes.
+    pub fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        let invalid =
+            |expected: &str| format!("Invalid {}: `{}`. Expected {}.", name, value, expected);
+        let filepath = || {
+            if value.is_empty() {
+                Err(invalid("a file path"))
+            } else {
+                Ok(Some(value.to_string()))
+            }
+        };
+        let number = |min: u32, max: u32| {
+            value
+                .parse::<u32>()
+                .ok()
+                .filter(|number| (min..=max).contains(number))
+                .ok_or(invalid(
+                    format!("a number from {} to {}", min, max).as_str(),
+                ))
+        };
+        let switch = || match value {
+            "true" | "1" => Ok(true),
+            "false" | "0" => Ok(false),
+            _ => Err(invalid("true or false")),
+        };
+        let color = || {
+            let hex = value.strip_prefix('#').unwrap_or(value);
+            match u32::from_str_radix(hex, 16) {
+                Ok(color) if hex.len() == 6 => Ok(Some(color)),
+                _ => Err(invalid("a color as RRGGBB")),
+            }
+        };
+
+        match name {
+            "ipf" => self.instructions_per_frame = number(1, MAX_INSTRUCTIONS_PER_FRAME)?,
+            "quirks" => {
+                self.quirks = Quirks::from_preset_name(value)
+                    .ok_or(invalid(Quirks::PRESET_NAMES.join(", ").as_str()))?
+            }
+            "seed" => {
+                let seed = match value.strip_prefix("0x") {
+                    Some(hex) => u64::from_str_radix(hex, 16),
+                    None => value.parse(),
+                };
+                self.seed = Some(seed.map_err(|_| invalid("a decimal or 0x hex number"))?);
+            }
+            "pixels" => {
+                self.pixels = PixelMode::from_name(value)
+                    .ok_or(invalid(PixelMode::NAMES.join(", ").as_str()))?
+            }
+            "scale" => self.scale = number(1, MAX_SCALE)?,
+            "on_color" => self.on_color = color()?,
+            "off_color" => self.off_color = color()?,
+            "keymap" => self.keymap_filepath = filepath()?,
+            "input" => {
+                self.input = InputKind::from_name(value)
+                    .ok_or(invalid(InputKind::NAMES.join(", ").as_str()))?
+            }
+            "key_release_ms" => self.key_release_ms = number(1, MAX_KEY_RELEASE_MS)?,
+            "log_file" => self.log_filepath = filepath()?,
+            "log_level" => {
+                self.log_level = LogLevel::from_name(value)
+                    .ok_or(invalid(LogLevel::NAMES.join(", ").as_str()))?
+            }
+            "log_categories" => {
+                self.log_categories = parse_log_categories(value).ok_or(invalid(
+                    format!("all or a list of {}", LogCategory::NAMES.join(", ")).as_str(),
+                ))?
+            }
+            "log_format" => {
+                self.log_json = match value {
+                    "text" => false,
+                    "json" => true,
+                    _ => return Err(invalid("text or json")),
+                }
+            }
+            "trace_dump" => self.trace_dump_filepath = filepath()?,
+            "trace_buffer" => self.trace_buffer = number(1, MAX_TRACE_BUFFER)?,
+            "headless" => self.headless = switch()?,
+            "frames" => self.frames = Some(number(1, u32::MAX)?),
+            "debug" => self.debug = switch()?,
+            "play" => self.play_filepath = filepath()?,
+            "record" => self.record_filepath = filepath()?,
+            "wav_file" => self.wav_filepath = filepath()?,
+            "play_audio" => self.play_audio = switch()?,
+            _ => return Err(format!("Unknown option: {}", name)),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,177 @@
+use crate::cli::*;
+
+use chip8::DEFAULT_INSTRUCTIONS_PER_FRAME;
+use chip8::logger::{LogCategory, LogLevel};
+use chip8::movie::rom_hash;
+use chip8::quirks::Quirks;
+
+fn args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+}
+
+fn options(command_line: &[&str]) -> Options {
+    parse_args(&args(command_line))
+        .unwrap()
+        .unwrap()
+        .to_options(&Config::default(), &[])
+        .unwrap()
+}
+
+#[test]
+fn test_defaults() {
+    let command_line = parse_args(&args(&["rom.ch8"])).unwrap().unwrap();
+    assert_eq!("rom.ch8", command_line.rom_filepath);
+    assert_eq!(None, command_line.config_filepath);
+    assert_eq!(Options::default(), options(&["rom.ch8"]));
+    assert_eq!(
+        DEFAULT_INSTRUCTIONS_PER_FRAME,
+        Options::default().instructions_per_frame
+    );
+}
+
+#[test]
+fn test_flags() {
+    let options = options(&[
+        "--ipf=30",
+        "--quirks=cosmac",
+        "--seed=0x10",
+        "--scale=2",
+        "--on_color=#ffcc00",
+        "--off_color=000000",
+        "--keymap=keys.cfg",
+        "--log_file=chip8.log",
+        "--log_level=warn",
+        "--log_categories=cpu,input",
+        "--log_format=json",
+        "--trace_dump=trace.txt",
+        "--trace_buffer=50",
+        "--headless",
+        "--frames=100",
+        "--debug",
+        "--wav_file=out.wav",
+        "rom.ch8",
+    ]);
+    assert_eq!(30, options.instructions_per_frame);
+    assert_eq!(Quirks::cosmac(), options.quirks);
+    assert_eq!(Some(16), options.seed);
+    assert_eq!(2, options.scale);
+    assert_eq!(Some(0xffcc00), options.on_color);
+    assert_eq!(Some(0x000000), options.off_color);
+    assert_eq!(Some("keys.cfg".to_string()), options.keymap_filepath);
+    assert_eq!(Some("chip8.log".to_string()), options.log_filepath);
+    assert_eq!(LogLevel::Warn, options.log_level);
+    assert_eq!(
+        LogCategory::Cpu as u8 | LogCategory::Input as u8,
+        options.log_categories
+    );
+    assert!(options.log_json);
+    assert_eq!(Some("trace.txt".to_string()), options.trace_dump_filepath);
+    assert_eq!(50, options.trace_buffer);
+    assert!(options.headless);
+    assert_eq!(Some(100), options.frames);
+    assert!(options.debug);
+    assert_eq!(Some("out.wav".to_string()), options.wav_filepath);
+    assert!(!options.play_audio);
+}
+
+#[test]
+fn test_help() {
+    assert_eq!(None, parse_args(&args(&["--help"])).unwrap());
+    assert_eq!(None, parse_args(&args(&["rom.ch8", "--help"])).unwrap());
+}
+
+#[test]
+fn test_invalid_args() {
+    let error = |command_line: &[&str]| parse_args(&args(command_line)).unwrap_err();
+    assert_eq!("Expected a ROM file.", error(&[]));
+    assert_eq!("Unexpected argument: b.ch8", error(&["a.ch8", "b.ch8"]));
+    assert_eq!("Unknown option: speed", error(&["--speed=2", "rom.ch8"]));
+    assert_eq!(
+        "Expected a value for --ipf, as --ipf=VALUE.",
+        error(&["--ipf", "rom.ch8"])
+    );
+    assert_eq!(
+        "Invalid ipf: `0`. Expected a number from 1 to 10000.",
+        error(&["--ipf=0"])
+    );
+    assert_eq!(
+        "Invalid quirks: `vip`. Expected default, cosmac, schip, xochip.",
+        error(&["--quirks=vip"])
+    );
+    assert_eq!(
+        "Invalid on_color: `red`. Expected a color as RRGGBB.",
+        error(&["--on_color=red"])
+    );
+    assert_eq!(
+        "Invalid headless: `yes`. Expected true or false.",
+        error(&["--headless=yes"])
+    );
+    assert_eq!(
+        "Invalid log_level: `loud`. Expected error, warn, info, debug, trace.",
+        error(&["--log_level=loud"])
+    );
+}
+
+#[test]
+fn test_conflicting_options() {
+    let command_line = parse_args(&args(&["--play=a", "--record=b", "rom.ch8"]))
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        "Cannot both play and record a movie.",
+        command_line
+            .to_options(&Config::default(), &[])
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn test_config_sections() {
+    let program = [0x00, 0xFD];
+    let text = format!(
+        "ipf = 30\nscale = 2\n\n[rom {:016x}]\nipf = 40 # comment\nquirks = schip\n\n[rom 0]\nscale = 3\n",
+        rom_hash(&program)
+    );
+    let config = Config::parse(&text).unwrap();
+
+    let command_line = parse_args(&args(&["rom.ch8"])).unwrap().unwrap();
+    let options = command_line.to_options(&config, &program).unwrap();
+    assert_eq!(40, options.instructions_per_frame);
+    assert_eq!(2, options.scale);
+    assert_eq!(Quirks::schip(), options.quirks);
+
+    let options = command_line.to_options(&config, &[0x00, 0xE0]).unwrap();
+    assert_eq!(30, options.instructions_per_frame);
+    assert_eq!(Quirks::default(), options.quirks);
+}
+
+#[test]
+fn test_command_line_overrides_config() {
+    let config = Config::parse("[default]\nipf = 30\nheadless = true\n").unwrap();
+    let command_line = parse_args(&args(&["--ipf=50", "--headless=false", "rom.ch8"]))
+        .unwrap()
+        .unwrap();
+    let options = command_line.to_options(&config, &[]).unwrap();
+    assert_eq!(50, options.instructions_per_frame);
+    assert!(!options.headless);
+}
+
+#[test]
+fn test_config_errors() {
+    let error = |text: &str| Config::parse(text).unwrap_err();
+    assert_eq!(
+        "Line 2: Invalid scale: `9`. Expected a number from 1 to 8.",
+        error("ipf = 10\nscale = 9")
+    );
+    assert_eq!("Line 1: Unknown option: speed", error("speed = 2"));
+    assert_eq!(
+        "Line 1: A config file cannot include another.",
+        error("config = other.cfg")
+    );
+    assert_eq!("Line 1: Invalid ROM hash: xyz", error("[rom xyz]"));
+    assert_eq!(
+        "Line 1: Unknown section `roms`. Expected `default` or `rom <hash>`.",
+        error("[roms]")
+    );
+    assert_eq!("Line 1: Expected `name = value`.", error("headless"));
+}
@@ -0,0 +1,110 @@
+use chip8::movie::rom_hash;
+
+/// A `name = value` line in a config file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    pub line_num: usize,
+    pub name: String,
+    pub value: String,
+}
+
+/// A config file with a default section and sections that apply to a single ROM:
+///
+/// ```text
+/// # Applies to every ROM. Lines before the first section are also in the default section.
+/// [default]
+/// name = value
+///
+/// # Applies to the ROM with this hash, after the default section.
+/// [rom 8c5b2a1f0e3d4c6b]
+/// name = value
+/// ```
+///
+/// The ROM hash is `chip8::movie::rom_hash`, as written in movie files. A `#` starts a
+/// comment at the start of a line or after a space, but not at the start of a value, so
+/// `on_color = #00ff00` keeps its color.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConfigFile {
+    default: Vec<Entry>,
+    roms: Vec<(u64, Vec<Entry>)>,
+}
+
+impl ConfigFile {
+    /// Parse the sections, checking each entry with `check(name, value)`.
+    pub fn parse(
+        text: &str,
+        check: impl Fn(&str, &str) -> Result<(), String>,
+    ) -> Result<Self, String> {
+        let mut config_file = Self::default();
+        let mut section = &mut config_file.default;
+        for (line_num, line) in text.lines().enumerate() {
+            let error = |message: String| format!("Line {}: {}", line_num + 1, message);
+            let line = strip_comment(line);
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let name = header
+                    .strip_suffix(']')
+                    .ok_or(error("Expected `]` after the section name.".to_string()))?
+                    .trim();
+                section = if name == "default" {
+                    &mut config_file.default
+                } else if let Some(hash) = name.strip_prefix("rom ") {
+                    let hash = u64::from_str_radix(hash.trim(), 16)
+                        .map_err(|_| error(format!("Invalid ROM hash: {}", hash.trim())))?;
+                    config_file.roms.push((hash, Vec::new()));
+                    &mut config_file.roms.last_mut().unwrap().1
+                } else {
+                    return Err(error(format!(
+                        "Unknown section `{}`. Expected `default` or `rom <hash>`.",
+                        name
+                    )));
+                };
+                continue;
+            }
+
+            let (name, value) = line
+                .split_once('=')
+                .ok_or(error("Expected `name = value`.".to_string()))?;
+            let (name, value) = (name.trim(), value.trim());
+            check(name, value).map_err(error)?;
+            section.push(Entry {
+                line_num,
+                name: name.to_string(),
+                value: value.to_string(),
+            });
+        }
+        Ok(config_file)
+    }
+
+    /// @return the entries of the default section followed by those of the ROM's sections.
+    pub fn entries_for(&self, program: &[u8]) -> impl Iterator<Item = &Entry> {
+        let hash = rom_hash(program);
+        let rom_entries = self
+            .roms
+            .iter()
+            .filter(move |(rom, _)| *rom == hash)
+            .flat_map(|(_, entries)| entries.iter());
+        self.default.iter().chain(rom_entries)
+    }
+}
+
+/// @return the trimmed line without its comment.
+fn strip_comment(line: &str) -> &str {
+    let line = line.trim();
+    let value_start = line.find('=').map(|equals| {
+        let value = &line[equals + 1..];
+        line.len() - value.trim_start().len()
+    });
+    let comment_start = line.char_indices().find(|(i, c)| {
+        *c == '#'
+            && Some(*i) != value_start
+            && (*i == 0 || line[..*i].ends_with(char::is_whitespace))
+    });
+    match comment_start {
+        Some((i, _)) => line[..i].trim_end(),
+        None => line,
+    }
+}
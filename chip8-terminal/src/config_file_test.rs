@@ -0,0 +1,54 @@
+use crate::config_file::*;
+
+fn parse(text: &str) -> Result<ConfigFile, String> {
+    ConfigFile::parse(text, |_, _| Ok(()))
+}
+
+/// @return the `(name, value)` pairs that apply to a ROM.
+fn entries(config_file: &ConfigFile, program: &[u8]) -> Vec<(String, String)> {
+    config_file
+        .entries_for(program)
+        .map(|entry| (entry.name.clone(), entry.value.clone()))
+        .collect()
+}
+
+fn entry(name: &str, value: &str) -> (String, String) {
+    (name.to_string(), value.to_string())
+}
+
+#[test]
+fn test_comments() {
+    let config_file = parse(
+        "# A comment\n  # An indented comment\n[default] # The default section\nipf = 30 # Faster\nscale=2#3\n",
+    )
+    .unwrap();
+    assert_eq!(
+        vec![entry("ipf", "30"), entry("scale", "2#3")],
+        entries(&config_file, &[])
+    );
+}
+
+#[test]
+fn test_color_value() {
+    let config_file = parse("on_color = #00ff00\noff_color=#000000 # Black\n").unwrap();
+    assert_eq!(
+        vec![entry("on_color", "#00ff00"), entry("off_color", "#000000")],
+        entries(&config_file, &[])
+    );
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(
+        Err("Line 2: Expected `name = value`.".to_string()),
+        parse("ipf = 30\nscale\n")
+    );
+    assert_eq!(
+        Err("Line 1: Invalid ROM hash: 12#4".to_string()),
+        parse("[rom 12#4]\n")
+    );
+    assert_eq!(
+        Err("Line 1: Expected `]` after the section name.".to_string()),
+        parse("[default # ]\n")
+    );
+}
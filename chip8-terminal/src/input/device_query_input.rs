@@ -0,0 +1,31 @@
+use super::InputBackend;
+use crate::key_map::parse_host_key;
+
+use device_query::DeviceQuery;
+use device_query::DeviceState;
+
+/// Reads the global keyboard state from the windowing system. This sees keys pressed in
+/// other windows and needs an X display.
+pub struct DeviceQueryInput {
+    device_state: DeviceState,
+}
+
+impl DeviceQueryInput {
+    pub fn new() -> Self {
+        Self {
+            device_state: DeviceState::new(),
+        }
+    }
+}
+
+impl InputBackend for DeviceQueryInput {
+    fn poll(&mut self) -> std::io::Result<Option<Vec<&'static str>>> {
+        let keys = self
+            .device_state
+            .get_keys()
+            .iter()
+            .filter_map(|keycode| parse_host_key(&keycode.to_string()))
+            .collect();
+        Ok(Some(keys))
+    }
+}
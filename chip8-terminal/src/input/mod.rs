@@ -0,0 +1,51 @@
+#[cfg(feature = "device_query")]
+pub mod device_query_input;
+pub mod terminal_input;
+
+use terminal_input::TerminalInput;
+
+use std::time::Duration;
+
+/// Reads which host keys are held. Keys are named as in `key_map::HOST_KEY_NAMES`.
+pub trait InputBackend {
+    /// @return the host keys held now, or None if the user asked to quit.
+    fn poll(&mut self) -> std::io::Result<Option<Vec<&'static str>>>;
+}
+
+/// Where key presses are read from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InputKind {
+    /// Key events from the terminal in raw mode. Works over SSH and in a TTY.
+    Terminal,
+    /// The global keyboard state from the windowing system. Needs the `device_query`
+    /// feature.
+    DeviceQuery,
+}
+
+impl InputKind {
+    pub const NAMES: [&str; 2] = ["terminal", "device_query"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "terminal" => Some(InputKind::Terminal),
+            "device_query" => Some(InputKind::DeviceQuery),
+            _ => None,
+        }
+    }
+}
+
+/// Create an input backend. `release_timeout` is how long the terminal backend treats a
+/// key as held after its last press or repeat, when the terminal does not report releases.
+pub fn create(kind: InputKind, release_timeout: Duration) -> Result<Box<dyn InputBackend>, String> {
+    match kind {
+        InputKind::Terminal => TerminalInput::new(release_timeout)
+            .map(|input| Box::new(input) as Box<dyn InputBackend>)
+            .map_err(|e| format!("Failed to read the terminal's keyboard: {}", e)),
+        #[cfg(feature = "device_query")]
+        InputKind::DeviceQuery => Ok(Box::new(device_query_input::DeviceQueryInput::new())),
+        #[cfg(not(feature = "device_query"))]
+        InputKind::DeviceQuery => {
+            Err("chip8-terminal was built without the device_query feature.".to_string())
+        }
+    }
+}
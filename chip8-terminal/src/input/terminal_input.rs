@@ -0,0 +1,145 @@
+use super::InputBackend;
+use crate::key_map::parse_host_key;
+
+use crossterm::event::{
+    self, Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers,
+    KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+};
+use crossterm::execute;
+use crossterm::terminal;
+use std::collections::HashMap;
+use std::io;
+use std::time::{Duration, Instant};
+
+/// Reads key events from stdin with the terminal in raw mode.
+///
+/// Most terminals only report presses, repeated while a key is held, so a key is released
+/// once no press has arrived for the release timeout. Terminals with the kitty keyboard
+/// protocol report releases, and the timeout is not used. Ctrl+C quits.
+pub struct TerminalInput {
+    held_keys: HeldKeys,
+    enhanced: bool,
+}
+
+/// Host keys held, with the time of the last press or repeat of each.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeldKeys {
+    pressed_at: HashMap<&'static str, Instant>,
+    /// None if releases are reported.
+    release_timeout: Option<Duration>,
+}
+
+impl TerminalInput {
+    pub fn new(release_timeout: Duration) -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        let enhanced = terminal::supports_keyboard_enhancement().unwrap_or(false);
+        if enhanced {
+            execute!(
+                io::stdout(),
+                PushKeyboardEnhancementFlags(
+                    KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES
+                        | KeyboardEnhancementFlags::REPORT_EVENT_TYPES
+                )
+            )?;
+        }
+        Ok(Self {
+            held_keys: HeldKeys::new((!enhanced).then_some(release_timeout)),
+            enhanced,
+        })
+    }
+}
+
+impl InputBackend for TerminalInput {
+    fn poll(&mut self) -> io::Result<Option<Vec<&'static str>>> {
+        while event::poll(Duration::ZERO)? {
+            let Event::Key(key_event) = event::read()? else {
+                continue;
+            };
+            if key_event.code == KeyCode::Char('c')
+                && key_event.modifiers.contains(KeyModifiers::CONTROL)
+            {
+                return Ok(None);
+            }
+            self.held_keys.update(&key_event, Instant::now());
+        }
+        Ok(Some(self.held_keys.get_held(Instant::now())))
+    }
+}
+
+impl Drop for TerminalInput {
+    fn drop(&mut self) {
+        if self.enhanced {
+            let _ = execute!(io::stdout(), PopKeyboardEnhancementFlags);
+        }
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+impl HeldKeys {
+    pub fn new(release_timeout: Option<Duration>) -> Self {
+        Self {
+            pressed_at: HashMap::new(),
+            release_timeout,
+        }
+    }
+
+    pub fn update(&mut self, key_event: &KeyEvent, now: Instant) {
+        let Some(host_key) = to_host_key(key_event) else {
+            return;
+        };
+        match key_event.kind {
+            KeyEventKind::Press | KeyEventKind::Repeat => {
+                self.pressed_at.insert(host_key, now);
+            }
+            KeyEventKind::Release => {
+                self.pressed_at.remove(host_key);
+            }
+        }
+    }
+
+    /// @return the keys held at `now`, releasing those that timed out.
+    pub fn get_held(&mut self, now: Instant) -> Vec<&'static str> {
+        if let Some(release_timeout) = self.release_timeout {
+            self.pressed_at
+                .retain(|_, pressed_at| now.duration_since(*pressed_at) < release_timeout);
+        }
+        let mut keys: Vec<&'static str> = self.pressed_at.keys().copied().collect();
+        keys.sort();
+        keys
+    }
+}
+
+/// @return the name of the host key for a key event, or None if it cannot be bound.
+pub fn to_host_key(key_event: &KeyEvent) -> Option<&'static str> {
+    let keypad = key_event.state.contains(KeyEventState::KEYPAD);
+    match key_event.code {
+        KeyCode::Char(c) if keypad => match c {
+            '0'..='9' => parse_host_key(&format!("Numpad{}", c)),
+            '+' => Some("NumpadAdd"),
+            '-' => Some("NumpadSubtract"),
+            '*' => Some("NumpadMultiply"),
+            '/' => Some("NumpadDivide"),
+            '.' => Some("NumpadDecimal"),
+            _ => None,
+        },
+        KeyCode::Char(' ') => Some("Space"),
+        KeyCode::Char(c) => parse_host_key(&c.to_string()),
+        KeyCode::Enter if keypad => Some("NumpadEnter"),
+        KeyCode::Enter => Some("Enter"),
+        KeyCode::Esc => Some("Escape"),
+        KeyCode::Backspace => Some("Backspace"),
+        KeyCode::Tab => Some("Tab"),
+        KeyCode::Up => Some("Up"),
+        KeyCode::Down => Some("Down"),
+        KeyCode::Left => Some("Left"),
+        KeyCode::Right => Some("Right"),
+        KeyCode::Home => Some("Home"),
+        KeyCode::End => Some("End"),
+        KeyCode::PageUp => Some("PageUp"),
+        KeyCode::PageDown => Some("PageDown"),
+        KeyCode::Insert => Some("Insert"),
+        KeyCode::Delete => Some("Delete"),
+        KeyCode::F(n) => parse_host_key(&format!("F{}", n)),
+        _ => None,
+    }
+}
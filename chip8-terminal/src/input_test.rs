@@ -0,0 +1,99 @@
+use crate::input::InputKind;
+use crate::input::terminal_input::*;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+use std::time::{Duration, Instant};
+
+fn key_event(code: KeyCode, kind: KeyEventKind) -> KeyEvent {
+    KeyEvent::new_with_kind(code, KeyModifiers::NONE, kind)
+}
+
+fn press(code: KeyCode) -> KeyEvent {
+    key_event(code, KeyEventKind::Press)
+}
+
+#[test]
+fn test_to_host_key() {
+    assert_eq!(Some("A"), to_host_key(&press(KeyCode::Char('a'))));
+    assert_eq!(Some("A"), to_host_key(&press(KeyCode::Char('A'))));
+    assert_eq!(Some("Key4"), to_host_key(&press(KeyCode::Char('4'))));
+    assert_eq!(Some("Comma"), to_host_key(&press(KeyCode::Char(','))));
+    assert_eq!(Some("Space"), to_host_key(&press(KeyCode::Char(' '))));
+    assert_eq!(Some("Up"), to_host_key(&press(KeyCode::Up)));
+    assert_eq!(Some("F5"), to_host_key(&press(KeyCode::F(5))));
+    assert_eq!(None, to_host_key(&press(KeyCode::Char('!'))));
+    assert_eq!(None, to_host_key(&press(KeyCode::F(24))));
+}
+
+#[test]
+fn test_to_host_key_keypad() {
+    let mut event = press(KeyCode::Char('7'));
+    event.state = KeyEventState::KEYPAD;
+    assert_eq!(Some("Numpad7"), to_host_key(&event));
+    event.code = KeyCode::Char('+');
+    assert_eq!(Some("NumpadAdd"), to_host_key(&event));
+    event.code = KeyCode::Enter;
+    assert_eq!(Some("NumpadEnter"), to_host_key(&event));
+}
+
+#[test]
+fn test_held_keys_release_after_timeout() {
+    let start = Instant::now();
+    let mut held_keys = HeldKeys::new(Some(Duration::from_millis(300)));
+    held_keys.update(&press(KeyCode::Char('q')), start);
+    held_keys.update(
+        &press(KeyCode::Char('w')),
+        start + Duration::from_millis(100),
+    );
+    assert_eq!(
+        vec!["Q", "W"],
+        held_keys.get_held(start + Duration::from_millis(200))
+    );
+    assert_eq!(
+        vec!["W"],
+        held_keys.get_held(start + Duration::from_millis(300))
+    );
+
+    // A repeat keeps the key held.
+    held_keys.update(
+        &key_event(KeyCode::Char('w'), KeyEventKind::Repeat),
+        start + Duration::from_millis(350),
+    );
+    assert_eq!(
+        vec!["W"],
+        held_keys.get_held(start + Duration::from_millis(600))
+    );
+    assert!(
+        held_keys
+            .get_held(start + Duration::from_millis(650))
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_held_keys_with_release_events() {
+    let start = Instant::now();
+    let mut held_keys = HeldKeys::new(None);
+    held_keys.update(&press(KeyCode::Char('q')), start);
+    assert_eq!(
+        vec!["Q"],
+        held_keys.get_held(start + Duration::from_secs(10))
+    );
+    held_keys.update(
+        &key_event(KeyCode::Char('q'), KeyEventKind::Release),
+        start + Duration::from_secs(11),
+    );
+    assert!(
+        held_keys
+            .get_held(start + Duration::from_secs(11))
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_input_kind_from_name() {
+    for name in InputKind::NAMES {
+        assert!(InputKind::from_name(name).is_some(), "{}", name);
+    }
+    assert_eq!(None, InputKind::from_name("x11"));
+}
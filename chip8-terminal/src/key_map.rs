@@ -0,0 +1,241 @@
+use chip8::Chip8Keys;
+
+use crate::config_file::ConfigFile;
+
+pub const LAYOUT_NAMES: [&str; 4] = ["qwerty", "azerty", "dvorak", "numpad"];
+
+/// Names of the host keys that can be bound, matching `device_query::Keycode`. The terminal
+/// input names a key by the character it types, but device_query names it by its position
+/// on a US QWERTY keyboard, so on an AZERTY keyboard the key that types `A` is `Q`. With
+/// device_query the qwerty layout suits any keyboard, and azerty and dvorak do not apply.
+#[rustfmt::skip]
+pub const HOST_KEY_NAMES: &[&str] = &[
+    "Key0", "Key1", "Key2", "Key3", "Key4", "Key5", "Key6", "Key7", "Key8", "Key9",
+    "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M",
+    "N", "O", "P", "Q", "R", "S", "T", "U", "V", "W", "X", "Y", "Z",
+    "F1", "F2", "F3", "F4", "F5", "F6", "F7", "F8", "F9", "F10", "F11", "F12",
+    "Escape", "Space", "Enter", "Backspace", "Tab", "CapsLock",
+    "LControl", "RControl", "LShift", "RShift", "LAlt", "RAlt",
+    "Up", "Down", "Left", "Right", "Home", "End", "PageUp", "PageDown", "Insert", "Delete",
+    "Numpad0", "Numpad1", "Numpad2", "Numpad3", "Numpad4",
+    "Numpad5", "Numpad6", "Numpad7", "Numpad8", "Numpad9",
+    "NumpadSubtract", "NumpadAdd", "NumpadDivide", "NumpadMultiply", "NumpadEnter",
+    "NumpadDecimal",
+    "Grave", "Minus", "Equal", "LeftBracket", "RightBracket", "BackSlash",
+    "Semicolon", "Apostrophe", "Comma", "Dot", "Slash",
+];
+
+/// Single characters accepted in config files in place of a host key name.
+const HOST_KEY_ALIASES: [(char, &str); 11] = [
+    ('`', "Grave"),
+    ('-', "Minus"),
+    ('=', "Equal"),
+    ('[', "LeftBracket"),
+    (']', "RightBracket"),
+    ('\\', "BackSlash"),
+    (';', "Semicolon"),
+    ('\'', "Apostrophe"),
+    (',', "Comma"),
+    ('.', "Dot"),
+    ('/', "Slash"),
+];
+
+/// Host keys for each CHIP-8 key, laid out like the COSMAC VIP keypad:
+///
+/// ```text
+/// 1 2 3 C
+/// 4 5 6 D
+/// 7 8 9 E
+/// A 0 B F
+/// ```
+const KEYPAD: [[u8; 4]; 4] = [
+    [0x1, 0x2, 0x3, 0xC],
+    [0x4, 0x5, 0x6, 0xD],
+    [0x7, 0x8, 0x9, 0xE],
+    [0xA, 0x0, 0xB, 0xF],
+];
+
+/// The host keys bound to each of the 16 CHIP-8 keys. A CHIP-8 key is pressed when any of
+/// its host keys is pressed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyMap {
+    host_keys: [Vec<String>; 16],
+}
+
+/// A key map file, in the format of `ConfigFile`:
+///
+/// ```text
+/// [default]
+/// layout = azerty
+///
+/// [rom 8c5b2a1f0e3d4c6b]
+/// 5 = Z Up
+/// 8 = S Down
+/// ```
+///
+/// `layout` replaces every binding with one of `LAYOUT_NAMES`. A hex digit binds that
+/// CHIP-8 key to a whitespace separated list of host keys, replacing its bindings.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct KeyMapConfig {
+    config_file: ConfigFile,
+}
+
+impl KeyMap {
+    /// 1234/QWER/ASDF/ZXCV, the layout used by most CHIP-8 emulators.
+    pub fn qwerty() -> Self {
+        Self::from_rows([
+            ["Key1", "Key2", "Key3", "Key4"],
+            ["Q", "W", "E", "R"],
+            ["A", "S", "D", "F"],
+            ["Z", "X", "C", "V"],
+        ])
+    }
+
+    /// The characters typed by the keys in the QWERTY positions on an AZERTY keyboard, for
+    /// the terminal input.
+    pub fn azerty() -> Self {
+        Self::from_rows([
+            ["Key1", "Key2", "Key3", "Key4"],
+            ["A", "Z", "E", "R"],
+            ["Q", "S", "D", "F"],
+            ["W", "X", "C", "V"],
+        ])
+    }
+
+    /// The characters typed by the keys in the QWERTY positions on a Dvorak keyboard, for
+    /// the terminal input.
+    pub fn dvorak() -> Self {
+        Self::from_rows([
+            ["Key1", "Key2", "Key3", "Key4"],
+            ["Apostrophe", "Comma", "Dot", "P"],
+            ["A", "O", "E", "U"],
+            ["Semicolon", "Q", "J", "K"],
+        ])
+    }
+
+    /// The numeric keypad, with the operator keys as the right-hand column.
+    pub fn numpad() -> Self {
+        Self::from_rows([
+            ["Numpad7", "Numpad8", "Numpad9", "NumpadDivide"],
+            ["Numpad4", "Numpad5", "Numpad6", "NumpadMultiply"],
+            ["Numpad1", "Numpad2", "Numpad3", "NumpadSubtract"],
+            ["Numpad0", "NumpadDecimal", "NumpadEnter", "NumpadAdd"],
+        ])
+    }
+
+    pub fn from_layout_name(name: &str) -> Option<Self> {
+        match name {
+            "qwerty" => Some(Self::qwerty()),
+            "azerty" => Some(Self::azerty()),
+            "dvorak" => Some(Self::dvorak()),
+            "numpad" => Some(Self::numpad()),
+            _ => None,
+        }
+    }
+
+    /// Build a map from host keys laid out in the same positions as `KEYPAD`.
+    fn from_rows(rows: [[&str; 4]; 4]) -> Self {
+        let mut key_map = Self {
+            host_keys: Default::default(),
+        };
+        for (keypad_row, row) in KEYPAD.iter().zip(rows) {
+            for (chip8_key, host_key) in keypad_row.iter().zip(row) {
+                key_map.host_keys[*chip8_key as usize] = vec![host_key.to_string()];
+            }
+        }
+        key_map
+    }
+
+    /// Replace the host keys bound to a CHIP-8 key, from 0x0 to 0xF.
+    pub fn bind(&mut self, chip8_key: u8, host_keys: Vec<String>) {
+        self.host_keys[chip8_key as usize] = host_keys;
+    }
+
+    /// Apply a line of a key map file: `layout = <name>` or `<hex key> = <host keys>`.
+    pub fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        if name == "layout" {
+            *self = KeyMap::from_layout_name(value).ok_or(format!(
+                "Unknown layout `{}`. Expected one of {}.",
+                value,
+                LAYOUT_NAMES.join(", ")
+            ))?;
+            return Ok(());
+        }
+
+        let chip8_key = match u8::from_str_radix(name, 16) {
+            Ok(chip8_key) if name.len() == 1 => chip8_key,
+            _ => {
+                return Err(format!(
+                    "Expected `layout` or a CHIP-8 key from 0 to F, found `{}`.",
+                    name
+                ));
+            }
+        };
+        let host_keys = value
+            .split_whitespace()
+            .map(|host_key| {
+                parse_host_key(host_key)
+                    .map(|host_key| host_key.to_string())
+                    .ok_or(format!("Unknown host key `{}`.", host_key))
+            })
+            .collect::<Result<Vec<String>, String>>()?;
+        self.bind(chip8_key, host_keys);
+        Ok(())
+    }
+
+    /// @return the CHIP-8 keys pressed while the named host keys are pressed.
+    pub fn keys_pressed<'a>(&self, pressed: impl IntoIterator<Item = &'a str>) -> Chip8Keys {
+        let pressed: Vec<&str> = pressed.into_iter().collect();
+        (0..16)
+            .filter(|i| {
+                self.host_keys[*i]
+                    .iter()
+                    .any(|host_key| pressed.contains(&host_key.as_str()))
+            })
+            .fold(0, |keys, i| keys | (1 << i))
+    }
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        Self::qwerty()
+    }
+}
+
+impl KeyMapConfig {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let config_file =
+            ConfigFile::parse(text, |name, value| KeyMap::default().set(name, value))?;
+        Ok(Self { config_file })
+    }
+
+    /// @return the key map for a ROM: the QWERTY layout, then the default section, then
+    /// any sections for the ROM's hash.
+    pub fn key_map_for(&self, program: &[u8]) -> KeyMap {
+        let mut key_map = KeyMap::default();
+        for entry in self.config_file.entries_for(program) {
+            key_map
+                .set(&entry.name, &entry.value)
+                .expect("entries are checked when parsed");
+        }
+        key_map
+    }
+}
+
+/// @return the canonical name of a host key given its name in any case, a digit, or one
+/// of the punctuation characters in `HOST_KEY_ALIASES`.
+pub fn parse_host_key(name: &str) -> Option<&'static str> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        if let Some(digit) = c.to_digit(10) {
+            return Some(HOST_KEY_NAMES[digit as usize]);
+        }
+        if let Some((_, alias)) = HOST_KEY_ALIASES.iter().find(|(alias, _)| *alias == c) {
+            return Some(alias);
+        }
+    }
+    HOST_KEY_NAMES
+        .iter()
+        .find(|host_key| host_key.eq_ignore_ascii_case(name))
+        .copied()
+}
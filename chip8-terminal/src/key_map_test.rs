@@ -0,0 +1,100 @@
+use crate::key_map::*;
+
+use chip8::Chip8Keys;
+use chip8::movie::rom_hash;
+
+#[test]
+fn test_qwerty_is_default() {
+    let key_map = KeyMapConfig::default().key_map_for(&[]);
+    assert_eq!(KeyMap::qwerty(), key_map);
+    assert_eq!(0x0002, key_map.keys_pressed(["Key1"]));
+    assert_eq!(0x8001, key_map.keys_pressed(["X", "V"]));
+    assert_eq!(0x0000, key_map.keys_pressed(["P"]));
+}
+
+#[test]
+fn test_layouts_bind_every_key() {
+    for name in LAYOUT_NAMES {
+        let key_map = KeyMap::from_layout_name(name).unwrap();
+        let bound: Vec<Chip8Keys> = HOST_KEY_NAMES
+            .iter()
+            .map(|host_key| key_map.keys_pressed([*host_key]))
+            .filter(|keys| *keys != 0)
+            .collect();
+        assert_eq!(16, bound.len(), "{}", name);
+        assert_eq!(
+            0xFFFF,
+            bound.iter().fold(0, |acc, keys| acc | keys),
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn test_parse_host_key() {
+    assert_eq!(Some("Key7"), parse_host_key("7"));
+    assert_eq!(Some("A"), parse_host_key("a"));
+    assert_eq!(Some("Numpad7"), parse_host_key("numpad7"));
+    assert_eq!(Some("Comma"), parse_host_key(","));
+    assert_eq!(Some("Slash"), parse_host_key("slash"));
+    assert_eq!(None, parse_host_key("Hyper"));
+}
+
+#[test]
+fn test_several_host_keys_per_chip8_key() {
+    let config = KeyMapConfig::parse("5 = W Up\n8 = s down # comment\n").unwrap();
+    let key_map = config.key_map_for(&[]);
+    assert_eq!(0x0020, key_map.keys_pressed(["W"]));
+    assert_eq!(0x0020, key_map.keys_pressed(["Up"]));
+    assert_eq!(0x0120, key_map.keys_pressed(["W", "Down"]));
+    assert_eq!(0x0100, key_map.keys_pressed(["S"]));
+    assert_eq!(0x0002, key_map.keys_pressed(["Key1"]));
+}
+
+#[test]
+fn test_rom_section_overrides_default() {
+    let program = [0x00, 0xFD];
+    let text = format!(
+        "[default]\nlayout = azerty\n\n[rom {:016x}]\n5 = Up\n\n[rom 0]\nlayout = numpad\n",
+        rom_hash(&program)
+    );
+    let config = KeyMapConfig::parse(&text).unwrap();
+
+    let key_map = config.key_map_for(&program);
+    assert_eq!(0x0020, key_map.keys_pressed(["Up"]));
+    assert_eq!(0x0000, key_map.keys_pressed(["Z"]));
+    assert_eq!(0x0010, key_map.keys_pressed(["A"]));
+
+    assert_eq!(KeyMap::azerty(), config.key_map_for(&[0x00, 0xE0]));
+}
+
+#[test]
+fn test_layout_replaces_earlier_bindings() {
+    let config = KeyMapConfig::parse("5 = Up\nlayout = dvorak\n").unwrap();
+    assert_eq!(KeyMap::dvorak(), config.key_map_for(&[]));
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = |text: &str| KeyMapConfig::parse(text).unwrap_err();
+    assert_eq!(
+        "Line 2: Unknown layout `colemak`. Expected one of qwerty, azerty, dvorak, numpad.",
+        error("\nlayout = colemak")
+    );
+    assert_eq!("Line 1: Unknown host key `Hyper`.", error("1 = A Hyper"));
+    assert_eq!(
+        "Line 1: Expected `layout` or a CHIP-8 key from 0 to F, found `10`.",
+        error("10 = A")
+    );
+    assert_eq!("Line 1: Expected `name = value`.", error("A"));
+    assert_eq!("Line 1: Invalid ROM hash: xyz", error("[rom xyz]"));
+    assert_eq!(
+        "Line 1: Unknown section `roms`. Expected `default` or `rom <hash>`.",
+        error("[roms]")
+    );
+    assert_eq!(
+        "Line 1: Expected `]` after the section name.",
+        error("[default")
+    );
+}
@@ -1,24 +1,167 @@
+mod audio_sink;
+mod cli;
+mod config_file;
+mod input;
+mod key_map;
+mod renderer;
 mod terminal_player;
 
+#[cfg(test)]
+mod cli_test;
+
+#[cfg(test)]
+mod config_file_test;
+
+#[cfg(test)]
+mod input_test;
+
+#[cfg(test)]
+mod key_map_test;
+
+#[cfg(test)]
+mod renderer_test;
+
 use chip8::Chip8;
 use chip8::logger;
+use chip8::logger::LogSource;
 use chip8::logger::Logger;
+use chip8::logger::filter_log_source::FilterLogSource;
+use chip8::logger::ring_buffer_log_source::{RingBufferLogSource, TraceBuffer};
+use chip8::logger::tee_log_source::TeeLogSource;
+use chip8::movie::Movie;
+use cli::{CommandLine, Config, Options, USAGE};
+use key_map::KeyMapConfig;
 
 use std::env;
 use std::fs;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::process::ExitCode;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() < 2 {
-        println!("Expected command line argument of path to program.");
-        return;
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let command_line = match cli::parse_args(&args) {
+        Ok(Some(command_line)) => command_line,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match run(&command_line) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
     }
+}
+
+fn run(command_line: &CommandLine) -> Result<(), String> {
+    let read_file = |filepath: &str| {
+        fs::read_to_string(filepath).map_err(|e| format!("Error reading {}: {}", filepath, e))
+    };
+    let program = fs::read(&command_line.rom_filepath)
+        .map_err(|e| format!("Error reading {}: {}", command_line.rom_filepath, e))?;
+    let config = match command_line.config_filepath {
+        Some(ref config_filepath) => Config::parse(&read_file(config_filepath)?)
+            .map_err(|e| format!("Error in {}: {}", config_filepath, e))?,
+        None => Config::default(),
+    };
+    let options = command_line.to_options(&config, &program)?;
+    let key_map_config = match options.keymap_filepath {
+        Some(ref keymap_filepath) => KeyMapConfig::parse(&read_file(keymap_filepath)?)
+            .map_err(|e| format!("Error in {}: {}", keymap_filepath, e))?,
+        None => KeyMapConfig::default(),
+    };
+
+    let (logger, trace_dump) = create_logger(&options)?;
+    let chip8 = create_chip8(logger, &options);
+    let audio_sinks = audio_sink::create(options.wav_filepath.as_deref(), options.play_audio)?;
+    let mut terminal_player = terminal_player::TerminalPlayer::new(
+        chip8,
+        &options,
+        key_map_config.key_map_for(&program),
+        audio_sinks,
+    );
+
+    let result = if let Some(ref movie_filepath) = options.play_filepath {
+        let movie = Movie::parse(&read_file(movie_filepath)?)
+            .map_err(|e| format!("Error in {}: {}", movie_filepath, e))?;
+        terminal_player
+            .run_playback(&program, &movie)
+            .map_err(|e| format!("Failed to play movie {}: {}", movie_filepath, e))
+    } else if let Some(ref movie_filepath) = options.record_filepath {
+        let file = File::create(movie_filepath)
+            .map_err(|e| format!("Error writing {}: {}", movie_filepath, e))?;
+        terminal_player
+            .run_recording(&program, &mut BufWriter::new(file))
+            .map_err(|e| format!("Failed to record movie {}: {}", movie_filepath, e))
+    } else {
+        terminal_player.run(&program)
+    };
 
-    let mut logger: Logger = logger::from_env_args();
+    if let Some((trace_buffer, mut file)) = trace_dump {
+        writeln!(file, "Trace dump: exited")
+            .and_then(|_| trace_buffer.dump(&mut file))
+            .map_err(|e| format!("Error writing trace dump: {}", e))?;
+    }
+    result
+}
+
+/// @return the logger for the log file options, and if a trace dump was requested, the
+/// trace buffer with the file to dump it to on exit.
+fn create_logger(options: &Options) -> Result<(Logger, Option<(TraceBuffer, File)>), String> {
+    let logger = match options.log_filepath {
+        Some(ref log_filepath) => {
+            let logger = if options.log_json {
+                logger::open_json_log_file(log_filepath)
+            } else {
+                logger::open_log_file(log_filepath)
+            };
+            let mut logger =
+                logger.map_err(|e| format!("Error opening log file {}: {}", log_filepath, e))?;
+            logger.set_level(options.log_level);
+            logger.set_categories(options.log_categories);
+            logger
+        }
+        None => Logger::new_null_logger(),
+    };
+
+    let Some(ref trace_dump_filepath) = options.trace_dump_filepath else {
+        return Ok((logger, None));
+    };
+    let open_error = |e: std::io::Error| format!("Error writing {}: {}", trace_dump_filepath, e);
+    let file = File::create(trace_dump_filepath).map_err(open_error)?;
+    let (ring_buffer, trace_buffer) = RingBufferLogSource::new(
+        options.trace_buffer as usize,
+        Some(Box::new(file.try_clone().map_err(open_error)?)),
+    );
+    // The ring buffer needs every instruction, so the log file's level and categories move
+    // to a filter in front of it.
+    let mut log_sources: Vec<Box<dyn LogSource>> = vec![Box::new(ring_buffer)];
+    if options.log_filepath.is_some() {
+        log_sources.push(Box::new(FilterLogSource::new(
+            logger.into_log_source(),
+            options.log_level,
+            options.log_categories,
+        )));
+    }
+    let logger = Logger::new(Box::new(TeeLogSource::new(log_sources)));
+    Ok((logger, Some((trace_buffer, file))))
+}
+
+fn create_chip8(mut logger: Logger, options: &Options) -> Chip8 {
     logger.log("Starting execution");
-    let chip8 = Chip8::new(logger);
-    let mut terminal_player = terminal_player::TerminalPlayer::new(chip8);
-    let filepath = args[1].as_str();
-    let program = fs::read(filepath).expect(format!("Failed to open file: {}", filepath).as_str());
-    terminal_player.run(&program);
+
+    let mut chip8 = Chip8::new(logger);
+    chip8.set_quirks(options.quirks);
+    if let Some(seed) = options.seed {
+        chip8.set_seed(seed);
+    }
+    chip8
 }
@@ -0,0 +1,194 @@
+use chip8::Chip8Display;
+
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::queue;
+use crossterm::style::{Color, Print, ResetColor, SetBackgroundColor, SetForegroundColor};
+use crossterm::terminal::{Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use std::io::Write;
+
+/// How pixels are packed into terminal characters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PixelMode {
+    /// One column by two rows per character, using `▀`, `▄` and `█`.
+    HalfBlock,
+    /// Two columns by four rows per character, using braille dots.
+    Braille,
+}
+
+impl PixelMode {
+    pub const NAMES: [&str; 2] = ["half", "braille"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "half" => Some(PixelMode::HalfBlock),
+            "braille" => Some(PixelMode::Braille),
+            _ => None,
+        }
+    }
+
+    /// @return the width and height in pixels of a character.
+    fn cell_size(self) -> (usize, usize) {
+        match self {
+            PixelMode::HalfBlock => (1, 2),
+            PixelMode::Braille => (2, 4),
+        }
+    }
+
+    /// @return the character for a cell, given whether each of its pixels is lit as
+    /// `pixels[row][column]`.
+    fn to_char(self, pixels: &[[bool; 2]; 4]) -> char {
+        match self {
+            PixelMode::HalfBlock => match (pixels[0][0], pixels[1][0]) {
+                (false, false) => ' ',
+                (true, false) => '▀',
+                (false, true) => '▄',
+                (true, true) => '█',
+            },
+            PixelMode::Braille => {
+                // Braille dots 1-8, numbered down the left column then the right, with
+                // dots 7 and 8 added later along the bottom.
+                const DOTS: [[u32; 2]; 4] =
+                    [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+                let bits = (0..4)
+                    .flat_map(|i| (0..2).map(move |j| (i, j)))
+                    .filter(|(i, j)| pixels[*i][*j])
+                    .fold(0, |bits, (i, j)| bits | DOTS[i][j]);
+                char::from_u32(0x2800 + bits).expect("braille is in range")
+            }
+        }
+    }
+}
+
+/// @return the rows of characters showing the display, with each pixel drawn as a
+/// `scale` by `scale` square.
+pub fn to_cells(display: &Chip8Display, mode: PixelMode, scale: usize) -> Vec<Vec<char>> {
+    let (cell_width, cell_height) = mode.cell_size();
+    let width = display.width * scale;
+    let height = display.height * scale;
+    let get_pixel =
+        |i: usize, j: usize| i < height && j < width && display.get_pixel(i / scale, j / scale);
+    (0..height.div_ceil(cell_height))
+        .map(|row| {
+            (0..width.div_ceil(cell_width))
+                .map(|column| {
+                    let mut pixels = [[false; 2]; 4];
+                    for (i, pixel_row) in pixels.iter_mut().enumerate().take(cell_height) {
+                        for (j, pixel) in pixel_row.iter_mut().enumerate().take(cell_width) {
+                            *pixel = get_pixel(row * cell_height + i, column * cell_width + j);
+                        }
+                    }
+                    mode.to_char(&pixels)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Draws the display on the terminal's alternate screen, rewriting only the characters
+/// that changed since the last frame. The terminal is restored when dropped.
+pub struct Renderer<W: Write> {
+    writer: W,
+    mode: PixelMode,
+    scale: usize,
+    on_color: Option<u32>,
+    off_color: Option<u32>,
+    /// The characters on screen, empty until the first frame is drawn.
+    cells: Vec<Vec<char>>,
+    status: String,
+}
+
+impl<W: Write> Renderer<W> {
+    /// Switch `writer` to the alternate screen. Colors are 0xRRGGBB, or None to use the
+    /// terminal's own.
+    pub fn new(
+        mut writer: W,
+        mode: PixelMode,
+        scale: u32,
+        on_color: Option<u32>,
+        off_color: Option<u32>,
+    ) -> std::io::Result<Self> {
+        queue!(writer, EnterAlternateScreen, Hide)?;
+        writer.flush()?;
+        Ok(Self {
+            writer,
+            mode,
+            scale: scale as usize,
+            on_color,
+            off_color,
+            cells: Vec::new(),
+            status: String::new(),
+        })
+    }
+
+    /// Draw a frame, with a status line below the display.
+    pub fn draw(&mut self, display: &Chip8Display, status: &str) -> std::io::Result<()> {
+        let cells = to_cells(display, self.mode, self.scale);
+        let resized = cells.len() != self.cells.len()
+            || cells.first().map(Vec::len) != self.cells.first().map(Vec::len);
+        if resized {
+            // The first frame, or the program switched resolution.
+            queue!(self.writer, ResetColor, Clear(ClearType::All))?;
+            self.cells = vec![vec!['\0'; cells[0].len()]; cells.len()];
+            self.status.clear();
+        }
+
+        self.set_colors()?;
+        for (row, (new_row, old_row)) in cells.iter().zip(self.cells.iter()).enumerate() {
+            let mut column = 0;
+            while column < new_row.len() {
+                if new_row[column] == old_row[column] {
+                    column += 1;
+                    continue;
+                }
+                // Write the run of changed characters in one go.
+                let start = column;
+                while column < new_row.len() && new_row[column] != old_row[column] {
+                    column += 1;
+                }
+                let run: String = new_row[start..column].iter().collect();
+                queue!(self.writer, MoveTo(start as u16, row as u16), Print(run))?;
+            }
+        }
+        self.cells = cells;
+
+        if status != self.status {
+            queue!(
+                self.writer,
+                ResetColor,
+                MoveTo(0, self.cells.len() as u16),
+                Clear(ClearType::CurrentLine),
+                Print(status)
+            )?;
+            self.status = status.to_string();
+        }
+        self.writer.flush()
+    }
+
+    fn set_colors(&mut self) -> std::io::Result<()> {
+        let to_color = |color: u32| Color::Rgb {
+            r: (color >> 16) as u8,
+            g: (color >> 8) as u8,
+            b: color as u8,
+        };
+        if let Some(color) = self.on_color {
+            queue!(self.writer, SetForegroundColor(to_color(color)))?;
+        }
+        if let Some(color) = self.off_color {
+            queue!(self.writer, SetBackgroundColor(to_color(color)))?;
+        }
+        Ok(())
+    }
+
+    /// @return the writer, for tests to check what was drawn.
+    #[cfg(test)]
+    pub fn get_writer(&mut self) -> &mut W {
+        &mut self.writer
+    }
+}
+
+impl<W: Write> Drop for Renderer<W> {
+    fn drop(&mut self) {
+        let _ = queue!(self.writer, ResetColor, Show, LeaveAlternateScreen);
+        let _ = self.writer.flush();
+    }
+}
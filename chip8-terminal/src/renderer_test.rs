@@ -0,0 +1,95 @@
+use crate::renderer::*;
+
+use chip8::Chip8Display;
+
+fn cells_to_strings(cells: Vec<Vec<char>>) -> Vec<String> {
+    cells.iter().map(|row| row.iter().collect()).collect()
+}
+
+#[test]
+fn test_half_block_cells() {
+    let data: [u8; 4] = [0b1010_0000, 0b1100_0000, 0b1000_0000, 0b0000_0000];
+    let display = Chip8Display::new(&data, 8, 4);
+    assert_eq!(
+        vec!["█▄▀     ", "▀       "],
+        cells_to_strings(to_cells(&display, PixelMode::HalfBlock, 1))
+    );
+}
+
+#[test]
+fn test_braille_cells() {
+    let data: [u8; 4] = [0b1000_0001, 0b0100_0000, 0b0010_0000, 0b0001_0001];
+    let display = Chip8Display::new(&data, 8, 4);
+    assert_eq!(
+        vec!["⠑⢄⠀⢈"],
+        cells_to_strings(to_cells(&display, PixelMode::Braille, 1))
+    );
+}
+
+#[test]
+fn test_scaled_cells() {
+    let data: [u8; 1] = [0b1000_0000];
+    let display = Chip8Display::new(&data, 8, 1);
+    let cells = cells_to_strings(to_cells(&display, PixelMode::HalfBlock, 2));
+    assert_eq!(vec!["██              "], cells);
+}
+
+#[test]
+fn test_odd_height_pads_the_last_row() {
+    let data: [u8; 3] = [0x00, 0x00, 0xFF];
+    let display = Chip8Display::new(&data, 8, 3);
+    assert_eq!(
+        vec!["        ", "▀▀▀▀▀▀▀▀"],
+        cells_to_strings(to_cells(&display, PixelMode::HalfBlock, 1))
+    );
+}
+
+#[test]
+fn test_pixel_mode_from_name() {
+    for name in PixelMode::NAMES {
+        assert!(PixelMode::from_name(name).is_some(), "{}", name);
+    }
+    assert_eq!(None, PixelMode::from_name("ascii"));
+}
+
+#[test]
+fn test_redraws_only_changed_cells() {
+    let mut renderer =
+        Renderer::new(Vec::new(), PixelMode::HalfBlock, 1, Some(0xFFFFFF), None).unwrap();
+    let mut data: [u8; 2] = [0x00, 0x00];
+    renderer
+        .draw(&Chip8Display::new(&data, 8, 2), "status")
+        .unwrap();
+    let first_frame = String::from_utf8(renderer.get_writer().split_off(0)).unwrap();
+    assert!(first_frame.contains("        "), "{:?}", first_frame);
+    assert!(first_frame.contains("status"), "{:?}", first_frame);
+
+    data[1] = 0b0001_1000;
+    renderer
+        .draw(&Chip8Display::new(&data, 8, 2), "status")
+        .unwrap();
+    let second_frame = String::from_utf8(renderer.get_writer().split_off(0)).unwrap();
+    // Move to row 1, column 4 and write the two changed cells, after setting the color.
+    assert_eq!("\x1b[38;2;255;255;255m\x1b[1;4H▄▄", second_frame);
+
+    renderer
+        .draw(&Chip8Display::new(&data, 8, 2), "status")
+        .unwrap();
+    let third_frame = String::from_utf8(renderer.get_writer().split_off(0)).unwrap();
+    assert_eq!("\x1b[38;2;255;255;255m", third_frame);
+}
+
+#[test]
+fn test_resolution_change_clears_the_screen() {
+    let mut renderer = Renderer::new(Vec::new(), PixelMode::HalfBlock, 1, None, None).unwrap();
+    renderer
+        .draw(&Chip8Display::new(&[0x00; 256], 64, 32), "")
+        .unwrap();
+    renderer.get_writer().clear();
+    renderer
+        .draw(&Chip8Display::new(&[0x00; 1024], 128, 64), "")
+        .unwrap();
+    let frame = String::from_utf8(renderer.get_writer().split_off(0)).unwrap();
+    assert!(frame.contains("\x1b[2J"), "{:?}", frame);
+    assert_eq!(128 * 32, frame.matches(' ').count());
+}
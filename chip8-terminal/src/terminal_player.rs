@@ -1,98 +1,202 @@
-use device_query::DeviceQuery;
-use device_query::DeviceState;
-use device_query::Keycode;
 use itertools::Itertools;
+use std::io::{self, Write};
 use std::thread;
 use std::time::Duration;
 use std::time::Instant;
 
+use crate::audio_sink::AudioSink;
+use crate::cli::Options;
+use crate::input;
+use crate::key_map::KeyMap;
+use crate::renderer::{Renderer, to_cells};
+use chip8::audio::AudioRenderer;
+use chip8::movie::{Movie, MovieHeader, write_frame};
 use chip8::*;
 
 pub struct TerminalPlayer {
     chip8: Chip8,
-    device_state: DeviceState,
-    prev_output: String,
+    key_map: KeyMap,
+    options: Options,
+    audio_renderer: AudioRenderer,
+    audio_sinks: Vec<Box<dyn AudioSink>>,
+    audio_buffer: Vec<f32>,
 }
 
-const THROTTLE_MICROS: u32 = 10000;
 const TICK_MICROS: u32 = 33333;
 
-const KEY_MAP: [(Keycode, Chip8Key); 16] = [
-    (Keycode::Key1, Chip8Key::_0),
-    (Keycode::Key2, Chip8Key::_1),
-    (Keycode::Key3, Chip8Key::_2),
-    (Keycode::Key4, Chip8Key::_3),
-    (Keycode::Q, Chip8Key::_4),
-    (Keycode::W, Chip8Key::_5),
-    (Keycode::E, Chip8Key::_6),
-    (Keycode::R, Chip8Key::_7),
-    (Keycode::A, Chip8Key::_8),
-    (Keycode::S, Chip8Key::_9),
-    (Keycode::D, Chip8Key::_A),
-    (Keycode::F, Chip8Key::_B),
-    (Keycode::Z, Chip8Key::_C),
-    (Keycode::X, Chip8Key::_D),
-    (Keycode::C, Chip8Key::_E),
-    (Keycode::V, Chip8Key::_F),
-];
-
 impl TerminalPlayer {
-    pub fn new(chip8: Chip8) -> Self {
+    /// Create a player for the options' instructions per frame, frame limit, display, input
+    /// and mode.
+    pub fn new(
+        chip8: Chip8,
+        options: &Options,
+        key_map: KeyMap,
+        audio_sinks: Vec<Box<dyn AudioSink>>,
+    ) -> Self {
+        let audio_renderer = AudioRenderer::new(crate::audio_sink::SAMPLE_RATE);
+        let audio_buffer = vec![0.0; audio_renderer.samples_for_micros(TICK_MICROS)];
         Self {
             chip8,
-            device_state: DeviceState::new(),
-            prev_output: String::new(),
+            key_map,
+            options: options.clone(),
+            audio_renderer,
+            audio_sinks,
+            audio_buffer,
         }
     }
 
-    pub fn run(&mut self, program: &[u8]) {
+    pub fn run(&mut self, program: &[u8]) -> Result<(), String> {
+        self.chip8.initialize(program);
+        self.run_frames(self.options.instructions_per_frame, Some)
+    }
+
+    /// Run, recording the keys held during each frame to `writer` as a movie.
+    pub fn run_recording(&mut self, program: &[u8], writer: &mut dyn Write) -> Result<(), String> {
+        let write_error = |e: io::Error| format!("Error writing the movie: {}", e);
         self.chip8.initialize(program);
+        let instructions_per_frame = self.options.instructions_per_frame;
+        let header = MovieHeader::new(&self.chip8, program, instructions_per_frame);
+        header.write_to(writer).map_err(write_error)?;
+        let mut result = Ok(());
+        self.run_frames(instructions_per_frame, |keys| {
+            // Flush every frame so the movie survives the player being killed.
+            result = write_frame(writer, keys).and_then(|_| writer.flush());
+            result.is_ok().then_some(keys)
+        })?;
+        result.map_err(write_error)
+    }
+
+    /// Replay a movie, ignoring the keyboard, until the movie ends or the program exits.
+    pub fn run_playback(&mut self, program: &[u8], movie: &Movie) -> Result<(), String> {
+        movie.header.start_playback(&mut self.chip8, program)?;
+        let mut frames = movie.frames.iter();
+        self.run_frames(movie.header.instructions_per_frame, |_| {
+            frames.next().copied()
+        })
+    }
+
+    /// Run one frame per tick until `get_keys` returns None, the frame limit is reached, the
+    /// user quits or the program exits, then print the final display. `get_keys` is given
+    /// the keys held on the keyboard and returns the keys to run the frame with.
+    ///
+    /// Headless mode runs as fast as possible without reading the keyboard or drawing
+    /// each frame.
+    fn run_frames(
+        &mut self,
+        instructions_per_frame: u32,
+        mut get_keys: impl FnMut(Chip8Keys) -> Option<Chip8Keys>,
+    ) -> Result<(), String> {
+        let draw_error = |e: io::Error| format!("Error drawing the display: {}", e);
+        let mut renderer = match self.options.headless {
+            true => None,
+            false => Some(
+                Renderer::new(
+                    io::stdout(),
+                    self.options.pixels,
+                    self.options.scale,
+                    self.options.on_color,
+                    self.options.off_color,
+                )
+                .map_err(draw_error)?,
+            ),
+        };
+        let release_timeout = Duration::from_millis(self.options.key_release_ms.into());
+        let mut input = match self.options.headless {
+            true => None,
+            false => Some(input::create(self.options.input, release_timeout)?),
+        };
 
-        let mut last_frame = Instant::now();
-        let mut last_throttle = Instant::now();
-        while !self.chip8.run_next_instruction() {
-            let elapsed_micros_throttle = last_throttle.elapsed().subsec_micros();
-            if elapsed_micros_throttle >= THROTTLE_MICROS {
-                // Yield thread and immeidately continue.
-                thread::sleep(Duration::from_nanos(1));
-                last_throttle += Duration::from_micros(THROTTLE_MICROS.into());
+        let mut next_frame = Instant::now();
+        let mut frame: u32 = 0;
+        while self.options.frames.is_none_or(|frames| frame < frames) {
+            let held_keys = match input {
+                Some(ref mut input) => {
+                    let host_keys = input
+                        .poll()
+                        .map_err(|e| format!("Error reading the keyboard: {}", e))?;
+                    let Some(host_keys) = host_keys else {
+                        break;
+                    };
+                    self.key_map.keys_pressed(host_keys)
+                }
+                None => 0,
+            };
+            let Some(keys) = get_keys(held_keys) else {
+                break;
+            };
+            frame += 1;
+            self.chip8.set_keys(keys);
+            self.play_audio();
+            let exit = self.chip8.run_frame(instructions_per_frame);
+            if exit {
+                break;
             }
-            let elapsed_micros_frame = last_frame.elapsed().subsec_micros();
-            if elapsed_micros_frame >= TICK_MICROS {
-                self.chip8.tick_timers();
-                self.chip8.set_keys(self.get_keys_pressed());
-                self.print_display();
-                last_frame += Duration::from_micros(TICK_MICROS.into());
+            let Some(ref mut renderer) = renderer else {
+                continue;
+            };
+            let status = if self.options.debug {
+                self.debug_state()
+            } else {
+                String::new()
+            };
+            renderer
+                .draw(&self.chip8.get_display(), &status)
+                .map_err(draw_error)?;
+            next_frame += Duration::from_micros(TICK_MICROS.into());
+            if let Some(wait) = next_frame.checked_duration_since(Instant::now()) {
+                thread::sleep(wait);
             }
         }
+
+        // Leave raw mode and the alternate screen so the final display stays in the terminal.
+        drop(input);
+        drop(renderer);
         self.print_display();
+        Ok(())
     }
 
-    fn print_display(&mut self) {
-        let display = self.chip8.get_display();
-        let output = (0..display.height)
-            .map(|i| {
-                (0..display.width)
-                    .map(|j| display.get_pixel(i, j))
-                    .map(|set| if set { '█' } else { '⠀' })
-                    .collect::<String>()
-            })
-            .chain(["\n".to_string()])
-            .join("\n");
-        if output == self.prev_output {
-            return;
+    fn print_display(&self) {
+        let cells = to_cells(
+            &self.chip8.get_display(),
+            self.options.pixels,
+            self.options.scale as usize,
+        );
+        for row in cells {
+            println!("{}", row.iter().collect::<String>());
+        }
+        if self.options.debug {
+            println!("{}", self.debug_state());
         }
-        println!("{}", output);
-        self.prev_output = output;
     }
 
-    fn get_keys_pressed(&self) -> Chip8Keys {
-        let keys: Vec<Keycode> = self.device_state.get_keys();
-        return KEY_MAP
+    /// @return the registers and timers, shown below the display in debug mode.
+    fn debug_state(&self) -> String {
+        let registers = self
+            .chip8
+            .get_registers()
             .iter()
-            .filter(|(keycode, _)| keys.contains(keycode))
-            .map(|(_, key)| *key as u16)
-            .reduce(|acc, e| acc | e)
-            .unwrap_or(0);
+            .map(|register| format!("{:02x}", register))
+            .join(" ");
+        format!(
+            "PC {:03x}  I {:03x}  SP {}  DT {:02x}  ST {:02x}  V {}",
+            self.chip8.get_program_counter(),
+            self.chip8.get_pointer(),
+            self.chip8.get_stack_pointer(),
+            self.chip8.get_delay_timer(),
+            self.chip8.get_sound_timer(),
+            registers
+        )
+    }
+
+    /// Render one tick of audio from the sound timer and pass it to the sinks.
+    fn play_audio(&mut self) {
+        if self.audio_sinks.is_empty() {
+            return;
+        }
+        self.audio_renderer
+            .render(self.chip8.has_sound(), &mut self.audio_buffer);
+        for sink in self.audio_sinks.iter_mut() {
+            sink.write(&self.audio_buffer);
+        }
     }
 }
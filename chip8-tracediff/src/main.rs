@@ -0,0 +1,184 @@
+#[cfg(test)]
+mod main_test;
+
+use chip8::trace_file::{TRACE_FIELDS, TraceDiff, TraceFile, diff_traces};
+
+use std::env;
+use std::fs;
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage: chip8-tracediff [--context=N] [--ignore=FIELDS] EXPECTED ACTUAL
+
+Compares two trace files, such as one from `chip8-headless --trace` and one exported from a
+reference emulator, and reports the first instruction where they differ. The traces are
+aligned first, so one may start later than the other. See chip8::trace_file for the format.
+  --context=N      Lines to show before and after the divergence. Defaults to 5.
+  --ignore=FIELDS  Comma separated fields to skip, such as dt,st when the emulators tick
+                   timers at different times.
+
+Exits with 0 if the traces match until one ends, 1 if they diverge, and 2 on errors.";
+
+const DEFAULT_CONTEXT: usize = 5;
+const EXIT_MATCH: u8 = 0;
+const EXIT_DIVERGED: u8 = 1;
+const EXIT_ERROR: u8 = 2;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Options {
+    expected_filepath: String,
+    actual_filepath: String,
+    context: usize,
+    ignore: Vec<&'static str>,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let read_file = |filepath: &str| {
+        fs::read_to_string(filepath).map_err(|e| format!("Error reading {}: {}", filepath, e))
+    };
+    ExitCode::from(run(&args, read_file))
+}
+
+/// Compare the traces named on the command line and print the report.
+/// @return the exit code described in `USAGE`.
+fn run(args: &[String], read_file: impl Fn(&str) -> Result<String, String>) -> u8 {
+    let options = match parse_args(args) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return EXIT_MATCH;
+        }
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            return EXIT_ERROR;
+        }
+    };
+
+    match compare(&options, read_file) {
+        Ok((report, matches)) => {
+            print!("{}", report);
+            if matches { EXIT_MATCH } else { EXIT_DIVERGED }
+        }
+        Err(message) => {
+            eprintln!("{}", message);
+            EXIT_ERROR
+        }
+    }
+}
+
+/// @return None if help was requested.
+fn parse_args(args: &[String]) -> Result<Option<Options>, String> {
+    let mut filepaths = Vec::new();
+    let mut context = DEFAULT_CONTEXT;
+    let mut ignore = Vec::new();
+    for arg in args {
+        match arg.split_once('=') {
+            _ if arg == "--help" => return Ok(None),
+            Some(("--context", value)) => {
+                context = value
+                    .parse()
+                    .map_err(|_| format!("Invalid context: {}", value))?
+            }
+            Some(("--ignore", value)) => {
+                for name in value.split(',') {
+                    let (field, _) = TRACE_FIELDS
+                        .iter()
+                        .find(|(field, _)| *field == name.trim())
+                        .ok_or(format!("Unknown field: {}", name))?;
+                    ignore.push(*field);
+                }
+            }
+            _ if arg.starts_with("--") => return Err(format!("Unknown flag: {}", arg)),
+            _ => filepaths.push(arg.clone()),
+        }
+    }
+
+    let [expected_filepath, actual_filepath]: [String; 2] = filepaths
+        .try_into()
+        .map_err(|_| "Expected two trace files.")?;
+    Ok(Some(Options {
+        expected_filepath,
+        actual_filepath,
+        context,
+        ignore,
+    }))
+}
+
+/// @return the report, and true if the traces match.
+fn compare(
+    options: &Options,
+    read_file: impl Fn(&str) -> Result<String, String>,
+) -> Result<(String, bool), String> {
+    let read_trace = |filepath: &str| {
+        TraceFile::parse(&read_file(filepath)?).map_err(|e| format!("Error in {}: {}", filepath, e))
+    };
+    let expected = read_trace(&options.expected_filepath)?;
+    let actual = read_trace(&options.actual_filepath)?;
+
+    let diff = diff_traces(&expected.lines, &actual.lines, &options.ignore);
+    let report = describe(&diff, &expected, &actual, options.context);
+    Ok((report, diff.divergence.is_none()))
+}
+
+/// @return a report of where the traces were aligned and where they first differ, with
+/// the lines around it.
+fn describe(diff: &TraceDiff, expected: &TraceFile, actual: &TraceFile, context: usize) -> String {
+    let mut report = String::new();
+    if diff.expected_start > 0 || diff.actual_start > 0 {
+        report += &format!(
+            "Aligned expected line {} with actual line {}.\n",
+            expected.line_nums[diff.expected_start], actual.line_nums[diff.actual_start]
+        );
+    }
+
+    let Some(ref divergence) = diff.divergence else {
+        let expected_rest = expected.lines.len() - diff.expected_start - diff.matched;
+        let actual_rest = actual.lines.len() - diff.actual_start - diff.matched;
+        report += &format!("Traces match for {} instructions.\n", diff.matched);
+        if expected_rest > 0 {
+            report += &format!("Expected has {} more.\n", expected_rest);
+        }
+        if actual_rest > 0 {
+            report += &format!("Actual has {} more.\n", actual_rest);
+        }
+        return report;
+    };
+
+    let (i, j) = (divergence.expected_index, divergence.actual_index);
+    let fields: Vec<String> = divergence
+        .fields
+        .iter()
+        .map(|field| {
+            format!(
+                "{} expected {:x}, actual {:x}",
+                field,
+                expected.lines[i].get(field).unwrap_or_default(),
+                actual.lines[j].get(field).unwrap_or_default()
+            )
+        })
+        .collect();
+    report += &format!(
+        "Traces diverge after {} matching instructions, at expected line {} and actual line {}:\n  {}\n\n",
+        diff.matched,
+        expected.line_nums[i],
+        actual.line_nums[j],
+        fields.join("\n  ")
+    );
+
+    let line = |marker: char, trace: &TraceFile, index: usize| {
+        format!(
+            "{} {:6}  {}\n",
+            marker, trace.line_nums[index], trace.lines[index]
+        )
+    };
+    for k in i.saturating_sub(context.min(diff.matched))..i {
+        report += &line(' ', expected, k);
+    }
+    for k in i..(i + context + 1).min(expected.lines.len()) {
+        report += &line('-', expected, k);
+    }
+    for k in j..(j + context + 1).min(actual.lines.len()) {
+        report += &line('+', actual, k);
+    }
+    report
+}
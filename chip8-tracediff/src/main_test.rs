@@ -0,0 +1,194 @@
+use crate::*;
+
+fn args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+}
+
+/// @return a trace of `count` instructions from 0x200, with V0 counting them.
+fn trace(count: u16) -> String {
+    (0..count)
+        .map(|i| format!("pc={:04x} v0={:02x}\n", 0x200 + i * 2, i))
+        .collect()
+}
+
+/// @return `trace` with V0 wrong at the instruction `index`.
+fn diverging_trace(count: u16, index: u16) -> String {
+    trace(count).replace(
+        &format!("pc={:04x} v0={:02x}", 0x200 + index * 2, index),
+        &format!("pc={:04x} v0=ff", 0x200 + index * 2),
+    )
+}
+
+fn read_file(filepath: &str) -> Result<String, String> {
+    match filepath {
+        "expected.trace" => Ok(trace(10)),
+        "same.trace" => Ok(trace(10)),
+        "diverging.trace" => Ok(diverging_trace(10, 6)),
+        "invalid.trace" => Ok("op=6001\n".to_string()),
+        _ => Err(format!("Error reading {}: not found", filepath)),
+    }
+}
+
+fn report(expected: &str, actual: &str, context: usize) -> String {
+    let options = Options {
+        expected_filepath: "expected".to_string(),
+        actual_filepath: "actual".to_string(),
+        context,
+        ignore: Vec::new(),
+    };
+    let read_file = |filepath: &str| match filepath {
+        "expected" => Ok(expected.to_string()),
+        _ => Ok(actual.to_string()),
+    };
+    compare(&options, read_file).unwrap().0
+}
+
+#[test]
+fn test_parse_args() {
+    let options = parse_args(&args(&["a.trace", "b.trace"])).unwrap().unwrap();
+    assert_eq!(
+        Options {
+            expected_filepath: "a.trace".to_string(),
+            actual_filepath: "b.trace".to_string(),
+            context: DEFAULT_CONTEXT,
+            ignore: Vec::new(),
+        },
+        options
+    );
+
+    let options = parse_args(&args(&[
+        "--context=2",
+        "--ignore=dt, st",
+        "a.trace",
+        "b.trace",
+    ]));
+    let options = options.unwrap().unwrap();
+    assert_eq!(2, options.context);
+    assert_eq!(vec!["dt", "st"], options.ignore);
+}
+
+#[test]
+fn test_parse_args_help() {
+    assert_eq!(None, parse_args(&args(&["a.trace", "--help"])).unwrap());
+}
+
+#[test]
+fn test_parse_args_errors() {
+    let error = |command_line: &[&str]| parse_args(&args(command_line)).unwrap_err();
+    assert_eq!("Expected two trace files.", error(&[]));
+    assert_eq!("Expected two trace files.", error(&["a.trace"]));
+    assert_eq!(
+        "Expected two trace files.",
+        error(&["a.trace", "b.trace", "c.trace"])
+    );
+    assert_eq!(
+        "Invalid context: -1",
+        error(&["--context=-1", "a.trace", "b.trace"])
+    );
+    assert_eq!(
+        "Unknown field: pc2",
+        error(&["--ignore=pc2", "a.trace", "b.trace"])
+    );
+    assert_eq!(
+        "Unknown flag: --fast",
+        error(&["--fast", "a.trace", "b.trace"])
+    );
+}
+
+#[test]
+fn test_describe_match() {
+    assert_eq!(
+        "Traces match for 10 instructions.\n",
+        report(&trace(10), &trace(10), DEFAULT_CONTEXT)
+    );
+}
+
+#[test]
+fn test_describe_match_with_more_lines() {
+    assert_eq!(
+        "Traces match for 8 instructions.\nExpected has 2 more.\n",
+        report(&trace(10), &trace(8), DEFAULT_CONTEXT)
+    );
+    assert_eq!(
+        "Traces match for 8 instructions.\nActual has 2 more.\n",
+        report(&trace(8), &trace(10), DEFAULT_CONTEXT)
+    );
+}
+
+#[test]
+fn test_describe_alignment() {
+    let actual = format!("pc=0100 v0=00\npc=0102 v0=00\n{}", trace(10));
+    assert_eq!(
+        "Aligned expected line 1 with actual line 3.\nTraces match for 10 instructions.\n",
+        report(&trace(10), &actual, DEFAULT_CONTEXT)
+    );
+}
+
+#[test]
+fn test_describe_divergence() {
+    assert_eq!(
+        "Traces diverge after 6 matching instructions, at expected line 7 and actual line 7:
+  v0 expected 6, actual ff
+
+       5  pc=0208 v0=04
+       6  pc=020a v0=05
+-      7  pc=020c v0=06
+-      8  pc=020e v0=07
+-      9  pc=0210 v0=08
++      7  pc=020c v0=ff
++      8  pc=020e v0=07
++      9  pc=0210 v0=08
+",
+        report(&trace(10), &diverging_trace(10, 6), 2)
+    );
+}
+
+#[test]
+fn test_describe_divergence_context_is_clipped() {
+    // The context stops at the start and end of the traces.
+    assert_eq!(
+        "Traces diverge after 1 matching instructions, at expected line 2 and actual line 2:
+  v0 expected 1, actual ff
+
+       1  pc=0200 v0=00
+-      2  pc=0202 v0=01
+-      3  pc=0204 v0=02
++      2  pc=0202 v0=ff
++      3  pc=0204 v0=02
+",
+        report(&trace(3), &diverging_trace(3, 1), DEFAULT_CONTEXT)
+    );
+}
+
+#[test]
+fn test_exit_codes() {
+    assert_eq!(0, run(&args(&["expected.trace", "same.trace"]), read_file));
+    assert_eq!(
+        1,
+        run(&args(&["expected.trace", "diverging.trace"]), read_file)
+    );
+    assert_eq!(0, run(&args(&["--help"]), read_file));
+}
+
+#[test]
+fn test_exit_code_on_errors() {
+    assert_eq!(2, run(&args(&["expected.trace"]), read_file));
+    assert_eq!(
+        2,
+        run(&args(&["expected.trace", "missing.trace"]), read_file)
+    );
+    assert_eq!(
+        2,
+        run(&args(&["expected.trace", "invalid.trace"]), read_file)
+    );
+    assert_eq!(
+        "Error in invalid.trace: Line 1: Expected a pc field.",
+        compare(
+            &parse_args(&args(&["expected.trace", "invalid.trace"]))
+                .unwrap()
+                .unwrap(),
+            read_file
+        )
+        .unwrap_err()
+    );
+}
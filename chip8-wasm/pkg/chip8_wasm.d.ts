@@ -1,14 +1,111 @@
 /* tslint:disable */
 /* eslint-disable */
 
+export class WasmAssemblerError {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  /**
+   * The zero based column of the error within its line, if known.
+   */
+  get_column(): number | undefined;
+  get_length(): number | undefined;
+  get_message(): string;
+  /**
+   * The zero based line of the error, if known.
+   */
+  get_line_num(): number | undefined;
+}
+
+export class WasmAssembly {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  get_program(): Uint8Array;
+  take_errors(): WasmAssemblerError[];
+  is_ok(): boolean;
+}
+
 export class WasmChip8 {
   private constructor();
   free(): void;
   [Symbol.dispose](): void;
+  read_memory(addr: number, len: number): Uint8Array;
+  get_register(x: number): number;
+  set_register(x: number, value: number): void;
+  write_memory(addr: number, bytes: Uint8Array): void;
+  /**
+   * @return V0 to VF.
+   */
+  get_registers(): Uint8Array;
+  add_breakpoint(addr: number): boolean;
+  get_breakpoints(): Uint16Array;
+  clear_breakpoints(): void;
+  remove_breakpoint(addr: number): boolean;
+  /**
+   * Set the function called with the breakpoint address whenever `run` stops at a
+   * breakpoint. Pass `undefined` to remove it.
+   */
+  set_on_breakpoint(callback?: Function | null): void;
+  /**
+   * Stop `run` and `step` after an instruction writes over code that has already run.
+   */
+  set_break_on_code_write(enabled: boolean): void;
+  /**
+   * Run up to `num_instructions` instructions, stopping at breakpoints.
+   */
+  run(num_instructions: number): WasmStopReason;
+  /**
+   * Run a single instruction, even if there is a breakpoint at the current address.
+   */
+  step(): WasmStopReason;
+  get_i(): number;
+  set_i(addr: number): void;
+  get_dt(): number;
+  get_pc(): number;
+  get_sp(): number;
+  get_st(): number;
+  set_dt(value: number): void;
+  set_pc(addr: number): void;
+  set_st(value: number): void;
+  /**
+   * @return the return addresses on the stack, oldest first.
+   */
+  get_stack(): Uint16Array;
+  /**
+   * Render `num_samples` mono samples for the current sound timer state, ready to be
+   * copied into an `AudioBuffer` channel.
+   */
+  render_audio(num_samples: number): Float32Array;
+  set_audio_volume(volume: number): void;
+  /**
+   * Match the sample rate of the `AudioContext` the samples are played through.
+   */
+  set_audio_sample_rate(sample_rate: number): void;
+  has_sound(): boolean;
   get_display(): WasmDisplay;
+  /**
+   * Set the colors used by `get_display_rgba`, given as 0xRRGGBBAA.
+   */
+  set_palette(off_color: number, on_color: number): void;
   tick_timers(): void;
   load_program(program: Uint8Array): void;
+  /**
+   * Copy the display into RGBA bytes, row by row, ready to be wrapped in an `ImageData`.
+   */
+  get_display_rgba(): Uint8ClampedArray;
   run_instructions(num_instructions: number): boolean;
+  get_display_width(): number;
+  get_display_height(): number;
+  /**
+   * Copy the display into one byte per pixel, row by row, with 1 for lit pixels.
+   */
+  get_display_indexed(): Uint8Array;
+  /**
+   * A counter that changes whenever the display may have changed. JS can compare it
+   * against the version it last drew to skip unchanged frames.
+   */
+  get_display_version(): number;
   static new(): WasmChip8;
   set_keys(keys: number): void;
 }
@@ -22,23 +119,90 @@ export class WasmDisplay {
   get_width(): number;
 }
 
+export enum WasmStopReason {
+  Breakpoint = 0,
+  InstructionLimit = 1,
+  WaitingForKey = 2,
+  Exited = 3,
+  CodeModified = 4,
+}
+
+/**
+ * Assemble source text into a program that can be passed to `WasmChip8::load_program`.
+ */
+export function assemble(text: string): WasmAssembly;
+
+/**
+ * Disassemble a program loaded at the start of program memory.
+ */
+export function disassemble(program: Uint8Array): string;
+
 export type InitInput = RequestInfo | URL | Response | BufferSource | WebAssembly.Module;
 
 export interface InitOutput {
   readonly memory: WebAssembly.Memory;
+  readonly __wbg_wasmassemblererror_free: (a: number, b: number) => void;
+  readonly __wbg_wasmassembly_free: (a: number, b: number) => void;
+  readonly assemble: (a: number, b: number) => number;
+  readonly disassemble: (a: number, b: number) => [number, number];
+  readonly wasmassemblererror_get_column: (a: number) => number;
+  readonly wasmassemblererror_get_length: (a: number) => number;
+  readonly wasmassemblererror_get_line_num: (a: number) => number;
+  readonly wasmassemblererror_get_message: (a: number) => [number, number];
+  readonly wasmassembly_get_program: (a: number) => [number, number];
+  readonly wasmassembly_is_ok: (a: number) => number;
+  readonly wasmassembly_take_errors: (a: number) => [number, number];
+  readonly wasmchip8_add_breakpoint: (a: number, b: number) => number;
+  readonly wasmchip8_clear_breakpoints: (a: number) => void;
+  readonly wasmchip8_get_breakpoints: (a: number) => [number, number];
+  readonly wasmchip8_get_dt: (a: number) => number;
+  readonly wasmchip8_get_i: (a: number) => number;
+  readonly wasmchip8_get_pc: (a: number) => number;
+  readonly wasmchip8_get_register: (a: number, b: number) => number;
+  readonly wasmchip8_get_registers: (a: number) => [number, number];
+  readonly wasmchip8_get_sp: (a: number) => number;
+  readonly wasmchip8_get_st: (a: number) => number;
+  readonly wasmchip8_get_stack: (a: number) => [number, number];
+  readonly wasmchip8_read_memory: (a: number, b: number, c: number) => [number, number];
+  readonly wasmchip8_remove_breakpoint: (a: number, b: number) => number;
+  readonly wasmchip8_run: (a: number, b: number) => number;
+  readonly wasmchip8_set_break_on_code_write: (a: number, b: number) => void;
+  readonly wasmchip8_set_dt: (a: number, b: number) => void;
+  readonly wasmchip8_set_i: (a: number, b: number) => void;
+  readonly wasmchip8_set_on_breakpoint: (a: number, b: number) => void;
+  readonly wasmchip8_set_pc: (a: number, b: number) => void;
+  readonly wasmchip8_set_register: (a: number, b: number, c: number) => void;
+  readonly wasmchip8_set_st: (a: number, b: number) => void;
+  readonly wasmchip8_step: (a: number) => number;
+  readonly wasmchip8_write_memory: (a: number, b: number, c: number, d: number) => void;
+  readonly wasmchip8_has_sound: (a: number) => number;
+  readonly wasmchip8_render_audio: (a: number, b: number) => [number, number];
+  readonly wasmchip8_set_audio_sample_rate: (a: number, b: number) => void;
+  readonly wasmchip8_set_audio_volume: (a: number, b: number) => void;
   readonly __wbg_wasmchip8_free: (a: number, b: number) => void;
   readonly __wbg_wasmdisplay_free: (a: number, b: number) => void;
   readonly wasmchip8_get_display: (a: number) => number;
+  readonly wasmchip8_get_display_height: (a: number) => number;
+  readonly wasmchip8_get_display_indexed: (a: number) => [number, number];
+  readonly wasmchip8_get_display_rgba: (a: number) => [number, number];
+  readonly wasmchip8_get_display_version: (a: number) => number;
+  readonly wasmchip8_get_display_width: (a: number) => number;
   readonly wasmchip8_load_program: (a: number, b: number, c: number) => void;
   readonly wasmchip8_new: () => number;
   readonly wasmchip8_run_instructions: (a: number, b: number) => number;
   readonly wasmchip8_set_keys: (a: number, b: number) => void;
+  readonly wasmchip8_set_palette: (a: number, b: number, c: number) => void;
   readonly wasmchip8_tick_timers: (a: number) => void;
   readonly wasmdisplay_get_height: (a: number) => number;
   readonly wasmdisplay_get_pixel: (a: number, b: number, c: number) => number;
   readonly wasmdisplay_get_width: (a: number) => number;
+  readonly __wbindgen_exn_store: (a: number) => void;
+  readonly __externref_table_alloc: () => number;
   readonly __wbindgen_externrefs: WebAssembly.Table;
+  readonly __wbindgen_free: (a: number, b: number, c: number) => void;
   readonly __wbindgen_malloc: (a: number, b: number) => number;
+  readonly __externref_drop_slice: (a: number, b: number) => void;
+  readonly __wbindgen_realloc: (a: number, b: number, c: number, d: number) => number;
   readonly __wbindgen_start: () => void;
 }
 
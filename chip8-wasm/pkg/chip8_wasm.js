@@ -1,10 +1,71 @@
 let wasm;
 
+function addToExternrefTable0(obj) {
+    const idx = wasm.__externref_table_alloc();
+    wasm.__wbindgen_externrefs.set(idx, obj);
+    return idx;
+}
+
+function getArrayF32FromWasm0(ptr, len) {
+    ptr = ptr >>> 0;
+    return getFloat32ArrayMemory0().subarray(ptr / 4, ptr / 4 + len);
+}
+
+function getArrayJsValueFromWasm0(ptr, len) {
+    ptr = ptr >>> 0;
+    const mem = getDataViewMemory0();
+    const result = [];
+    for (let i = ptr; i < ptr + 4 * len; i += 4) {
+        result.push(wasm.__wbindgen_externrefs.get(mem.getUint32(i, true)));
+    }
+    wasm.__externref_drop_slice(ptr, len);
+    return result;
+}
+
+function getArrayU16FromWasm0(ptr, len) {
+    ptr = ptr >>> 0;
+    return getUint16ArrayMemory0().subarray(ptr / 2, ptr / 2 + len);
+}
+
+function getArrayU8FromWasm0(ptr, len) {
+    ptr = ptr >>> 0;
+    return getUint8ArrayMemory0().subarray(ptr / 1, ptr / 1 + len);
+}
+
+function getClampedArrayU8FromWasm0(ptr, len) {
+    ptr = ptr >>> 0;
+    return getUint8ClampedArrayMemory0().subarray(ptr / 1, ptr / 1 + len);
+}
+
+let cachedDataViewMemory0 = null;
+function getDataViewMemory0() {
+    if (cachedDataViewMemory0 === null || cachedDataViewMemory0.buffer.detached === true || (cachedDataViewMemory0.buffer.detached === undefined && cachedDataViewMemory0.buffer !== wasm.memory.buffer)) {
+        cachedDataViewMemory0 = new DataView(wasm.memory.buffer);
+    }
+    return cachedDataViewMemory0;
+}
+
+let cachedFloat32ArrayMemory0 = null;
+function getFloat32ArrayMemory0() {
+    if (cachedFloat32ArrayMemory0 === null || cachedFloat32ArrayMemory0.byteLength === 0) {
+        cachedFloat32ArrayMemory0 = new Float32Array(wasm.memory.buffer);
+    }
+    return cachedFloat32ArrayMemory0;
+}
+
 function getStringFromWasm0(ptr, len) {
     ptr = ptr >>> 0;
     return decodeText(ptr, len);
 }
 
+let cachedUint16ArrayMemory0 = null;
+function getUint16ArrayMemory0() {
+    if (cachedUint16ArrayMemory0 === null || cachedUint16ArrayMemory0.byteLength === 0) {
+        cachedUint16ArrayMemory0 = new Uint16Array(wasm.memory.buffer);
+    }
+    return cachedUint16ArrayMemory0;
+}
+
 let cachedUint8ArrayMemory0 = null;
 function getUint8ArrayMemory0() {
     if (cachedUint8ArrayMemory0 === null || cachedUint8ArrayMemory0.byteLength === 0) {
@@ -13,6 +74,27 @@ function getUint8ArrayMemory0() {
     return cachedUint8ArrayMemory0;
 }
 
+let cachedUint8ClampedArrayMemory0 = null;
+function getUint8ClampedArrayMemory0() {
+    if (cachedUint8ClampedArrayMemory0 === null || cachedUint8ClampedArrayMemory0.byteLength === 0) {
+        cachedUint8ClampedArrayMemory0 = new Uint8ClampedArray(wasm.memory.buffer);
+    }
+    return cachedUint8ClampedArrayMemory0;
+}
+
+function handleError(f, args) {
+    try {
+        return f.apply(this, args);
+    } catch (e) {
+        const idx = addToExternrefTable0(e);
+        wasm.__wbindgen_exn_store(idx);
+    }
+}
+
+function isLikeNone(x) {
+    return x === undefined || x === null;
+}
+
 function passArray8ToWasm0(arg, malloc) {
     const ptr = malloc(arg.length * 1, 1) >>> 0;
     getUint8ArrayMemory0().set(arg, ptr / 1);
@@ -20,6 +102,43 @@ function passArray8ToWasm0(arg, malloc) {
     return ptr;
 }
 
+function passStringToWasm0(arg, malloc, realloc) {
+    if (realloc === undefined) {
+        const buf = cachedTextEncoder.encode(arg);
+        const ptr = malloc(buf.length, 1) >>> 0;
+        getUint8ArrayMemory0().subarray(ptr, ptr + buf.length).set(buf);
+        WASM_VECTOR_LEN = buf.length;
+        return ptr;
+    }
+
+    let len = arg.length;
+    let ptr = malloc(len, 1) >>> 0;
+
+    const mem = getUint8ArrayMemory0();
+
+    let offset = 0;
+
+    for (; offset < len; offset++) {
+        const code = arg.charCodeAt(offset);
+        if (code > 0x7F) break;
+        mem[ptr + offset] = code;
+    }
+    if (offset !== len) {
+        if (offset !== 0) {
+            arg = arg.slice(offset);
+        }
+        ptr = realloc(ptr, len, len = offset + arg.length * 3, 1) >>> 0;
+        const view = getUint8ArrayMemory0().subarray(ptr + offset, ptr + len);
+        const ret = cachedTextEncoder.encodeInto(arg, view);
+
+        offset += ret.written;
+        ptr = realloc(ptr, len, offset, 1) >>> 0;
+    }
+
+    WASM_VECTOR_LEN = offset;
+    return ptr;
+}
+
 let cachedTextDecoder = new TextDecoder('utf-8', { ignoreBOM: true, fatal: true });
 cachedTextDecoder.decode();
 const MAX_SAFARI_DECODE_BYTES = 2146435072;
@@ -34,8 +153,29 @@ function decodeText(ptr, len) {
     return cachedTextDecoder.decode(getUint8ArrayMemory0().subarray(ptr, ptr + len));
 }
 
+const cachedTextEncoder = new TextEncoder();
+
+if (!('encodeInto' in cachedTextEncoder)) {
+    cachedTextEncoder.encodeInto = function (arg, view) {
+        const buf = cachedTextEncoder.encode(arg);
+        view.set(buf);
+        return {
+            read: arg.length,
+            written: buf.length
+        };
+    }
+}
+
 let WASM_VECTOR_LEN = 0;
 
+const WasmAssemblerErrorFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_wasmassemblererror_free(ptr >>> 0, 1));
+
+const WasmAssemblyFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_wasmassembly_free(ptr >>> 0, 1));
+
 const WasmChip8Finalization = (typeof FinalizationRegistry === 'undefined')
     ? { register: () => {}, unregister: () => {} }
     : new FinalizationRegistry(ptr => wasm.__wbg_wasmchip8_free(ptr >>> 0, 1));
@@ -44,6 +184,111 @@ const WasmDisplayFinalization = (typeof FinalizationRegistry === 'undefined')
     ? { register: () => {}, unregister: () => {} }
     : new FinalizationRegistry(ptr => wasm.__wbg_wasmdisplay_free(ptr >>> 0, 1));
 
+export class WasmAssemblerError {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(WasmAssemblerError.prototype);
+        obj.__wbg_ptr = ptr;
+        WasmAssemblerErrorFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        WasmAssemblerErrorFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_wasmassemblererror_free(ptr, 0);
+    }
+    /**
+     * The zero based column of the error within its line, if known.
+     * @returns {number | undefined}
+     */
+    get_column() {
+        const ret = wasm.wasmassemblererror_get_column(this.__wbg_ptr);
+        return ret === 0x100000001 ? undefined : ret;
+    }
+    /**
+     * @returns {number | undefined}
+     */
+    get_length() {
+        const ret = wasm.wasmassemblererror_get_length(this.__wbg_ptr);
+        return ret === 0x100000001 ? undefined : ret;
+    }
+    /**
+     * @returns {string}
+     */
+    get_message() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const ret = wasm.wasmassemblererror_get_message(this.__wbg_ptr);
+            deferred1_0 = ret[0];
+            deferred1_1 = ret[1];
+            return getStringFromWasm0(ret[0], ret[1]);
+        } finally {
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+     * The zero based line of the error, if known.
+     * @returns {number | undefined}
+     */
+    get_line_num() {
+        const ret = wasm.wasmassemblererror_get_line_num(this.__wbg_ptr);
+        return ret === 0xFFFFFF ? undefined : ret;
+    }
+}
+if (Symbol.dispose) WasmAssemblerError.prototype[Symbol.dispose] = WasmAssemblerError.prototype.free;
+
+export class WasmAssembly {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(WasmAssembly.prototype);
+        obj.__wbg_ptr = ptr;
+        WasmAssemblyFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        WasmAssemblyFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_wasmassembly_free(ptr, 0);
+    }
+    /**
+     * @returns {Uint8Array}
+     */
+    get_program() {
+        const ret = wasm.wasmassembly_get_program(this.__wbg_ptr);
+        var v1 = getArrayU8FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 1, 1);
+        return v1;
+    }
+    /**
+     * @returns {WasmAssemblerError[]}
+     */
+    take_errors() {
+        const ret = wasm.wasmassembly_take_errors(this.__wbg_ptr);
+        var v1 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * @returns {boolean}
+     */
+    is_ok() {
+        const ret = wasm.wasmassembly_is_ok(this.__wbg_ptr);
+        return ret !== 0;
+    }
+}
+if (Symbol.dispose) WasmAssembly.prototype[Symbol.dispose] = WasmAssembly.prototype.free;
+
 export class WasmChip8 {
     static __wrap(ptr) {
         ptr = ptr >>> 0;
@@ -62,6 +307,212 @@ export class WasmChip8 {
         const ptr = this.__destroy_into_raw();
         wasm.__wbg_wasmchip8_free(ptr, 0);
     }
+    /**
+     * @param {number} addr
+     * @param {number} len
+     * @returns {Uint8Array}
+     */
+    read_memory(addr, len) {
+        const ret = wasm.wasmchip8_read_memory(this.__wbg_ptr, addr, len);
+        var v1 = getArrayU8FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 1, 1);
+        return v1;
+    }
+    /**
+     * @param {number} x
+     * @returns {number}
+     */
+    get_register(x) {
+        const ret = wasm.wasmchip8_get_register(this.__wbg_ptr, x);
+        return ret;
+    }
+    /**
+     * @param {number} x
+     * @param {number} value
+     */
+    set_register(x, value) {
+        wasm.wasmchip8_set_register(this.__wbg_ptr, x, value);
+    }
+    /**
+     * @param {number} addr
+     * @param {Uint8Array} bytes
+     */
+    write_memory(addr, bytes) {
+        const ptr0 = passArray8ToWasm0(bytes, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.wasmchip8_write_memory(this.__wbg_ptr, addr, ptr0, len0);
+    }
+    /**
+     * @return V0 to VF.
+     * @returns {Uint8Array}
+     */
+    get_registers() {
+        const ret = wasm.wasmchip8_get_registers(this.__wbg_ptr);
+        var v1 = getArrayU8FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 1, 1);
+        return v1;
+    }
+    /**
+     * @param {number} addr
+     * @returns {boolean}
+     */
+    add_breakpoint(addr) {
+        const ret = wasm.wasmchip8_add_breakpoint(this.__wbg_ptr, addr);
+        return ret !== 0;
+    }
+    /**
+     * @returns {Uint16Array}
+     */
+    get_breakpoints() {
+        const ret = wasm.wasmchip8_get_breakpoints(this.__wbg_ptr);
+        var v1 = getArrayU16FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 2, 2);
+        return v1;
+    }
+    clear_breakpoints() {
+        wasm.wasmchip8_clear_breakpoints(this.__wbg_ptr);
+    }
+    /**
+     * @param {number} addr
+     * @returns {boolean}
+     */
+    remove_breakpoint(addr) {
+        const ret = wasm.wasmchip8_remove_breakpoint(this.__wbg_ptr, addr);
+        return ret !== 0;
+    }
+    /**
+     * Set the function called with the breakpoint address whenever `run` stops at a
+     * breakpoint. Pass `undefined` to remove it.
+     * @param {Function | null} [callback]
+     */
+    set_on_breakpoint(callback) {
+        wasm.wasmchip8_set_on_breakpoint(this.__wbg_ptr, isLikeNone(callback) ? 0 : addToExternrefTable0(callback));
+    }
+    /**
+     * Stop `run` and `step` after an instruction writes over code that has already run.
+     * @param {boolean} enabled
+     */
+    set_break_on_code_write(enabled) {
+        wasm.wasmchip8_set_break_on_code_write(this.__wbg_ptr, enabled);
+    }
+    /**
+     * Run up to `num_instructions` instructions, stopping at breakpoints.
+     * @param {number} num_instructions
+     * @returns {WasmStopReason}
+     */
+    run(num_instructions) {
+        const ret = wasm.wasmchip8_run(this.__wbg_ptr, num_instructions);
+        return ret;
+    }
+    /**
+     * Run a single instruction, even if there is a breakpoint at the current address.
+     * @returns {WasmStopReason}
+     */
+    step() {
+        const ret = wasm.wasmchip8_step(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @returns {number}
+     */
+    get_i() {
+        const ret = wasm.wasmchip8_get_i(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @param {number} addr
+     */
+    set_i(addr) {
+        wasm.wasmchip8_set_i(this.__wbg_ptr, addr);
+    }
+    /**
+     * @returns {number}
+     */
+    get_dt() {
+        const ret = wasm.wasmchip8_get_dt(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @returns {number}
+     */
+    get_pc() {
+        const ret = wasm.wasmchip8_get_pc(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @returns {number}
+     */
+    get_sp() {
+        const ret = wasm.wasmchip8_get_sp(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {number}
+     */
+    get_st() {
+        const ret = wasm.wasmchip8_get_st(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @param {number} value
+     */
+    set_dt(value) {
+        wasm.wasmchip8_set_dt(this.__wbg_ptr, value);
+    }
+    /**
+     * @param {number} addr
+     */
+    set_pc(addr) {
+        wasm.wasmchip8_set_pc(this.__wbg_ptr, addr);
+    }
+    /**
+     * @param {number} value
+     */
+    set_st(value) {
+        wasm.wasmchip8_set_st(this.__wbg_ptr, value);
+    }
+    /**
+     * @return the return addresses on the stack, oldest first.
+     * @returns {Uint16Array}
+     */
+    get_stack() {
+        const ret = wasm.wasmchip8_get_stack(this.__wbg_ptr);
+        var v1 = getArrayU16FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 2, 2);
+        return v1;
+    }
+    /**
+     * Render `num_samples` mono samples for the current sound timer state, ready to be
+     * copied into an `AudioBuffer` channel.
+     * @param {number} num_samples
+     * @returns {Float32Array}
+     */
+    render_audio(num_samples) {
+        const ret = wasm.wasmchip8_render_audio(this.__wbg_ptr, num_samples);
+        var v1 = getArrayF32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * @param {number} volume
+     */
+    set_audio_volume(volume) {
+        wasm.wasmchip8_set_audio_volume(this.__wbg_ptr, volume);
+    }
+    /**
+     * Match the sample rate of the `AudioContext` the samples are played through.
+     * @param {number} sample_rate
+     */
+    set_audio_sample_rate(sample_rate) {
+        wasm.wasmchip8_set_audio_sample_rate(this.__wbg_ptr, sample_rate);
+    }
+    /**
+     * @returns {boolean}
+     */
+    has_sound() {
+        const ret = wasm.wasmchip8_has_sound(this.__wbg_ptr);
+        return ret !== 0;
+    }
     /**
      * @returns {WasmDisplay}
      */
@@ -69,6 +520,14 @@ export class WasmChip8 {
         const ret = wasm.wasmchip8_get_display(this.__wbg_ptr);
         return WasmDisplay.__wrap(ret);
     }
+    /**
+     * Set the colors used by `get_display_rgba`, given as 0xRRGGBBAA.
+     * @param {number} off_color
+     * @param {number} on_color
+     */
+    set_palette(off_color, on_color) {
+        wasm.wasmchip8_set_palette(this.__wbg_ptr, off_color, on_color);
+    }
     tick_timers() {
         wasm.wasmchip8_tick_timers(this.__wbg_ptr);
     }
@@ -80,6 +539,16 @@ export class WasmChip8 {
         const len0 = WASM_VECTOR_LEN;
         wasm.wasmchip8_load_program(this.__wbg_ptr, ptr0, len0);
     }
+    /**
+     * Copy the display into RGBA bytes, row by row, ready to be wrapped in an `ImageData`.
+     * @returns {Uint8ClampedArray}
+     */
+    get_display_rgba() {
+        const ret = wasm.wasmchip8_get_display_rgba(this.__wbg_ptr);
+        var v1 = getClampedArrayU8FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 1, 1);
+        return v1;
+    }
     /**
      * @param {number} num_instructions
      * @returns {boolean}
@@ -88,6 +557,39 @@ export class WasmChip8 {
         const ret = wasm.wasmchip8_run_instructions(this.__wbg_ptr, num_instructions);
         return ret !== 0;
     }
+    /**
+     * @returns {number}
+     */
+    get_display_width() {
+        const ret = wasm.wasmchip8_get_display_width(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {number}
+     */
+    get_display_height() {
+        const ret = wasm.wasmchip8_get_display_height(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * Copy the display into one byte per pixel, row by row, with 1 for lit pixels.
+     * @returns {Uint8Array}
+     */
+    get_display_indexed() {
+        const ret = wasm.wasmchip8_get_display_indexed(this.__wbg_ptr);
+        var v1 = getArrayU8FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 1, 1);
+        return v1;
+    }
+    /**
+     * A counter that changes whenever the display may have changed. JS can compare it
+     * against the version it last drew to skip unchanged frames.
+     * @returns {number}
+     */
+    get_display_version() {
+        const ret = wasm.wasmchip8_get_display_version(this.__wbg_ptr);
+        return ret >>> 0;
+    }
     /**
      * @returns {WasmChip8}
      */
@@ -104,6 +606,10 @@ export class WasmChip8 {
 }
 if (Symbol.dispose) WasmChip8.prototype[Symbol.dispose] = WasmChip8.prototype.free;
 
+/**
+ * A copy of the display taken when it was requested. It stays valid after the `WasmChip8`
+ * it came from is freed, but does not follow later changes to the display.
+ */
 export class WasmDisplay {
     static __wrap(ptr) {
         ptr = ptr >>> 0;
@@ -148,6 +654,49 @@ export class WasmDisplay {
 }
 if (Symbol.dispose) WasmDisplay.prototype[Symbol.dispose] = WasmDisplay.prototype.free;
 
+/**
+ * @enum {0 | 1 | 2 | 3 | 4}
+ */
+export const WasmStopReason = Object.freeze({
+    Breakpoint: 0, "0": "Breakpoint",
+    InstructionLimit: 1, "1": "InstructionLimit",
+    WaitingForKey: 2, "2": "WaitingForKey",
+    Exited: 3, "3": "Exited",
+    CodeModified: 4, "4": "CodeModified",
+});
+
+/**
+ * Assemble source text into a program that can be passed to `WasmChip8::load_program`.
+ * @param {string} text
+ * @returns {WasmAssembly}
+ */
+export function assemble(text) {
+    const ptr0 = passStringToWasm0(text, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.assemble(ptr0, len0);
+    return WasmAssembly.__wrap(ret);
+}
+
+/**
+ * Disassemble a program loaded at the start of program memory.
+ * @param {Uint8Array} program
+ * @returns {string}
+ */
+export function disassemble(program) {
+    let deferred2_0;
+    let deferred2_1;
+    try {
+        const ptr0 = passArray8ToWasm0(program, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.disassemble(ptr0, len0);
+        deferred2_0 = ret[0];
+        deferred2_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);
+    }
+}
+
 const EXPECTED_RESPONSE_TYPES = new Set(['basic', 'cors', 'default']);
 
 async function __wbg_load(module, imports) {
@@ -186,9 +735,22 @@ function __wbg_get_imports() {
     imports.wbg.__wbg___wbindgen_throw_dd24417ed36fc46e = function(arg0, arg1) {
         throw new Error(getStringFromWasm0(arg0, arg1));
     };
+    imports.wbg.__wbg_call_3020136f7a2d6e44 = function() { return handleError(function (arg0, arg1, arg2) {
+        const ret = arg0.call(arg1, arg2);
+        return ret;
+    }, arguments) };
     imports.wbg.__wbg_log_c0ea3c40c4012bb2 = function(arg0, arg1) {
         console.log(getStringFromWasm0(arg0, arg1));
     };
+    imports.wbg.__wbg_wasmassemblererror_new = function(arg0) {
+        const ret = WasmAssemblerError.__wrap(arg0);
+        return ret;
+    };
+    imports.wbg.__wbindgen_cast_d6cd19b81560fd6e = function(arg0) {
+        // Cast intrinsic for `F64 -> Externref`.
+        const ret = arg0;
+        return ret;
+    };
     imports.wbg.__wbindgen_init_externref_table = function() {
         const table = wasm.__wbindgen_externrefs;
         const offset = table.grow(4);
@@ -205,7 +767,11 @@ function __wbg_get_imports() {
 function __wbg_finalize_init(instance, module) {
     wasm = instance.exports;
     __wbg_init.__wbindgen_wasm_module = module;
+    cachedDataViewMemory0 = null;
+    cachedFloat32ArrayMemory0 = null;
+    cachedUint16ArrayMemory0 = null;
     cachedUint8ArrayMemory0 = null;
+    cachedUint8ClampedArrayMemory0 = null;
 
 
     wasm.__wbindgen_start();
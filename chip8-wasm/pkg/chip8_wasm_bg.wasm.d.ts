@@ -1,17 +1,66 @@
 /* tslint:disable */
 /* eslint-disable */
 export const memory: WebAssembly.Memory;
+export const __wbg_wasmassemblererror_free: (a: number, b: number) => void;
+export const __wbg_wasmassembly_free: (a: number, b: number) => void;
+export const assemble: (a: number, b: number) => number;
+export const disassemble: (a: number, b: number) => [number, number];
+export const wasmassemblererror_get_column: (a: number) => number;
+export const wasmassemblererror_get_length: (a: number) => number;
+export const wasmassemblererror_get_line_num: (a: number) => number;
+export const wasmassemblererror_get_message: (a: number) => [number, number];
+export const wasmassembly_get_program: (a: number) => [number, number];
+export const wasmassembly_is_ok: (a: number) => number;
+export const wasmassembly_take_errors: (a: number) => [number, number];
+export const wasmchip8_add_breakpoint: (a: number, b: number) => number;
+export const wasmchip8_clear_breakpoints: (a: number) => void;
+export const wasmchip8_get_breakpoints: (a: number) => [number, number];
+export const wasmchip8_get_dt: (a: number) => number;
+export const wasmchip8_get_i: (a: number) => number;
+export const wasmchip8_get_pc: (a: number) => number;
+export const wasmchip8_get_register: (a: number, b: number) => number;
+export const wasmchip8_get_registers: (a: number) => [number, number];
+export const wasmchip8_get_sp: (a: number) => number;
+export const wasmchip8_get_st: (a: number) => number;
+export const wasmchip8_get_stack: (a: number) => [number, number];
+export const wasmchip8_read_memory: (a: number, b: number, c: number) => [number, number];
+export const wasmchip8_remove_breakpoint: (a: number, b: number) => number;
+export const wasmchip8_run: (a: number, b: number) => number;
+export const wasmchip8_set_break_on_code_write: (a: number, b: number) => void;
+export const wasmchip8_set_dt: (a: number, b: number) => void;
+export const wasmchip8_set_i: (a: number, b: number) => void;
+export const wasmchip8_set_on_breakpoint: (a: number, b: number) => void;
+export const wasmchip8_set_pc: (a: number, b: number) => void;
+export const wasmchip8_set_register: (a: number, b: number, c: number) => void;
+export const wasmchip8_set_st: (a: number, b: number) => void;
+export const wasmchip8_step: (a: number) => number;
+export const wasmchip8_write_memory: (a: number, b: number, c: number, d: number) => void;
+export const wasmchip8_has_sound: (a: number) => number;
+export const wasmchip8_render_audio: (a: number, b: number) => [number, number];
+export const wasmchip8_set_audio_sample_rate: (a: number, b: number) => void;
+export const wasmchip8_set_audio_volume: (a: number, b: number) => void;
 export const __wbg_wasmchip8_free: (a: number, b: number) => void;
 export const __wbg_wasmdisplay_free: (a: number, b: number) => void;
 export const wasmchip8_get_display: (a: number) => number;
+export const wasmchip8_get_display_height: (a: number) => number;
+export const wasmchip8_get_display_indexed: (a: number) => [number, number];
+export const wasmchip8_get_display_rgba: (a: number) => [number, number];
+export const wasmchip8_get_display_version: (a: number) => number;
+export const wasmchip8_get_display_width: (a: number) => number;
 export const wasmchip8_load_program: (a: number, b: number, c: number) => void;
 export const wasmchip8_new: () => number;
 export const wasmchip8_run_instructions: (a: number, b: number) => number;
 export const wasmchip8_set_keys: (a: number, b: number) => void;
+export const wasmchip8_set_palette: (a: number, b: number, c: number) => void;
 export const wasmchip8_tick_timers: (a: number) => void;
 export const wasmdisplay_get_height: (a: number) => number;
 export const wasmdisplay_get_pixel: (a: number, b: number, c: number) => number;
 export const wasmdisplay_get_width: (a: number) => number;
+export const __wbindgen_exn_store: (a: number) => void;
+export const __externref_table_alloc: () => number;
 export const __wbindgen_externrefs: WebAssembly.Table;
+export const __wbindgen_free: (a: number, b: number, c: number) => void;
 export const __wbindgen_malloc: (a: number, b: number) => number;
+export const __externref_drop_slice: (a: number, b: number) => void;
+export const __wbindgen_realloc: (a: number, b: number, c: number, d: number) => number;
 export const __wbindgen_start: () => void;
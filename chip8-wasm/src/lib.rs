@@ -1,16 +1,30 @@
+mod wasm_assembler;
+mod wasm_audio;
+mod wasm_debugger;
 mod wasm_log_source;
 
+pub use wasm_assembler::{WasmAssemblerError, WasmAssembly, assemble, disassemble};
+pub use wasm_debugger::WasmStopReason;
+
 use chip8::Chip8;
 use chip8::Chip8Display;
 use chip8::Chip8Keys;
+use chip8::audio::AudioRenderer;
+use chip8::debugger::Debugger;
 use chip8::logger::Logger;
 use wasm_log_source::WasmLogSource;
 
+use wasm_bindgen::Clamped;
 use wasm_bindgen::prelude::*;
 
+/// Palette of RGBA colors for unlit and lit pixels.
+const DEFAULT_PALETTE: [[u8; 4]; 2] = [[0x00, 0x00, 0x00, 0xFF], [0xFF, 0xFF, 0xFF, 0xFF]];
+
+/// A copy of the display taken when it was requested. It stays valid after the `WasmChip8`
+/// it came from is freed, but does not follow later changes to the display.
 #[wasm_bindgen]
 pub struct WasmDisplay {
-    data: *const u8,
+    pixels: Vec<u8>,
     width: usize,
     height: usize,
 }
@@ -18,7 +32,7 @@ pub struct WasmDisplay {
 impl WasmDisplay {
     fn new(chip8_display: &Chip8Display) -> Self {
         WasmDisplay {
-            data: chip8_display.data.as_ptr(),
+            pixels: chip8_display.to_palette_indices(),
             width: chip8_display.width,
             height: chip8_display.height,
         }
@@ -39,23 +53,17 @@ impl WasmDisplay {
         if !(x < self.width && y < self.height) {
             return false;
         }
-        let (byte, bit) = get_display_bit(x as u8, y as u8, self.width);
-        // SAFETY: The display data exists for the lifetime of the Chip8 instance. The JS
-        // code is responsible for managing this lifetime.
-        unsafe { *self.data.offset(byte as isize) & (1 << bit) != 0 }
+        self.pixels[y * self.width + x] != 0
     }
 }
 
-fn get_display_bit(x: u8, y: u8, row_size: usize) -> (usize, usize) {
-    let bit = row_size * (y as usize) + (x as usize);
-    let byte_index = bit >> 3;
-    let bit_index = 7 - (bit & 0x7);
-    return (byte_index, bit_index);
-}
-
 #[wasm_bindgen]
 pub struct WasmChip8 {
     chip8: Chip8,
+    debugger: Debugger,
+    on_breakpoint: Option<js_sys::Function>,
+    palette: [[u8; 4]; 2],
+    audio_renderer: AudioRenderer,
 }
 
 #[wasm_bindgen]
@@ -63,7 +71,13 @@ impl WasmChip8 {
     pub fn new() -> Self {
         let logger = Logger::new(Box::new(WasmLogSource::new()));
         let chip8 = Chip8::new(logger);
-        WasmChip8 { chip8 }
+        WasmChip8 {
+            chip8,
+            debugger: Debugger::new(),
+            on_breakpoint: None,
+            palette: DEFAULT_PALETTE,
+            audio_renderer: AudioRenderer::new(wasm_audio::DEFAULT_SAMPLE_RATE),
+        }
     }
 
     pub fn load_program(&mut self, program: &[u8]) {
@@ -91,4 +105,33 @@ impl WasmChip8 {
         let display = self.chip8.get_display();
         WasmDisplay::new(&display)
     }
+
+    pub fn get_display_width(&self) -> usize {
+        self.chip8.get_display().width
+    }
+
+    pub fn get_display_height(&self) -> usize {
+        self.chip8.get_display().height
+    }
+
+    /// A counter that changes whenever the display may have changed. JS can compare it
+    /// against the version it last drew to skip unchanged frames.
+    pub fn get_display_version(&self) -> u32 {
+        self.chip8.get_display_version()
+    }
+
+    /// Set the colors used by `get_display_rgba`, given as 0xRRGGBBAA.
+    pub fn set_palette(&mut self, off_color: u32, on_color: u32) {
+        self.palette = [off_color.to_be_bytes(), on_color.to_be_bytes()];
+    }
+
+    /// Copy the display into RGBA bytes, row by row, ready to be wrapped in an `ImageData`.
+    pub fn get_display_rgba(&self) -> Clamped<Vec<u8>> {
+        Clamped(self.chip8.get_display().to_rgba(&self.palette))
+    }
+
+    /// Copy the display into one byte per pixel, row by row, with 1 for lit pixels.
+    pub fn get_display_indexed(&self) -> Vec<u8> {
+        self.chip8.get_display().to_palette_indices()
+    }
 }
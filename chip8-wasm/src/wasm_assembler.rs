@@ -0,0 +1,88 @@
+use wasm_bindgen::prelude::*;
+
+const MEM_ADDR_START: u16 = 0x200;
+const MEM_ADDR_MAX: u16 = 4096;
+
+#[wasm_bindgen]
+pub struct WasmAssemblerError {
+    message: String,
+    line_num: Option<u16>,
+    column: Option<u32>,
+    length: Option<u32>,
+}
+
+#[wasm_bindgen]
+impl WasmAssemblerError {
+    pub fn get_message(&self) -> String {
+        self.message.clone()
+    }
+
+    /// The zero based line of the error, if known.
+    pub fn get_line_num(&self) -> Option<u16> {
+        self.line_num
+    }
+
+    /// The zero based column of the error within its line, if known.
+    pub fn get_column(&self) -> Option<u32> {
+        self.column
+    }
+
+    pub fn get_length(&self) -> Option<u32> {
+        self.length
+    }
+}
+
+impl WasmAssemblerError {
+    fn new(error: &chip8_assembler::AssemblerError) -> Self {
+        let location = error.src_location.as_ref();
+        let line_location = location.and_then(|location| location.line_location.as_ref());
+        WasmAssemblerError {
+            message: error.message.clone(),
+            line_num: location.map(|location| location.line_num),
+            column: line_location.map(|line_location| line_location.column),
+            length: line_location.map(|line_location| line_location.length),
+        }
+    }
+}
+
+#[wasm_bindgen]
+pub struct WasmAssembly {
+    program: Vec<u8>,
+    errors: Vec<WasmAssemblerError>,
+}
+
+#[wasm_bindgen]
+impl WasmAssembly {
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn get_program(&self) -> Vec<u8> {
+        self.program.clone()
+    }
+
+    pub fn take_errors(&mut self) -> Vec<WasmAssemblerError> {
+        std::mem::take(&mut self.errors)
+    }
+}
+
+/// Assemble source text into a program that can be passed to `WasmChip8::load_program`.
+#[wasm_bindgen]
+pub fn assemble(text: &str) -> WasmAssembly {
+    match chip8_assembler::assemble(text, MEM_ADDR_START, MEM_ADDR_MAX) {
+        Ok(program) => WasmAssembly {
+            program,
+            errors: Vec::new(),
+        },
+        Err(errors) => WasmAssembly {
+            program: Vec::new(),
+            errors: errors.iter().map(WasmAssemblerError::new).collect(),
+        },
+    }
+}
+
+/// Disassemble a program loaded at the start of program memory.
+#[wasm_bindgen]
+pub fn disassemble(program: &[u8]) -> String {
+    chip8_assembler::disassemble(program, MEM_ADDR_START)
+}
@@ -0,0 +1,29 @@
+use crate::WasmChip8;
+
+use chip8::audio::AudioRenderer;
+use wasm_bindgen::prelude::*;
+
+pub const DEFAULT_SAMPLE_RATE: u32 = 44100;
+
+#[wasm_bindgen]
+impl WasmChip8 {
+    pub fn has_sound(&self) -> bool {
+        self.chip8.has_sound()
+    }
+
+    /// Match the sample rate of the `AudioContext` the samples are played through.
+    pub fn set_audio_sample_rate(&mut self, sample_rate: u32) {
+        self.audio_renderer = AudioRenderer::new(sample_rate);
+    }
+
+    pub fn set_audio_volume(&mut self, volume: f32) {
+        self.audio_renderer.set_volume(volume);
+    }
+
+    /// Render `num_samples` mono samples for the current sound timer state, ready to be
+    /// copied into an `AudioBuffer` channel.
+    pub fn render_audio(&mut self, num_samples: usize) -> Vec<f32> {
+        let sound_on = self.chip8.has_sound();
+        self.audio_renderer.render_to_vec(sound_on, num_samples)
+    }
+}
@@ -0,0 +1,141 @@
+use crate::WasmChip8;
+
+use chip8::debugger::StopReason;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WasmStopReason {
+    Breakpoint,
+    InstructionLimit,
+    WaitingForKey,
+    Exited,
+    CodeModified,
+}
+
+impl From<StopReason> for WasmStopReason {
+    fn from(reason: StopReason) -> Self {
+        match reason {
+            StopReason::Breakpoint { .. } => WasmStopReason::Breakpoint,
+            StopReason::CodeModified { .. } => WasmStopReason::CodeModified,
+            StopReason::InstructionLimit => WasmStopReason::InstructionLimit,
+            StopReason::WaitingForKey => WasmStopReason::WaitingForKey,
+            StopReason::Exited => WasmStopReason::Exited,
+        }
+    }
+}
+
+/// Debugger and state inspection. Register indices above 0xF and memory accesses past the
+/// end of memory are ignored.
+#[wasm_bindgen]
+impl WasmChip8 {
+    pub fn get_register(&self, x: u8) -> u8 {
+        if x > 0xF {
+            return 0;
+        }
+        self.chip8.get_register(x)
+    }
+
+    pub fn set_register(&mut self, x: u8, value: u8) {
+        if x > 0xF {
+            return;
+        }
+        self.chip8.set_register(x, value);
+    }
+
+    /// @return V0 to VF.
+    pub fn get_registers(&self) -> Vec<u8> {
+        self.chip8.get_registers().to_vec()
+    }
+
+    pub fn get_i(&self) -> u16 {
+        self.chip8.get_pointer()
+    }
+
+    pub fn set_i(&mut self, addr: u16) {
+        self.chip8.set_pointer(addr);
+    }
+
+    pub fn get_pc(&self) -> u16 {
+        self.chip8.get_program_counter()
+    }
+
+    pub fn set_pc(&mut self, addr: u16) {
+        self.chip8.set_program_counter(addr);
+    }
+
+    pub fn get_sp(&self) -> usize {
+        self.chip8.get_stack_pointer()
+    }
+
+    /// @return the return addresses on the stack, oldest first.
+    pub fn get_stack(&self) -> Vec<u16> {
+        self.chip8.get_stack().to_vec()
+    }
+
+    pub fn get_dt(&self) -> u8 {
+        self.chip8.get_delay_timer()
+    }
+
+    pub fn set_dt(&mut self, value: u8) {
+        self.chip8.set_delay_timer(value);
+    }
+
+    pub fn get_st(&self) -> u8 {
+        self.chip8.get_sound_timer()
+    }
+
+    pub fn set_st(&mut self, value: u8) {
+        self.chip8.set_sound_timer(value);
+    }
+
+    pub fn read_memory(&self, addr: u16, len: usize) -> Vec<u8> {
+        self.chip8.get_memory(addr, len).to_vec()
+    }
+
+    pub fn write_memory(&mut self, addr: u16, bytes: &[u8]) {
+        self.chip8.set_memory(addr, bytes);
+    }
+
+    pub fn add_breakpoint(&mut self, addr: u16) -> bool {
+        self.debugger.add_breakpoint(addr)
+    }
+
+    pub fn remove_breakpoint(&mut self, addr: u16) -> bool {
+        self.debugger.remove_breakpoint(addr)
+    }
+
+    pub fn clear_breakpoints(&mut self) {
+        self.debugger.clear_breakpoints();
+    }
+
+    pub fn get_breakpoints(&self) -> Vec<u16> {
+        self.debugger.get_breakpoints().collect()
+    }
+
+    /// Stop `run` and `step` after an instruction writes over code that has already run.
+    pub fn set_break_on_code_write(&mut self, enabled: bool) {
+        self.debugger.set_break_on_code_write(enabled);
+    }
+
+    /// Set the function called with the breakpoint address whenever `run` stops at a
+    /// breakpoint. Pass `undefined` to remove it.
+    pub fn set_on_breakpoint(&mut self, callback: Option<js_sys::Function>) {
+        self.on_breakpoint = callback;
+    }
+
+    /// Run a single instruction, even if there is a breakpoint at the current address.
+    pub fn step(&mut self) -> WasmStopReason {
+        self.debugger.step(&mut self.chip8).into()
+    }
+
+    /// Run up to `num_instructions` instructions, stopping at breakpoints.
+    pub fn run(&mut self, num_instructions: u32) -> WasmStopReason {
+        let reason = self.debugger.run(&mut self.chip8, num_instructions);
+        if let (StopReason::Breakpoint { addr }, Some(callback)) = (reason, &self.on_breakpoint) {
+            // An exception thrown by the callback has nothing to unwind in Rust.
+            let _ = callback.call1(&JsValue::NULL, &JsValue::from(addr));
+        }
+        reason.into()
+    }
+}
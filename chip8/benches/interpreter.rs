@@ -0,0 +1,72 @@
+use chip8::Chip8;
+use chip8::logger::Logger;
+use chip8::logger::null_log_source::NullLogSource;
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use std::hint::black_box;
+
+const INSTRUCTIONS: u32 = 10_000;
+
+// 0x200: LD V0, 0x00
+// 0x202: ADD V0, 0x01
+// 0x204: ADD V1, V0
+// 0x206: OR V2, V1
+// 0x208: SE V0, 0x00
+// 0x20A: JP 0x202
+// 0x20C: JP 0x200
+const ALU_LOOP: [u8; 14] = [
+    0x60, 0x00, 0x70, 0x01, 0x81, 0x04, 0x82, 0x11, 0x30, 0x00, 0x12, 0x02, 0x12, 0x00,
+];
+
+// 0x200: LD V0, 0x00
+// 0x202: LD F, V0
+// 0x204: DRW V1, V2, 5
+// 0x206: ADD V0, 0x01
+// 0x208: ADD V1, 0x05
+// 0x20A: JP 0x202
+const DRAW_LOOP: [u8; 12] = [
+    0x60, 0x00, 0xF0, 0x29, 0xD1, 0x25, 0x70, 0x01, 0x71, 0x05, 0x12, 0x02,
+];
+
+const PROGRAMS: [(&str, &[u8]); 2] = [("alu_loop", &ALU_LOOP), ("draw_loop", &DRAW_LOOP)];
+
+fn new_chip8(logger: Logger, program: &[u8]) -> Chip8 {
+    let mut chip8 = Chip8::new(logger);
+    chip8.set_seed(0);
+    chip8.initialize(program);
+    chip8
+}
+
+/// Compares one instruction per `run_next_instruction` call, the `run` loop, and `run`
+/// with a logger that wants every instruction, which falls back to the per-step path.
+fn bench_interpreter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("interpreter");
+    group.throughput(Throughput::Elements(INSTRUCTIONS as u64));
+    for (name, program) in PROGRAMS {
+        group.bench_with_input(BenchmarkId::new("step", name), program, |b, program| {
+            let mut chip8 = new_chip8(Logger::new_null_logger(), program);
+            b.iter(|| {
+                for _ in 0..INSTRUCTIONS {
+                    black_box(chip8.run_next_instruction());
+                }
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("run", name), program, |b, program| {
+            let mut chip8 = new_chip8(Logger::new_null_logger(), program);
+            b.iter(|| black_box(chip8.run(INSTRUCTIONS)));
+        });
+        group.bench_with_input(
+            BenchmarkId::new("run_logged", name),
+            program,
+            |b, program| {
+                let logger = Logger::new(Box::new(NullLogSource::new()));
+                let mut chip8 = new_chip8(logger, program);
+                b.iter(|| black_box(chip8.run(INSTRUCTIONS)));
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_interpreter);
+criterion_main!(benches);
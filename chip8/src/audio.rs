@@ -0,0 +1,120 @@
+/// Frequency of the classic CHIP-8 buzzer tone.
+pub const DEFAULT_FREQUENCY_HZ: f32 = 440.0;
+pub const DEFAULT_VOLUME: f32 = 0.25;
+
+/// An XO-CHIP audio pattern: 128 one-bit samples, most significant bit first, played in a
+/// loop at a rate set by the pitch register.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AudioPattern {
+    pub bits: [u8; 16],
+    pub pitch: u8,
+}
+
+impl AudioPattern {
+    const BIT_COUNT: usize = 128;
+
+    /// @return the number of pattern bits played per second.
+    pub fn playback_rate(&self) -> f32 {
+        4000.0 * 2f32.powf((self.pitch as f32 - 64.0) / 48.0)
+    }
+
+    fn get_bit(&self, i: usize) -> bool {
+        let i = i % Self::BIT_COUNT;
+        self.bits[i >> 3] & (0x80 >> (i & 0x7)) != 0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Waveform {
+    Square { frequency_hz: f32 },
+    Pattern(AudioPattern),
+}
+
+/// Renders mono PCM samples in `-1.0..=1.0` for the sound timer. The waveform phase is
+/// kept between calls so consecutive buffers join without clicks.
+#[derive(Debug)]
+pub struct AudioRenderer {
+    sample_rate: u32,
+    waveform: Waveform,
+    volume: f32,
+    phase: f64,
+}
+
+impl AudioRenderer {
+    pub fn new(sample_rate: u32) -> Self {
+        Self {
+            sample_rate,
+            waveform: Waveform::Square {
+                frequency_hz: DEFAULT_FREQUENCY_HZ,
+            },
+            volume: DEFAULT_VOLUME,
+            phase: 0.0,
+        }
+    }
+
+    pub fn get_sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    pub fn set_waveform(&mut self, waveform: Waveform) {
+        self.waveform = waveform;
+        self.phase = 0.0;
+    }
+
+    pub fn set_volume(&mut self, volume: f32) {
+        self.volume = volume.clamp(0.0, 1.0);
+    }
+
+    /// @return the number of samples covering `micros` microseconds.
+    pub fn samples_for_micros(&self, micros: u32) -> usize {
+        (self.sample_rate as u64 * micros as u64 / 1_000_000) as usize
+    }
+
+    /// Fill `samples` with the waveform if `sound_on`, or with silence otherwise.
+    pub fn render(&mut self, sound_on: bool, samples: &mut [f32]) {
+        if !sound_on {
+            samples.fill(0.0);
+            self.phase = 0.0;
+            return;
+        }
+
+        let step = self.cycles_per_second() / self.sample_rate as f64;
+        for sample in samples.iter_mut() {
+            *sample = if self.is_high() {
+                self.volume
+            } else {
+                -self.volume
+            };
+            self.phase = (self.phase + step) % self.phase_period();
+        }
+    }
+
+    pub fn render_to_vec(&mut self, sound_on: bool, num_samples: usize) -> Vec<f32> {
+        let mut samples = vec![0.0; num_samples];
+        self.render(sound_on, &mut samples);
+        samples
+    }
+
+    /// @return how many waveform periods, or pattern bits, pass per second.
+    fn cycles_per_second(&self) -> f64 {
+        match self.waveform {
+            Waveform::Square { frequency_hz } => frequency_hz as f64,
+            Waveform::Pattern(pattern) => pattern.playback_rate() as f64,
+        }
+    }
+
+    /// @return the value at which the phase wraps back to 0.
+    fn phase_period(&self) -> f64 {
+        match self.waveform {
+            Waveform::Square { .. } => 1.0,
+            Waveform::Pattern(_) => AudioPattern::BIT_COUNT as f64,
+        }
+    }
+
+    fn is_high(&self) -> bool {
+        match self.waveform {
+            Waveform::Square { .. } => self.phase < 0.5,
+            Waveform::Pattern(pattern) => pattern.get_bit(self.phase as usize),
+        }
+    }
+}
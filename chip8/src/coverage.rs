@@ -0,0 +1,141 @@
+use crate::source_map::SourceMap;
+
+use std::collections::BTreeMap;
+
+const MEMORY_BYTES: usize = 4096;
+
+/// Which bytes of memory a program ran as instructions and which it read as data, as seen
+/// by `Draw` and `ReadRegFromPointer`.
+#[derive(Debug, Clone)]
+pub struct Coverage {
+    /// The number of times an instruction starting at each address ran.
+    executions: Vec<u64>,
+    /// The number of times each byte was read as data.
+    reads: Vec<u64>,
+}
+
+impl Coverage {
+    pub fn new() -> Self {
+        Self {
+            executions: vec![0; MEMORY_BYTES],
+            reads: vec![0; MEMORY_BYTES],
+        }
+    }
+
+    pub fn record_execution(&mut self, pc: u16) {
+        if let Some(count) = self.executions.get_mut(pc as usize) {
+            *count += 1;
+        }
+    }
+
+    pub fn record_read(&mut self, addr: u16, len: u16) {
+        let start = (addr as usize).min(MEMORY_BYTES);
+        let end = (addr as usize + len as usize).min(MEMORY_BYTES);
+        for count in self.reads[start..end].iter_mut() {
+            *count += 1;
+        }
+    }
+
+    /// @return the number of times the instruction starting at an address ran.
+    pub fn get_executions(&self, addr: u16) -> u64 {
+        self.executions.get(addr as usize).copied().unwrap_or(0)
+    }
+
+    /// @return the number of times a byte was read as data.
+    pub fn get_reads(&self, addr: u16) -> u64 {
+        self.reads.get(addr as usize).copied().unwrap_or(0)
+    }
+
+    /// @return the number of times an instruction or data row was used: the executions of
+    /// an instruction starting at `addr`, or the most reads of any of its bytes.
+    fn get_hits(&self, addr: u16, size: u16) -> u64 {
+        let max_reads = (addr..addr.saturating_add(size))
+            .map(|addr| self.get_reads(addr))
+            .max()
+            .unwrap_or(0);
+        self.get_executions(addr).max(max_reads)
+    }
+
+    /// @return an lcov tracefile with the hits for each source line in the map. A line
+    /// that assembled to several instructions is hit as often as the most used of them.
+    pub fn to_lcov(&self, source_map: &SourceMap) -> String {
+        let mut hits_by_line: BTreeMap<usize, u64> = BTreeMap::new();
+        for entry in source_map.entries.iter() {
+            let hits = hits_by_line.entry(entry.line_num).or_default();
+            *hits = (*hits).max(self.get_hits(entry.addr, entry.size));
+        }
+
+        let mut lcov = String::from("TN:\n");
+        lcov += &format!(
+            "SF:{}\n",
+            source_map.source_path.as_deref().unwrap_or_default()
+        );
+        for (line_num, hits) in hits_by_line.iter() {
+            lcov += &format!("DA:{},{}\n", line_num, hits);
+        }
+        lcov += &format!("LF:{}\n", hits_by_line.len());
+        lcov += &format!(
+            "LH:{}\n",
+            hits_by_line.values().filter(|hits| **hits > 0).count()
+        );
+        lcov += "end_of_record\n";
+        lcov
+    }
+
+    /// @return a listing of the program with how often each instruction ran and each byte
+    /// was read. Instructions are written with `to_assembly`, bytes that were only read
+    /// are listed one per line with an `r` before their count, and anything never reached
+    /// is marked with `-`.
+    pub fn annotate(
+        &self,
+        program: &[u8],
+        mem_addr_start: u16,
+        to_assembly: impl Fn(u8, u8) -> String,
+    ) -> String {
+        let mut listing = String::new();
+        let mut offset = 0;
+        while offset < program.len() {
+            let addr = mem_addr_start + offset as u16;
+            let executions = self.get_executions(addr);
+            let reads = self.get_reads(addr);
+            let line = if offset + 1 < program.len() && (executions > 0 || reads == 0) {
+                let (byte1, byte2) = (program[offset], program[offset + 1]);
+                let hits = if executions > 0 {
+                    executions.to_string()
+                } else {
+                    "-".to_string()
+                };
+                offset += 2;
+                format!(
+                    "{:>10}  0x{:03x}  {:02x}{:02x}  {}",
+                    hits,
+                    addr,
+                    byte1,
+                    byte2,
+                    to_assembly(byte1, byte2)
+                )
+            } else {
+                let byte = program[offset];
+                offset += 1;
+                let hits = if reads > 0 {
+                    format!("r{}", reads)
+                } else {
+                    "-".to_string()
+                };
+                format!(
+                    "{:>10}  0x{:03x}  {:02x}    0x{:02X}",
+                    hits, addr, byte, byte
+                )
+            };
+            listing += line.trim_end();
+            listing += "\n";
+        }
+        listing
+    }
+}
+
+impl Default for Coverage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,126 @@
+use crate::Chip8;
+use crate::logger::LogEvent;
+
+use std::collections::BTreeSet;
+
+/// Why `Debugger::run` or `Debugger::step` returned control to the caller.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StopReason {
+    /// The program counter reached a breakpoint. The instruction there has not run yet.
+    Breakpoint { addr: u16 },
+    /// The instruction at `pc` wrote over the instruction at `addr`, which had already run.
+    /// The writing instruction has run. Only reported when breaking on code writes.
+    CodeModified { pc: u16, addr: u16 },
+    /// The requested number of instructions ran without stopping.
+    InstructionLimit,
+    /// The processor is waiting for a key press.
+    WaitingForKey,
+    /// The program exited.
+    Exited,
+}
+
+/// Breakpoints and stepping on top of a `Chip8`, shared by the debugger frontends.
+#[derive(Debug, Default)]
+pub struct Debugger {
+    breakpoints: BTreeSet<u16>,
+    break_on_code_write: bool,
+}
+
+impl Debugger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// @return true if the breakpoint was not already set.
+    pub fn add_breakpoint(&mut self, addr: u16) -> bool {
+        self.breakpoints.insert(addr)
+    }
+
+    /// @return true if the breakpoint was set.
+    pub fn remove_breakpoint(&mut self, addr: u16) -> bool {
+        self.breakpoints.remove(&addr)
+    }
+
+    pub fn clear_breakpoints(&mut self) {
+        self.breakpoints.clear();
+    }
+
+    pub fn has_breakpoint(&self, addr: u16) -> bool {
+        self.breakpoints.contains(&addr)
+    }
+
+    pub fn get_breakpoints(&self) -> impl Iterator<Item = u16> + '_ {
+        self.breakpoints.iter().copied()
+    }
+
+    /// Stop after any instruction that writes over code which has already run.
+    pub fn set_break_on_code_write(&mut self, enabled: bool) {
+        self.break_on_code_write = enabled;
+    }
+
+    pub fn get_break_on_code_write(&self) -> bool {
+        self.break_on_code_write
+    }
+
+    /// Run a single instruction, ignoring any breakpoint at the current address.
+    pub fn step(&self, chip8: &mut Chip8) -> StopReason {
+        if let Some(reason) = Self::get_halt_reason(chip8) {
+            return reason;
+        }
+        chip8.run_next_instruction();
+        if let Some(reason) = self.get_code_write_reason(chip8) {
+            return reason;
+        }
+        Self::get_halt_reason(chip8).unwrap_or(StopReason::InstructionLimit)
+    }
+
+    /// Run up to `num_instructions` instructions, stopping before any instruction at a
+    /// breakpoint. A breakpoint at the current address is stepped over so that a stopped
+    /// program can be resumed.
+    pub fn run(&self, chip8: &mut Chip8, num_instructions: u32) -> StopReason {
+        for i in 0..num_instructions {
+            let addr = chip8.get_program_counter();
+            if i > 0 && self.has_breakpoint(addr) {
+                return Self::hit_breakpoint(chip8, addr);
+            }
+            if let Some(reason) = Self::get_halt_reason(chip8) {
+                return reason;
+            }
+            chip8.run_next_instruction();
+            if let Some(reason) = self.get_code_write_reason(chip8) {
+                return reason;
+            }
+        }
+        let addr = chip8.get_program_counter();
+        if self.has_breakpoint(addr) {
+            return Self::hit_breakpoint(chip8, addr);
+        }
+        Self::get_halt_reason(chip8).unwrap_or(StopReason::InstructionLimit)
+    }
+
+    fn hit_breakpoint(chip8: &mut Chip8, addr: u16) -> StopReason {
+        chip8.log_event(LogEvent::BreakpointHit { pc: addr });
+        StopReason::Breakpoint { addr }
+    }
+
+    fn get_code_write_reason(&self, chip8: &Chip8) -> Option<StopReason> {
+        if !self.break_on_code_write {
+            return None;
+        }
+        let code_write = chip8.get_last_code_write()?;
+        Some(StopReason::CodeModified {
+            pc: code_write.pc,
+            addr: code_write.addr,
+        })
+    }
+
+    fn get_halt_reason(chip8: &Chip8) -> Option<StopReason> {
+        if chip8.is_exited() {
+            Some(StopReason::Exited)
+        } else if chip8.is_waiting_for_key() {
+            Some(StopReason::WaitingForKey)
+        } else {
+            None
+        }
+    }
+}
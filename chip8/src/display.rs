@@ -24,4 +24,43 @@ impl<'a> Display<'a> {
         let bit = 7 - (pixel & 0x7);
         return self.data[byte] & (1 << bit) != 0;
     }
+
+    /// @return one byte per pixel, row by row, with 1 for a lit pixel and 0 otherwise.
+    pub fn to_palette_indices(&self) -> Vec<u8> {
+        (0..self.height)
+            .flat_map(|i| (0..self.width).map(move |j| self.get_pixel(i, j) as u8))
+            .collect()
+    }
+
+    /// @return four RGBA bytes per pixel, row by row, using `palette[0]` for unlit pixels
+    /// and `palette[1]` for lit pixels.
+    pub fn to_rgba(&self, palette: &[[u8; 4]; 2]) -> Vec<u8> {
+        self.to_palette_indices()
+            .into_iter()
+            .flat_map(|index| palette[index as usize])
+            .collect()
+    }
+
+    /// @return one line of text per row, using `on` and `off` for lit and unlit pixels.
+    pub fn to_text(&self, on: char, off: char) -> String {
+        let mut text = String::new();
+        for i in 0..self.height {
+            text.extend((0..self.width).map(|j| if self.get_pixel(i, j) { on } else { off }));
+            text.push('\n');
+        }
+        text
+    }
+
+    /// @return the display as a plain PBM image, where 1 is a lit pixel.
+    pub fn to_pbm(&self) -> String {
+        let mut text = format!("P1\n{} {}\n", self.width, self.height);
+        for i in 0..self.height {
+            let row: Vec<&str> = (0..self.width)
+                .map(|j| if self.get_pixel(i, j) { "1" } else { "0" })
+                .collect();
+            text += row.join(" ").as_str();
+            text.push('\n');
+        }
+        text
+    }
 }
@@ -0,0 +1,199 @@
+use crate::Chip8;
+use crate::Chip8Display;
+use crate::input_script::InputScript;
+use crate::logger::Logger;
+
+use std::env;
+use std::fs;
+
+pub const BLESS_ENV_VAR: &str = "CHIP8_BLESS";
+
+/// A golden-image test: run a ROM with scripted input for a number of frames and compare
+/// the final display against a fixture.
+///
+/// Fixtures are either text, one line per row with `.` for unlit pixels and any other
+/// character for lit pixels, or plain PBM when the path ends in `.pbm`. Set `CHIP8_BLESS`
+/// to write the actual display to the fixture instead of comparing against it.
+///
+/// Only ROMs checked in next to the tests are run. To add another, such as one from a
+/// community test suite, copy the ROM into `src/`, bless its fixture from a run that is
+/// known to pass and check the fixture by eye.
+#[derive(Debug, Clone)]
+pub struct RomTest<'a> {
+    pub rom_path: &'a str,
+    /// See `InputScript` for the format.
+    pub input_script: &'a str,
+    pub frames: u32,
+    pub instructions_per_frame: u32,
+    pub golden_path: &'a str,
+}
+
+impl<'a> RomTest<'a> {
+    pub fn new(rom_path: &'a str, golden_path: &'a str) -> Self {
+        Self {
+            rom_path,
+            input_script: "",
+            frames: 100,
+            instructions_per_frame: 10,
+            golden_path,
+        }
+    }
+
+    /// Run the ROM until the frame count is reached or it exits.
+    pub fn run(&self) -> Chip8 {
+        self.run_with(Chip8::run_frame)
+    }
+
+    /// Run the ROM as `run` does, with `run_frame` in place of `Chip8::run_frame`, such as
+    /// a recompiled program's.
+    pub fn run_with(&self, mut run_frame: impl FnMut(&mut Chip8, u32) -> bool) -> Chip8 {
+        let program = fs::read(self.rom_path)
+            .unwrap_or_else(|e| panic!("Failed to open ROM {}: {}", self.rom_path, e));
+        let input_script = InputScript::parse(self.input_script)
+            .unwrap_or_else(|e| panic!("Invalid input script: {}", e));
+
+        let mut chip8 = Chip8::new(Logger::new_null_logger());
+        chip8.initialize(&program);
+        for frame in 0..self.frames {
+            chip8.set_keys(input_script.keys_at(frame));
+            if run_frame(&mut chip8, self.instructions_per_frame) {
+                break;
+            }
+        }
+        chip8
+    }
+
+    /// Run the ROM and panic with a pixel diff if the display does not match the golden.
+    pub fn assert_golden(&self) {
+        self.assert_golden_with(Chip8::run_frame);
+    }
+
+    /// Run the ROM with `run_frame` as `run_with` does and compare it as `assert_golden`
+    /// does.
+    pub fn assert_golden_with(&self, run_frame: impl FnMut(&mut Chip8, u32) -> bool) {
+        let chip8 = self.run_with(run_frame);
+        assert_display_matches(&chip8.get_display(), self.golden_path);
+    }
+}
+
+/// Panic with a pixel diff if the display does not match the golden, or overwrite the
+/// golden if `CHIP8_BLESS` is set.
+pub fn assert_display_matches(display: &Chip8Display, golden_path: &str) {
+    if env::var_os(BLESS_ENV_VAR).is_some() {
+        let text = if golden_path.ends_with(".pbm") {
+            display.to_pbm()
+        } else {
+            display.to_text('X', '.')
+        };
+        fs::write(golden_path, text)
+            .unwrap_or_else(|e| panic!("Failed to bless {}: {}", golden_path, e));
+        return;
+    }
+
+    let text = fs::read_to_string(golden_path).unwrap_or_else(|e| {
+        panic!(
+            "Failed to open golden {}: {}. Set {}=1 to create it.",
+            golden_path, e, BLESS_ENV_VAR
+        )
+    });
+    let expected = if golden_path.ends_with(".pbm") {
+        parse_pbm(&text)
+    } else {
+        Ok(parse_text(&text))
+    }
+    .unwrap_or_else(|e| panic!("Invalid golden {}: {}", golden_path, e));
+
+    if let Some(diff) = diff_display(display, &expected) {
+        panic!(
+            "Display does not match golden {}. Set {}=1 to update it.\n{}",
+            golden_path, BLESS_ENV_VAR, diff
+        );
+    }
+}
+
+/// Parse a text fixture into rows of pixels.
+pub fn parse_text(text: &str) -> Vec<Vec<bool>> {
+    text.split_ascii_whitespace()
+        .map(|row| row.chars().map(|c| c != '.').collect())
+        .collect()
+}
+
+/// Parse a plain PBM fixture into rows of pixels.
+pub fn parse_pbm(text: &str) -> Result<Vec<Vec<bool>>, String> {
+    let mut words = text
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default())
+        .flat_map(|line| line.split_ascii_whitespace());
+    if words.next() != Some("P1") {
+        return Err("Expected a plain PBM starting with P1.".to_string());
+    }
+    let mut next_number = |name: &str| {
+        words
+            .next()
+            .and_then(|word| word.parse::<usize>().ok())
+            .ok_or(format!("Expected the {}.", name))
+    };
+    let width = next_number("width")?;
+    let height = next_number("height")?;
+    let mut rows = vec![vec![false; width]; height];
+    for row in rows.iter_mut() {
+        for pixel in row.iter_mut() {
+            *pixel = next_number("pixel")? != 0;
+        }
+    }
+    Ok(rows)
+}
+
+/// @return a description of each row that differs from `expected`, with `^` under the
+/// differing pixels, or None if the display matches.
+pub fn diff_display(display: &Chip8Display, expected: &[Vec<bool>]) -> Option<String> {
+    let to_row = |pixels: &mut dyn Iterator<Item = bool>| -> String {
+        pixels.map(|pixel| if pixel { 'X' } else { '.' }).collect()
+    };
+
+    let mut diff = String::new();
+    let mut diff_pixels = 0;
+    if expected.len() != display.height || expected.iter().any(|row| row.len() != display.width) {
+        diff += format!(
+            "Expected a {}x{} display, found {}x{}.\n",
+            expected.first().map(|row| row.len()).unwrap_or(0),
+            expected.len(),
+            display.width,
+            display.height
+        )
+        .as_str();
+    }
+
+    for i in 0..display.height.max(expected.len()) {
+        let expected_row = expected.get(i).map(|row| row.as_slice()).unwrap_or(&[]);
+        let width = display.width.max(expected_row.len());
+        let markers: String = (0..width)
+            .map(|j| {
+                let expected_pixel = expected_row.get(j).copied().unwrap_or(false);
+                if expected_pixel != display.get_pixel(i, j) {
+                    '^'
+                } else {
+                    ' '
+                }
+            })
+            .collect();
+        if !markers.contains('^') {
+            continue;
+        }
+        diff_pixels += markers.matches('^').count();
+        diff += format!(
+            "row {:2} expected {}\n       actual   {}\n                {}\n",
+            i,
+            to_row(&mut expected_row.iter().copied()),
+            to_row(&mut (0..display.width).map(|j| display.get_pixel(i, j))),
+            markers.trim_end()
+        )
+        .as_str();
+    }
+
+    if diff.is_empty() {
+        None
+    } else {
+        Some(format!("{} pixels differ:\n{}", diff_pixels, diff))
+    }
+}
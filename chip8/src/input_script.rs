@@ -0,0 +1,68 @@
+use crate::Chip8Keys;
+
+/// Keys held down over time, for driving a program without a keyboard.
+///
+/// Each line is a frame number followed by the keys held from that frame on, as hex digits
+/// separated by whitespace, or `-` for no keys. Lines must be in increasing frame order.
+/// Text after `#` is a comment.
+///
+/// ```text
+/// # frame  keys
+/// 0        -
+/// 30       5 A
+/// 35       -
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InputScript {
+    changes: Vec<(u32, Chip8Keys)>,
+}
+
+impl InputScript {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut changes: Vec<(u32, Chip8Keys)> = Vec::new();
+        for (line_num, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default();
+            let mut words = line.split_whitespace();
+            let Some(frame_word) = words.next() else {
+                continue;
+            };
+            let error = |message: String| format!("Line {}: {}", line_num + 1, message);
+
+            let frame: u32 = frame_word
+                .parse()
+                .map_err(|_| error(format!("Invalid frame number: {}", frame_word)))?;
+            if let Some((prev_frame, _)) = changes.last()
+                && frame <= *prev_frame
+            {
+                return Err(error(format!(
+                    "Frame {} is not after frame {}",
+                    frame, prev_frame
+                )));
+            }
+
+            let mut keys: Chip8Keys = 0;
+            for word in words {
+                if word == "-" {
+                    continue;
+                }
+                let key = u8::from_str_radix(word, 16)
+                    .ok()
+                    .filter(|key| *key <= 0xF)
+                    .ok_or_else(|| error(format!("Invalid key: {}", word)))?;
+                keys |= 1 << key;
+            }
+            changes.push((frame, keys));
+        }
+        Ok(Self { changes })
+    }
+
+    /// @return the keys held during `frame`.
+    pub fn keys_at(&self, frame: u32) -> Chip8Keys {
+        self.changes
+            .iter()
+            .take_while(|(change_frame, _)| *change_frame <= frame)
+            .last()
+            .map(|(_, keys)| *keys)
+            .unwrap_or(0)
+    }
+}
@@ -1,12 +1,36 @@
+pub mod audio;
+pub mod coverage;
+pub mod debugger;
 mod display;
+pub mod golden;
+pub mod input_script;
 mod key;
 pub mod logger;
+pub mod movie;
 mod processor;
+pub mod profiler;
+pub mod quirks;
+pub mod source_map;
+pub mod symbol_map;
+pub mod trace_file;
 
 pub use display::Display as Chip8Display;
 pub use key::Key as Chip8Key;
 pub use key::Keys as Chip8Keys;
+pub use processor::CodeWrite as Chip8CodeWrite;
 pub use processor::Processor as Chip8;
+pub use processor::{
+    DEFAULT_INSTRUCTIONS_PER_FRAME, MAX_INSTRUCTIONS_PER_FRAME, MAX_PROGRAM_BYTES,
+};
+
+#[cfg(test)]
+mod test_audio;
+
+#[cfg(test)]
+mod test_coverage;
+
+#[cfg(test)]
+mod test_debugger;
 
 #[cfg(test)]
 mod test_display;
@@ -20,8 +44,32 @@ mod test_execute_flags;
 #[cfg(test)]
 mod test_execute_key_press;
 
+#[cfg(test)]
+mod test_golden;
+
+#[cfg(test)]
+mod test_input_script;
+
 #[cfg(test)]
 mod test_key;
 
+#[cfg(test)]
+mod test_movie;
+
 #[cfg(test)]
 mod test_processor;
+
+#[cfg(test)]
+mod test_profiler;
+
+#[cfg(test)]
+mod test_quirks;
+
+#[cfg(test)]
+mod test_source_map;
+
+#[cfg(test)]
+mod test_symbol_map;
+
+#[cfg(test)]
+mod test_trace_file;
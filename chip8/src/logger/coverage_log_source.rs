@@ -0,0 +1,38 @@
+use super::{LogEvent, LogSource};
+use crate::coverage::Coverage;
+
+use std::sync::{Arc, Mutex};
+
+/// Records the instructions executed and the memory read into a shared `Coverage`. Other
+/// events are skipped.
+#[derive(Debug)]
+pub struct CoverageLogSource {
+    coverage: Arc<Mutex<Coverage>>,
+}
+
+impl CoverageLogSource {
+    /// @return the log source and the coverage it records into.
+    pub fn new() -> (Self, Arc<Mutex<Coverage>>) {
+        let coverage = Arc::new(Mutex::new(Coverage::new()));
+        let log_source = CoverageLogSource {
+            coverage: coverage.clone(),
+        };
+        (log_source, coverage)
+    }
+}
+
+impl LogSource for CoverageLogSource {
+    fn write(&mut self, _message: &str) {}
+
+    fn write_event(&mut self, event: &LogEvent) {
+        match event {
+            LogEvent::InstructionExecuted { pc, .. } => {
+                self.coverage.lock().unwrap().record_execution(*pc)
+            }
+            LogEvent::MemoryRead { addr, len } => {
+                self.coverage.lock().unwrap().record_read(*addr, *len)
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use super::{LogCategories, LogCategory, LogEvent, LogLevel, LogSource};
+
+/// Passes on the events at or below a level and in a set of categories, for sending
+/// different events to different places. Plain messages are always passed on.
+#[derive(Debug)]
+pub struct FilterLogSource {
+    log_source: Box<dyn LogSource>,
+    level: LogLevel,
+    categories: LogCategories,
+}
+
+impl FilterLogSource {
+    pub fn new(log_source: Box<dyn LogSource>, level: LogLevel, categories: LogCategories) -> Self {
+        FilterLogSource {
+            log_source,
+            level,
+            categories,
+        }
+    }
+}
+
+impl LogSource for FilterLogSource {
+    fn write(&mut self, message: &str) {
+        self.log_source.write(message);
+    }
+
+    fn write_event(&mut self, event: &LogEvent) {
+        if event.level() <= self.level && self.categories & event.category() as u8 != 0 {
+            self.log_source.write_event(event);
+        }
+    }
+
+    fn records_trace_entries(&self) -> bool {
+        // `InstructionTraced` is a trace level CPU event, so it only gets through the filter
+        // when both are let through.
+        self.level >= LogLevel::Trace
+            && self.categories & LogCategory::Cpu as u8 != 0
+            && self.log_source.records_trace_entries()
+    }
+}
@@ -0,0 +1,35 @@
+use super::{LogCategory, LogEvent, LogLevel, LogSource};
+
+use std::fmt;
+use std::io::Write;
+
+/// Writes each event as a line of JSON. See `LogEvent::to_json` for the format.
+pub struct JsonLinesLogSource<W: Write + Send + Sync> {
+    writer: W,
+}
+
+impl<W: Write + Send + Sync> JsonLinesLogSource<W> {
+    pub fn new(writer: W) -> Self {
+        JsonLinesLogSource { writer }
+    }
+}
+
+impl<W: Write + Send + Sync> fmt::Debug for JsonLinesLogSource<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JsonLinesLogSource").finish_non_exhaustive()
+    }
+}
+
+impl<W: Write + Send + Sync> LogSource for JsonLinesLogSource<W> {
+    fn write(&mut self, message: &str) {
+        self.write_event(&LogEvent::Message {
+            level: LogLevel::Info,
+            category: LogCategory::General,
+            text: message,
+        });
+    }
+
+    fn write_event(&mut self, event: &LogEvent) {
+        let _ = writeln!(self.writer, "{}", event.to_json());
+    }
+}
@@ -0,0 +1,255 @@
+use super::trace::{CpuState, TraceEntry};
+use super::{LogCategory, LogLevel};
+use crate::Chip8Keys;
+
+use chip8_instructions::Instruction;
+use std::fmt;
+
+/// Something that happened in the emulator. Events are cheap to build, and only formatted
+/// when a logger writes them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LogEvent<'a> {
+    Message {
+        level: LogLevel,
+        category: LogCategory,
+        text: &'a str,
+    },
+    InstructionExecuted {
+        pc: u16,
+        instruction: Instruction,
+    },
+    /// An executed instruction with the registers before and after it. Only logged when
+    /// the log source records trace entries.
+    InstructionTraced {
+        entry: &'a TraceEntry,
+    },
+    UnknownInstruction {
+        pc: u16,
+        byte1: u8,
+        byte2: u8,
+    },
+    JumpLoopDetected {
+        pc: u16,
+    },
+    /// An instruction read `len` bytes of memory as data, starting at `addr`.
+    MemoryRead {
+        addr: u16,
+        len: u16,
+    },
+    /// The instruction at `pc` wrote over the instruction at `addr`, which had already run.
+    /// `opcode` is the instruction before it was overwritten.
+    CodeModified {
+        pc: u16,
+        addr: u16,
+        opcode: u16,
+    },
+    /// The debugger stopped before the instruction at `pc`.
+    BreakpointHit {
+        pc: u16,
+    },
+    KeysChanged {
+        keys: Chip8Keys,
+    },
+    DisplayCleared,
+    SpriteDrawn {
+        x: u8,
+        y: u8,
+        height: u8,
+        collision: bool,
+    },
+    /// The timers before they were decremented.
+    TimersTicked {
+        delay_timer: u8,
+        sound_timer: u8,
+    },
+}
+
+impl LogEvent<'_> {
+    pub fn level(&self) -> LogLevel {
+        match self {
+            LogEvent::Message { level, .. } => *level,
+            LogEvent::InstructionExecuted { .. } => LogLevel::Trace,
+            LogEvent::InstructionTraced { .. } => LogLevel::Trace,
+            LogEvent::UnknownInstruction { .. } => LogLevel::Warn,
+            LogEvent::JumpLoopDetected { .. } => LogLevel::Info,
+            LogEvent::MemoryRead { .. } => LogLevel::Trace,
+            LogEvent::CodeModified { .. } => LogLevel::Info,
+            LogEvent::BreakpointHit { .. } => LogLevel::Info,
+            LogEvent::KeysChanged { .. } => LogLevel::Debug,
+            LogEvent::DisplayCleared => LogLevel::Debug,
+            LogEvent::SpriteDrawn { .. } => LogLevel::Trace,
+            LogEvent::TimersTicked { .. } => LogLevel::Trace,
+        }
+    }
+
+    pub fn category(&self) -> LogCategory {
+        match self {
+            LogEvent::Message { category, .. } => *category,
+            LogEvent::InstructionExecuted { .. }
+            | LogEvent::InstructionTraced { .. }
+            | LogEvent::UnknownInstruction { .. }
+            | LogEvent::JumpLoopDetected { .. }
+            | LogEvent::MemoryRead { .. }
+            | LogEvent::CodeModified { .. }
+            | LogEvent::BreakpointHit { .. } => LogCategory::Cpu,
+            LogEvent::KeysChanged { .. } => LogCategory::Input,
+            LogEvent::DisplayCleared | LogEvent::SpriteDrawn { .. } => LogCategory::Display,
+            LogEvent::TimersTicked { .. } => LogCategory::Timers,
+        }
+    }
+
+    /// @return the event's name in JSON lines.
+    pub fn name(&self) -> &'static str {
+        match self {
+            LogEvent::Message { .. } => "message",
+            LogEvent::InstructionExecuted { .. } => "instruction_executed",
+            LogEvent::InstructionTraced { .. } => "instruction_traced",
+            LogEvent::UnknownInstruction { .. } => "unknown_instruction",
+            LogEvent::JumpLoopDetected { .. } => "jump_loop_detected",
+            LogEvent::MemoryRead { .. } => "memory_read",
+            LogEvent::CodeModified { .. } => "code_modified",
+            LogEvent::BreakpointHit { .. } => "breakpoint_hit",
+            LogEvent::KeysChanged { .. } => "keys_changed",
+            LogEvent::DisplayCleared => "display_cleared",
+            LogEvent::SpriteDrawn { .. } => "sprite_drawn",
+            LogEvent::TimersTicked { .. } => "timers_ticked",
+        }
+    }
+
+    /// @return the event as a single line JSON object with its level, category and name
+    /// followed by its fields.
+    pub fn to_json(&self) -> String {
+        let fields = match self {
+            LogEvent::Message { text, .. } => format!(r#""text":{}"#, json_string(text)),
+            LogEvent::InstructionExecuted { pc, instruction } => format!(
+                r#""pc":{},"instruction":{}"#,
+                pc,
+                json_string(&format!("{:?}", instruction))
+            ),
+            LogEvent::UnknownInstruction { pc, byte1, byte2 } => {
+                format!(r#""pc":{},"byte1":{},"byte2":{}"#, pc, byte1, byte2)
+            }
+            LogEvent::InstructionTraced { entry } => format!(
+                r#""pc":{},"opcode":{},"instruction":{},"before":{},"after":{}"#,
+                entry.pc,
+                entry.opcode,
+                json_string(&format!("{:?}", entry.instruction)),
+                cpu_state_json(&entry.before),
+                cpu_state_json(&entry.after)
+            ),
+            LogEvent::MemoryRead { addr, len } => format!(r#""addr":{},"len":{}"#, addr, len),
+            LogEvent::CodeModified { pc, addr, opcode } => {
+                format!(r#""pc":{},"addr":{},"opcode":{}"#, pc, addr, opcode)
+            }
+            LogEvent::JumpLoopDetected { pc } | LogEvent::BreakpointHit { pc } => {
+                format!(r#""pc":{}"#, pc)
+            }
+            LogEvent::KeysChanged { keys } => format!(r#""keys":{}"#, keys),
+            LogEvent::DisplayCleared => String::new(),
+            LogEvent::SpriteDrawn {
+                x,
+                y,
+                height,
+                collision,
+            } => format!(
+                r#""x":{},"y":{},"height":{},"collision":{}"#,
+                x, y, height, collision
+            ),
+            LogEvent::TimersTicked {
+                delay_timer,
+                sound_timer,
+            } => format!(
+                r#""delay_timer":{},"sound_timer":{}"#,
+                delay_timer, sound_timer
+            ),
+        };
+        format!(
+            r#"{{"level":"{}","category":"{}","event":"{}"{}{}}}"#,
+            self.level().name(),
+            self.category().name(),
+            self.name(),
+            if fields.is_empty() { "" } else { "," },
+            fields
+        )
+    }
+}
+
+/// Formats the event as the plain text message written to log files.
+impl fmt::Display for LogEvent<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LogEvent::Message { text, .. } => write!(f, "{}", text),
+            LogEvent::InstructionExecuted { pc, instruction } => {
+                write!(f, "0x{:x}: Executing instruction: {:?}", pc, instruction)
+            }
+            LogEvent::UnknownInstruction { pc, byte1, byte2 } => write!(
+                f,
+                "0x{:x}: Unknown instruction 0x{:x} 0x{:x}",
+                pc, byte1, byte2
+            ),
+            LogEvent::InstructionTraced { entry } => write!(f, "{}", entry),
+            LogEvent::JumpLoopDetected { pc } => {
+                write!(f, "0x{:x}: Jump loop detected. Exiting.", pc)
+            }
+            LogEvent::MemoryRead { addr, len } => {
+                write!(f, "Read {} bytes of memory at 0x{:x}", len, addr)
+            }
+            LogEvent::CodeModified { pc, addr, opcode } => write!(
+                f,
+                "0x{:x}: Overwrote instruction {:04x} at 0x{:x}, which has already run",
+                pc, opcode, addr
+            ),
+            LogEvent::BreakpointHit { pc } => write!(f, "0x{:x}: Breakpoint hit", pc),
+            LogEvent::KeysChanged { keys } => write!(f, "Set new keys: {:#b}", keys),
+            LogEvent::DisplayCleared => write!(f, "Display cleared"),
+            LogEvent::SpriteDrawn {
+                x,
+                y,
+                height,
+                collision,
+            } => write!(
+                f,
+                "Sprite drawn at ({}, {}) with height {}{}",
+                x,
+                y,
+                height,
+                if *collision { ", collision" } else { "" }
+            ),
+            LogEvent::TimersTicked {
+                delay_timer,
+                sound_timer,
+            } => write!(
+                f,
+                "Timers ticked: delay {}, sound {}",
+                delay_timer, sound_timer
+            ),
+        }
+    }
+}
+
+/// @return the registers as a JSON object.
+fn cpu_state_json(state: &CpuState) -> String {
+    format!(
+        r#"{{"registers":{:?},"pointer":{},"stack_pointer":{},"delay_timer":{},"sound_timer":{}}}"#,
+        state.registers, state.pointer, state.stack_pointer, state.delay_timer, state.sound_timer
+    )
+}
+
+/// @return the text as a quoted JSON string.
+fn json_string(text: &str) -> String {
+    let mut json = String::with_capacity(text.len() + 2);
+    json.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
@@ -1,55 +1,228 @@
+pub mod coverage_log_source;
 pub mod file_log_source;
+pub mod filter_log_source;
+pub mod json_lines_log_source;
+pub mod log_event;
 pub mod null_log_source;
+pub mod profiler_log_source;
+pub mod ring_buffer_log_source;
+pub mod tee_log_source;
+pub mod trace;
+pub mod trace_file_log_source;
 
 use file_log_source::FileLogSource;
+use json_lines_log_source::JsonLinesLogSource;
 use null_log_source::NullLogSource;
 
+pub use log_event::LogEvent;
+
 use std::env;
 use std::fmt::Debug;
 use std::fs::OpenOptions;
 
+/// How much detail a message carries. A logger writes messages at or below its level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    /// Every instruction executed.
+    Trace,
+}
+
+impl LogLevel {
+    pub const NAMES: [&str; 5] = ["error", "warn", "info", "debug", "trace"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "error" => Some(LogLevel::Error),
+            "warn" => Some(LogLevel::Warn),
+            "info" => Some(LogLevel::Info),
+            "debug" => Some(LogLevel::Debug),
+            "trace" => Some(LogLevel::Trace),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        Self::NAMES[*self as usize]
+    }
+}
+
+/// The part of the emulator a message is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogCategory {
+    General = 1 << 0,
+    Cpu = 1 << 1,
+    Display = 1 << 2,
+    Input = 1 << 3,
+    Timers = 1 << 4,
+}
+
+/// A set of `LogCategory` bits.
+pub type LogCategories = u8;
+
+pub const ALL_LOG_CATEGORIES: LogCategories = 0x1F;
+
+impl LogCategory {
+    pub const NAMES: [&str; 5] = ["general", "cpu", "display", "input", "timers"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "general" => Some(LogCategory::General),
+            "cpu" => Some(LogCategory::Cpu),
+            "display" => Some(LogCategory::Display),
+            "input" => Some(LogCategory::Input),
+            "timers" => Some(LogCategory::Timers),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        Self::NAMES[(*self as u8).trailing_zeros() as usize]
+    }
+}
+
+/// Parse a comma separated list of category names, or `all`.
+pub fn parse_log_categories(names: &str) -> Option<LogCategories> {
+    if names == "all" {
+        return Some(ALL_LOG_CATEGORIES);
+    }
+    names.split(',').try_fold(0, |categories, name| {
+        LogCategory::from_name(name.trim()).map(|category| categories | category as u8)
+    })
+}
+
 #[derive(Debug)]
 pub struct Logger {
     log_source: Box<dyn LogSource>,
+    level: LogLevel,
+    categories: LogCategories,
+    records_trace_entries: bool,
 }
 
 impl Logger {
+    /// Create a logger that writes events at every level and in every category.
     pub fn new(log_source: Box<dyn LogSource>) -> Self {
-        Logger { log_source }
+        let records_trace_entries = log_source.records_trace_entries();
+        Logger {
+            log_source,
+            level: LogLevel::Trace,
+            categories: ALL_LOG_CATEGORIES,
+            records_trace_entries,
+        }
     }
 
+    /// Create a logger with no categories enabled, so nothing is formatted or written.
     pub fn new_null_logger() -> Self {
         Logger {
             log_source: Box::new(NullLogSource::new()),
+            level: LogLevel::Error,
+            categories: 0,
+            records_trace_entries: false,
         }
     }
 
+    /// @return the log source, to wrap it in another.
+    pub fn into_log_source(self) -> Box<dyn LogSource> {
+        self.log_source
+    }
+
+    pub fn get_level(&self) -> LogLevel {
+        self.level
+    }
+
+    pub fn set_level(&mut self, level: LogLevel) {
+        self.level = level;
+    }
+
+    pub fn get_categories(&self) -> LogCategories {
+        self.categories
+    }
+
+    pub fn set_categories(&mut self, categories: LogCategories) {
+        self.categories = categories;
+    }
+
+    /// @return whether events at this level and category are written. Check this before
+    /// building an expensive message.
+    #[inline]
+    pub fn enabled(&self, level: LogLevel, category: LogCategory) -> bool {
+        level <= self.level && self.categories & category as u8 != 0
+    }
+
+    /// @return whether `InstructionTraced` events are written and wanted, so the processor
+    /// only copies its registers around each instruction when something records them.
+    #[inline]
+    pub fn records_trace_entries(&self) -> bool {
+        self.records_trace_entries && self.enabled(LogLevel::Trace, LogCategory::Cpu)
+    }
+
+    /// Write a general message at the info level.
     pub fn log(&mut self, message: &str) {
-        self.log_source.as_mut().write(message);
+        self.log_at(LogLevel::Info, LogCategory::General, message);
+    }
+
+    pub fn log_at(&mut self, level: LogLevel, category: LogCategory, message: &str) {
+        self.log_event(LogEvent::Message {
+            level,
+            category,
+            text: message,
+        });
+    }
+
+    #[inline]
+    pub fn log_event(&mut self, event: LogEvent) {
+        if self.enabled(event.level(), event.category()) {
+            self.log_source.as_mut().write_event(&event);
+        }
     }
 }
 
 pub trait LogSource: Debug + Send + Sync {
     fn write(&mut self, message: &str);
+
+    /// Write a structured event. By default the event is written as a message.
+    fn write_event(&mut self, event: &LogEvent) {
+        self.write(&event.to_string());
+    }
+
+    /// @return whether this source wants `InstructionTraced` events. They are only built
+    /// when some source does, since they copy the registers before and after each
+    /// instruction.
+    fn records_trace_entries(&self) -> bool {
+        false
+    }
 }
 
 pub fn from_env_args() -> Logger {
     let log_file_path_option = env::args()
         .flat_map(|str| str.strip_prefix("--log_file=").map(|str| str.to_string()))
         .next();
-    let log_source: Box<dyn LogSource> = match log_file_path_option {
-        Some(log_file_path) => {
-            let file_open_error_msg = format!("Failed to open log file: {}", log_file_path);
-            let log_file = OpenOptions::new()
-                .append(true)
-                .create(true)
-                .open(log_file_path)
-                .expect(file_open_error_msg.as_str());
-            Box::new(FileLogSource::new(log_file))
-        }
-        None => Box::new(NullLogSource::new()),
-    };
-    Logger::new(log_source)
+    match log_file_path_option {
+        Some(log_file_path) => open_log_file(&log_file_path)
+            .unwrap_or_else(|e| panic!("Failed to open log file: {}: {}", log_file_path, e)),
+        None => Logger::new_null_logger(),
+    }
+}
+
+/// Create a logger that appends to a file, creating it if needed.
+pub fn open_log_file(log_file_path: &str) -> std::io::Result<Logger> {
+    let log_file = OpenOptions::new()
+        .append(true)
+        .create(true)
+        .open(log_file_path)?;
+    Ok(Logger::new(Box::new(FileLogSource::new(log_file))))
+}
+
+/// Create a logger that appends events to a file as JSON lines, creating it if needed.
+pub fn open_json_log_file(log_file_path: &str) -> std::io::Result<Logger> {
+    let log_file = OpenOptions::new()
+        .append(true)
+        .create(true)
+        .open(log_file_path)?;
+    Ok(Logger::new(Box::new(JsonLinesLogSource::new(log_file))))
 }
 
 #[cfg(test)]
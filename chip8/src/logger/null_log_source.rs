@@ -1,4 +1,4 @@
-use super::LogSource;
+use super::{LogEvent, LogSource};
 
 #[derive(Debug)]
 pub struct NullLogSource {}
@@ -11,4 +11,6 @@ impl NullLogSource {
 
 impl LogSource for NullLogSource {
     fn write(&mut self, _message: &str) {}
+
+    fn write_event(&mut self, _event: &LogEvent) {}
 }
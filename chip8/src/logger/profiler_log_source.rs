@@ -0,0 +1,31 @@
+use super::{LogEvent, LogSource};
+use crate::profiler::Profile;
+
+use std::sync::{Arc, Mutex};
+
+/// Counts each instruction executed into a shared `Profile`. Other events are skipped.
+#[derive(Debug)]
+pub struct ProfilerLogSource {
+    profile: Arc<Mutex<Profile>>,
+}
+
+impl ProfilerLogSource {
+    /// @return the log source and the profile it counts into.
+    pub fn new() -> (Self, Arc<Mutex<Profile>>) {
+        let profile = Arc::new(Mutex::new(Profile::new()));
+        let log_source = ProfilerLogSource {
+            profile: profile.clone(),
+        };
+        (log_source, profile)
+    }
+}
+
+impl LogSource for ProfilerLogSource {
+    fn write(&mut self, _message: &str) {}
+
+    fn write_event(&mut self, event: &LogEvent) {
+        if let LogEvent::InstructionExecuted { pc, instruction } = event {
+            self.profile.lock().unwrap().record(*pc, *instruction);
+        }
+    }
+}
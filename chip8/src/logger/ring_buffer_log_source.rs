@@ -0,0 +1,127 @@
+use super::trace::TraceEntry;
+use super::{LogEvent, LogLevel, LogSource};
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+/// Keeps the last executed instructions in memory instead of writing every one, so that
+/// tracing can stay on through long sessions.
+///
+/// The buffer is dumped to the dump writer, if there is one, whenever a warning, error or
+/// breakpoint is logged. Warnings are logged while an instruction runs, so that dump waits
+/// until the instruction's own entry is recorded. Use the `TraceBuffer` handle to read or
+/// dump the buffer on demand.
+pub struct RingBufferLogSource {
+    trace_buffer: TraceBuffer,
+    dump_writer: Option<Box<dyn Write + Send + Sync>>,
+    /// Why the buffer should be dumped after the next entry.
+    pending_dump: Option<String>,
+}
+
+/// A shared handle to the instructions kept by a `RingBufferLogSource`.
+#[derive(Debug, Clone)]
+pub struct TraceBuffer {
+    entries: Arc<Mutex<VecDeque<TraceEntry>>>,
+    capacity: usize,
+}
+
+impl RingBufferLogSource {
+    /// @return the log source and a handle to its buffer, which keeps the last `capacity`
+    /// instructions.
+    pub fn new(
+        capacity: usize,
+        dump_writer: Option<Box<dyn Write + Send + Sync>>,
+    ) -> (Self, TraceBuffer) {
+        let trace_buffer = TraceBuffer {
+            entries: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+            capacity,
+        };
+        let log_source = RingBufferLogSource {
+            trace_buffer: trace_buffer.clone(),
+            dump_writer,
+            pending_dump: None,
+        };
+        (log_source, trace_buffer)
+    }
+
+    fn dump(&mut self, reason: &str) {
+        if let Some(ref mut dump_writer) = self.dump_writer {
+            let _ = writeln!(dump_writer, "Trace dump: {}", reason)
+                .and_then(|_| self.trace_buffer.dump(dump_writer.as_mut()))
+                .and_then(|_| dump_writer.flush());
+        }
+    }
+}
+
+impl fmt::Debug for RingBufferLogSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RingBufferLogSource")
+            .field("trace_buffer", &self.trace_buffer)
+            .field("pending_dump", &self.pending_dump)
+            .finish_non_exhaustive()
+    }
+}
+
+impl LogSource for RingBufferLogSource {
+    fn write(&mut self, _message: &str) {}
+
+    fn write_event(&mut self, event: &LogEvent) {
+        match event {
+            LogEvent::InstructionTraced { entry } => {
+                self.trace_buffer.push(**entry);
+                if let Some(reason) = self.pending_dump.take() {
+                    self.dump(&reason);
+                }
+            }
+            LogEvent::BreakpointHit { .. } => self.dump(&event.to_string()),
+            _ if event.level() <= LogLevel::Warn && self.dump_writer.is_some() => {
+                self.pending_dump.get_or_insert_with(|| event.to_string());
+            }
+            _ => {}
+        }
+    }
+
+    fn records_trace_entries(&self) -> bool {
+        true
+    }
+}
+
+impl Drop for RingBufferLogSource {
+    fn drop(&mut self) {
+        if let Some(reason) = self.pending_dump.take() {
+            self.dump(&reason);
+        }
+    }
+}
+
+impl TraceBuffer {
+    fn push(&self, entry: TraceEntry) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut entries = self.entries.lock().unwrap();
+        if entries.len() == self.capacity {
+            entries.pop_front();
+        }
+        entries.push_back(entry);
+    }
+
+    /// @return the kept instructions, oldest first.
+    pub fn get_entries(&self) -> Vec<TraceEntry> {
+        self.entries.lock().unwrap().iter().copied().collect()
+    }
+
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    /// Write the kept instructions, oldest first.
+    pub fn dump(&self, writer: &mut dyn Write) -> std::io::Result<()> {
+        for entry in self.entries.lock().unwrap().iter() {
+            writeln!(writer, "{}", entry)?;
+        }
+        Ok(())
+    }
+}
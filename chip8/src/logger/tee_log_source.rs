@@ -0,0 +1,33 @@
+use super::{LogEvent, LogSource};
+
+/// Writes every message and event to each of several log sources.
+#[derive(Debug)]
+pub struct TeeLogSource {
+    log_sources: Vec<Box<dyn LogSource>>,
+}
+
+impl TeeLogSource {
+    pub fn new(log_sources: Vec<Box<dyn LogSource>>) -> Self {
+        TeeLogSource { log_sources }
+    }
+}
+
+impl LogSource for TeeLogSource {
+    fn write(&mut self, message: &str) {
+        for log_source in self.log_sources.iter_mut() {
+            log_source.write(message);
+        }
+    }
+
+    fn write_event(&mut self, event: &LogEvent) {
+        for log_source in self.log_sources.iter_mut() {
+            log_source.write_event(event);
+        }
+    }
+
+    fn records_trace_entries(&self) -> bool {
+        self.log_sources
+            .iter()
+            .any(|log_source| log_source.records_trace_entries())
+    }
+}
@@ -1,6 +1,14 @@
+use crate::Chip8;
 use crate::logger::LogSource;
 
-use super::Logger;
+use super::filter_log_source::FilterLogSource;
+use super::json_lines_log_source::JsonLinesLogSource;
+use super::null_log_source::NullLogSource;
+use super::ring_buffer_log_source::RingBufferLogSource;
+use super::tee_log_source::TeeLogSource;
+use super::trace::{CpuState, TraceEntry};
+use super::{ALL_LOG_CATEGORIES, LogCategory, LogEvent, LogLevel, Logger, parse_log_categories};
+use chip8_instructions::Instruction;
 
 use std::sync::{Arc, Mutex};
 
@@ -27,3 +35,316 @@ fn test_logger_logs() {
 
     assert_eq!("Hello there friend", *log_str.lock().unwrap());
 }
+
+#[test]
+fn test_logger_skips_messages_above_level() {
+    let log_str = Arc::new(Mutex::new("".to_owned()));
+    let test_log_source = TestLogSource {
+        log_str: log_str.clone(),
+    };
+
+    let mut logger = Logger::new(Box::new(test_log_source));
+    logger.set_level(LogLevel::Info);
+    logger.log_at(LogLevel::Trace, LogCategory::Cpu, "Too detailed");
+    assert_eq!("", *log_str.lock().unwrap());
+
+    logger.log_at(LogLevel::Warn, LogCategory::Cpu, "Important");
+    assert_eq!("Important", *log_str.lock().unwrap());
+}
+
+#[test]
+fn test_log_level_from_name() {
+    for name in LogLevel::NAMES {
+        assert!(LogLevel::from_name(name).is_some(), "{}", name);
+    }
+    assert_eq!(Some(LogLevel::Debug), LogLevel::from_name("debug"));
+    assert_eq!(None, LogLevel::from_name("verbose"));
+}
+
+#[test]
+fn test_logger_skips_disabled_categories() {
+    let log_str = Arc::new(Mutex::new("".to_owned()));
+    let test_log_source = TestLogSource {
+        log_str: log_str.clone(),
+    };
+
+    let mut logger = Logger::new(Box::new(test_log_source));
+    logger.set_categories(LogCategory::Input as u8 | LogCategory::Timers as u8);
+    assert!(logger.enabled(LogLevel::Trace, LogCategory::Input));
+    assert!(!logger.enabled(LogLevel::Error, LogCategory::Cpu));
+
+    logger.log_event(LogEvent::InstructionExecuted {
+        pc: 0x200,
+        instruction: Instruction::ClearDisplay,
+    });
+    assert_eq!("", *log_str.lock().unwrap());
+
+    logger.log_event(LogEvent::KeysChanged { keys: 0b101 });
+    assert_eq!("Set new keys: 0b101", *log_str.lock().unwrap());
+}
+
+#[test]
+fn test_null_logger_enables_nothing() {
+    let logger = Logger::new_null_logger();
+    assert!(!logger.enabled(LogLevel::Error, LogCategory::General));
+}
+
+#[test]
+fn test_event_text() {
+    let event = LogEvent::InstructionExecuted {
+        pc: 0x202,
+        instruction: Instruction::LoadImmToReg { x: 1, byte: 5 },
+    };
+    assert_eq!(
+        "0x202: Executing instruction: LoadImmToReg { x: 1, byte: 5 }",
+        event.to_string()
+    );
+    assert_eq!(LogLevel::Trace, event.level());
+    assert_eq!(LogCategory::Cpu, event.category());
+}
+
+#[test]
+fn test_code_modified_event() {
+    let event = LogEvent::CodeModified {
+        pc: 0x20A,
+        addr: 0x206,
+        opcode: 0x120A,
+    };
+    assert_eq!(
+        "0x20a: Overwrote instruction 120a at 0x206, which has already run",
+        event.to_string()
+    );
+    assert_eq!(
+        r#"{"level":"info","category":"cpu","event":"code_modified","pc":522,"addr":518,"opcode":4618}"#,
+        event.to_json()
+    );
+}
+
+#[test]
+fn test_event_json() {
+    let event = LogEvent::SpriteDrawn {
+        x: 3,
+        y: 4,
+        height: 5,
+        collision: true,
+    };
+    assert_eq!(
+        r#"{"level":"trace","category":"display","event":"sprite_drawn","x":3,"y":4,"height":5,"collision":true}"#,
+        event.to_json()
+    );
+    assert_eq!(
+        r#"{"level":"debug","category":"display","event":"display_cleared"}"#,
+        LogEvent::DisplayCleared.to_json()
+    );
+    let message = LogEvent::Message {
+        level: LogLevel::Info,
+        category: LogCategory::General,
+        text: "say \"hi\"\n",
+    };
+    assert_eq!(
+        r#"{"level":"info","category":"general","event":"message","text":"say \"hi\"\n"}"#,
+        message.to_json()
+    );
+}
+
+#[derive(Debug, Clone)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl std::io::Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_json_lines_log_source() {
+    let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+    let mut logger = Logger::new(Box::new(JsonLinesLogSource::new(buffer.clone())));
+    logger.log("Starting");
+    logger.log_event(LogEvent::JumpLoopDetected { pc: 0x204 });
+    assert_eq!(
+        concat!(
+            r#"{"level":"info","category":"general","event":"message","text":"Starting"}"#,
+            "\n",
+            r#"{"level":"info","category":"cpu","event":"jump_loop_detected","pc":516}"#,
+            "\n"
+        ),
+        String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap()
+    );
+}
+
+#[test]
+fn test_filter_log_source() {
+    let log_str = Arc::new(Mutex::new("".to_owned()));
+    let test_log_source = TestLogSource {
+        log_str: log_str.clone(),
+    };
+    let filter = FilterLogSource::new(
+        Box::new(test_log_source),
+        LogLevel::Warn,
+        ALL_LOG_CATEGORIES,
+    );
+
+    let mut logger = Logger::new(Box::new(filter));
+    logger.log_event(LogEvent::DisplayCleared);
+    assert_eq!("", *log_str.lock().unwrap());
+    logger.log_event(LogEvent::UnknownInstruction {
+        pc: 0x200,
+        byte1: 0xFF,
+        byte2: 0xFF,
+    });
+    assert_eq!(
+        "0x200: Unknown instruction 0xff 0xff",
+        *log_str.lock().unwrap()
+    );
+}
+
+#[test]
+fn test_parse_log_categories() {
+    assert_eq!(Some(ALL_LOG_CATEGORIES), parse_log_categories("all"));
+    assert_eq!(
+        Some(LogCategory::Cpu as u8 | LogCategory::Timers as u8),
+        parse_log_categories("cpu, timers")
+    );
+    assert_eq!(None, parse_log_categories("cpu,sound"));
+    for name in LogCategory::NAMES {
+        assert_eq!(name, LogCategory::from_name(name).unwrap().name());
+    }
+}
+
+// 0x200: LD V0, 0x01
+// 0x202: ADD V0, 0x01
+// 0x204: ADD V0, 0x01
+// 0x206: unknown
+const TRACED_PROGRAM: [u8; 8] = [0x60, 0x01, 0x70, 0x01, 0x70, 0x01, 0xFF, 0xFF];
+
+#[test]
+fn test_ring_buffer_keeps_last_instructions() {
+    let (ring_buffer, trace_buffer) = RingBufferLogSource::new(2, None);
+    let mut chip8 = Chip8::new(Logger::new(Box::new(ring_buffer)));
+    chip8.initialize(&TRACED_PROGRAM);
+    for _ in 0..3 {
+        chip8.run_next_instruction();
+    }
+
+    let entries = trace_buffer.get_entries();
+    assert_eq!(
+        vec![0x202, 0x204],
+        entries.iter().map(|entry| entry.pc).collect::<Vec<u16>>()
+    );
+    assert_eq!(0x7001, entries[1].opcode);
+    assert_eq!(2, entries[1].before.registers[0]);
+    assert_eq!(3, entries[1].after.registers[0]);
+
+    trace_buffer.clear();
+    assert!(trace_buffer.get_entries().is_empty());
+}
+
+#[test]
+fn test_ring_buffer_dumps_after_unknown_instruction() {
+    let dump = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+    let (ring_buffer, _) = RingBufferLogSource::new(2, Some(Box::new(dump.clone())));
+    let mut chip8 = Chip8::new(Logger::new(Box::new(ring_buffer)));
+    chip8.initialize(&TRACED_PROGRAM);
+    for _ in 0..3 {
+        chip8.run_next_instruction();
+    }
+    assert!(dump.0.lock().unwrap().is_empty());
+
+    chip8.run_next_instruction();
+    let text = String::from_utf8(dump.0.lock().unwrap().clone()).unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(7, lines.len(), "{}", text);
+    assert_eq!("Trace dump: 0x206: Unknown instruction 0xff 0xff", lines[0]);
+    assert_eq!("0x204: 7001 AddImmToReg { x: 0, byte: 1 }", lines[1]);
+    assert_eq!("0x206: ffff Unknown { byte1: 255, byte2: 255 }", lines[4]);
+}
+
+#[test]
+fn test_null_logger_records_no_trace_entries() {
+    assert!(!Logger::new_null_logger().records_trace_entries());
+
+    let (ring_buffer, _) = RingBufferLogSource::new(2, None);
+    let mut logger = Logger::new(Box::new(ring_buffer));
+    assert!(logger.records_trace_entries());
+    logger.set_level(LogLevel::Debug);
+    assert!(!logger.records_trace_entries());
+}
+
+#[test]
+fn test_filter_log_source_records_trace_entries() {
+    let filter = |level: LogLevel, categories: u8| {
+        let (ring_buffer, _) = RingBufferLogSource::new(2, None);
+        FilterLogSource::new(Box::new(ring_buffer), level, categories)
+    };
+    assert!(filter(LogLevel::Trace, ALL_LOG_CATEGORIES).records_trace_entries());
+    assert!(filter(LogLevel::Trace, LogCategory::Cpu as u8).records_trace_entries());
+    assert!(!filter(LogLevel::Debug, ALL_LOG_CATEGORIES).records_trace_entries());
+    assert!(!filter(LogLevel::Trace, LogCategory::Display as u8).records_trace_entries());
+
+    let null_filter = FilterLogSource::new(
+        Box::new(NullLogSource {}),
+        LogLevel::Trace,
+        ALL_LOG_CATEGORIES,
+    );
+    assert!(!null_filter.records_trace_entries());
+
+    let mut logger = Logger::new(Box::new(filter(LogLevel::Trace, ALL_LOG_CATEGORIES)));
+    assert!(logger.records_trace_entries());
+    logger.set_level(LogLevel::Info);
+    assert!(!logger.records_trace_entries());
+}
+
+#[test]
+fn test_trace_entry_text() {
+    let mut before = CpuState::default();
+    before.registers[0xF] = 0x12;
+    let entry = TraceEntry {
+        pc: 0x20a,
+        opcode: 0x6f13,
+        instruction: Instruction::LoadImmToReg { x: 0xF, byte: 0x13 },
+        before,
+        after: CpuState {
+            registers: [0x13; 16],
+            pointer: 0x300,
+            stack_pointer: 1,
+            delay_timer: 2,
+            sound_timer: 3,
+        },
+    };
+    assert_eq!(
+        concat!(
+            "0x20a: 6f13 LoadImmToReg { x: 15, byte: 19 }\n",
+            "  before V 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 12  I 000  SP 0  DT 00  ST 00\n",
+            "  after  V 13 13 13 13 13 13 13 13 13 13 13 13 13 13 13 13  I 300  SP 1  DT 02  ST 03"
+        ),
+        entry.to_string()
+    );
+}
+
+#[test]
+fn test_tee_log_source() {
+    let first = Arc::new(Mutex::new("".to_owned()));
+    let second = Arc::new(Mutex::new("".to_owned()));
+    let (ring_buffer, trace_buffer) = RingBufferLogSource::new(4, None);
+    let tee = TeeLogSource::new(vec![
+        Box::new(TestLogSource {
+            log_str: first.clone(),
+        }),
+        Box::new(TestLogSource {
+            log_str: second.clone(),
+        }),
+        Box::new(ring_buffer),
+    ]);
+
+    let mut logger = Logger::new(Box::new(tee));
+    assert!(logger.records_trace_entries());
+    logger.log("Both");
+    assert_eq!("Both", *first.lock().unwrap());
+    assert_eq!("Both", *second.lock().unwrap());
+    assert!(trace_buffer.get_entries().is_empty());
+}
@@ -0,0 +1,49 @@
+use chip8_instructions::Instruction;
+use std::fmt;
+
+/// The registers an instruction can change, other than the program counter.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CpuState {
+    pub registers: [u8; 16],
+    pub pointer: u16,
+    pub stack_pointer: usize,
+    pub delay_timer: u8,
+    pub sound_timer: u8,
+}
+
+/// An executed instruction with the state before and after it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TraceEntry {
+    pub pc: u16,
+    pub opcode: u16,
+    pub instruction: Instruction,
+    pub before: CpuState,
+    pub after: CpuState,
+}
+
+impl fmt::Display for CpuState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "V")?;
+        for register in self.registers.iter() {
+            write!(f, " {:02x}", register)?;
+        }
+        write!(
+            f,
+            "  I {:03x}  SP {}  DT {:02x}  ST {:02x}",
+            self.pointer, self.stack_pointer, self.delay_timer, self.sound_timer
+        )
+    }
+}
+
+/// Formats the entry as three lines: the instruction, then the state before and after.
+impl fmt::Display for TraceEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "0x{:03x}: {:04x} {:?}",
+            self.pc, self.opcode, self.instruction
+        )?;
+        writeln!(f, "  before {}", self.before)?;
+        write!(f, "  after  {}", self.after)
+    }
+}
@@ -0,0 +1,37 @@
+use super::{LogEvent, LogSource};
+use crate::trace_file::TraceLine;
+
+use std::fmt;
+use std::io::Write;
+
+/// Writes a trace file with a line for each instruction executed. See `TraceLine` for the
+/// format. Other events are skipped.
+pub struct TraceFileLogSource<W: Write + Send + Sync> {
+    writer: W,
+}
+
+impl<W: Write + Send + Sync> TraceFileLogSource<W> {
+    pub fn new(writer: W) -> Self {
+        TraceFileLogSource { writer }
+    }
+}
+
+impl<W: Write + Send + Sync> fmt::Debug for TraceFileLogSource<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TraceFileLogSource").finish_non_exhaustive()
+    }
+}
+
+impl<W: Write + Send + Sync> LogSource for TraceFileLogSource<W> {
+    fn write(&mut self, _message: &str) {}
+
+    fn write_event(&mut self, event: &LogEvent) {
+        if let LogEvent::InstructionTraced { entry } = event {
+            let _ = writeln!(self.writer, "{}", TraceLine::from_entry(entry));
+        }
+    }
+
+    fn records_trace_entries(&self) -> bool {
+        true
+    }
+}
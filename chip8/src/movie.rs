@@ -0,0 +1,172 @@
+use crate::Chip8;
+use crate::Chip8Keys;
+use crate::quirks::Quirks;
+
+use std::io::Write;
+
+const MAGIC: &str = "chip8-movie 1";
+
+/// @return the 64-bit FNV-1a hash of a ROM, used to check a movie is played on its ROM.
+pub fn rom_hash(program: &[u8]) -> u64 {
+    program.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Everything besides the keys needed to reproduce a run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MovieHeader {
+    pub rom_hash: u64,
+    pub seed: u64,
+    pub quirks: Quirks,
+    pub instructions_per_frame: u32,
+}
+
+/// A recorded run: the header followed by the keys held during each frame.
+///
+/// The file is text, so it can be attached to bug reports and diffed:
+///
+/// ```text
+/// chip8-movie 1
+/// rom_hash 8c5b2a1f0e3d4c6b
+/// seed 1234
+/// quirks vf_reset=0 shift_uses_vy=0 memory_increments_i=0 jump_offset_uses_vx=0 clip_sprites=0
+/// instructions_per_frame 20
+/// frames
+/// 0000
+/// 0020
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Movie {
+    pub header: MovieHeader,
+    pub frames: Vec<Chip8Keys>,
+}
+
+impl MovieHeader {
+    /// Describe a run of `program` with the processor's current seed and quirks.
+    pub fn new(chip8: &Chip8, program: &[u8], instructions_per_frame: u32) -> Self {
+        Self {
+            rom_hash: rom_hash(program),
+            seed: chip8.get_seed(),
+            quirks: chip8.get_quirks(),
+            instructions_per_frame,
+        }
+    }
+
+    /// Set up the processor to replay the movie from the start.
+    pub fn start_playback(&self, chip8: &mut Chip8, program: &[u8]) -> Result<(), String> {
+        if rom_hash(program) != self.rom_hash {
+            return Err(format!(
+                "The movie was recorded with a different ROM. Expected hash {:016x}, found {:016x}.",
+                self.rom_hash,
+                rom_hash(program)
+            ));
+        }
+        chip8.set_seed(self.seed);
+        chip8.set_quirks(self.quirks);
+        chip8.initialize(program);
+        Ok(())
+    }
+
+    pub fn write_to(&self, writer: &mut dyn Write) -> std::io::Result<()> {
+        let quirks: Vec<String> = self
+            .quirks
+            .to_flags()
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, *value as u8))
+            .collect();
+        writeln!(writer, "{}", MAGIC)?;
+        writeln!(writer, "rom_hash {:016x}", self.rom_hash)?;
+        writeln!(writer, "seed {}", self.seed)?;
+        writeln!(writer, "quirks {}", quirks.join(" "))?;
+        writeln!(
+            writer,
+            "instructions_per_frame {}",
+            self.instructions_per_frame
+        )?;
+        writeln!(writer, "frames")
+    }
+}
+
+/// Append the keys for one frame to a movie whose header has been written.
+pub fn write_frame(writer: &mut dyn Write, keys: Chip8Keys) -> std::io::Result<()> {
+    writeln!(writer, "{:04x}", keys)
+}
+
+impl Movie {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut lines = text.lines().enumerate();
+        let mut next_line = |expected: &str| {
+            lines
+                .next()
+                .ok_or(format!("Expected {} but the movie ended.", expected))
+        };
+
+        let (_, magic) = next_line(MAGIC)?;
+        if magic != MAGIC {
+            return Err(format!("Expected `{}` on the first line.", MAGIC));
+        }
+
+        let mut get_value = |name: &str| -> Result<(usize, String), String> {
+            let (line_num, line) = next_line(name)?;
+            match line.split_once(' ') {
+                Some((key, value)) if key == name => Ok((line_num, value.to_string())),
+                _ => Err(format!("Line {}: Expected {}.", line_num + 1, name)),
+            }
+        };
+        let error = |line_num: usize, message: &str| format!("Line {}: {}", line_num + 1, message);
+
+        let (line_num, value) = get_value("rom_hash")?;
+        let rom_hash =
+            u64::from_str_radix(&value, 16).map_err(|_| error(line_num, "Invalid ROM hash."))?;
+        let (line_num, value) = get_value("seed")?;
+        let seed = value
+            .parse()
+            .map_err(|_| error(line_num, "Invalid seed."))?;
+        let (line_num, value) = get_value("quirks")?;
+        let mut quirks = Quirks::default();
+        for flag in value.split_whitespace() {
+            let valid = match flag.split_once('=') {
+                Some((name, "0")) => quirks.set_flag(name, false),
+                Some((name, "1")) => quirks.set_flag(name, true),
+                _ => false,
+            };
+            if !valid {
+                return Err(error(line_num, format!("Invalid quirk: {}", flag).as_str()));
+            }
+        }
+        let (line_num, value) = get_value("instructions_per_frame")?;
+        let instructions_per_frame = value
+            .parse()
+            .map_err(|_| error(line_num, "Invalid instructions per frame."))?;
+
+        let (line_num, frames_line) = next_line("frames")?;
+        if frames_line != "frames" {
+            return Err(error(line_num, "Expected frames."));
+        }
+        let frames = lines
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(line_num, line)| {
+                Chip8Keys::from_str_radix(line, 16).map_err(|_| error(line_num, "Invalid keys."))
+            })
+            .collect::<Result<Vec<Chip8Keys>, String>>()?;
+
+        Ok(Self {
+            header: MovieHeader {
+                rom_hash,
+                seed,
+                quirks,
+                instructions_per_frame,
+            },
+            frames,
+        })
+    }
+
+    pub fn write_to(&self, writer: &mut dyn Write) -> std::io::Result<()> {
+        self.header.write_to(writer)?;
+        for keys in self.frames.iter() {
+            write_frame(writer, *keys)?;
+        }
+        Ok(())
+    }
+}
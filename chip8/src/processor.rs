@@ -1,9 +1,12 @@
 use super::display::*;
 use super::key::{Keys, keys_to_key_number};
-use crate::logger::Logger;
+use crate::logger::trace::{CpuState, TraceEntry};
+use crate::logger::{LogCategory, LogEvent, LogLevel, Logger};
+use crate::quirks::Quirks;
 
 use chip8_instructions::*;
-use fastrand;
+use fastrand::Rng;
+use std::collections::BTreeSet;
 
 const DIGIT_SPRITES: [u8; 80] = [
     0xF0, 0x90, 0x90, 0x90, 0xF0, // 0
@@ -29,17 +32,51 @@ const DISPLAY_BYTES: usize = (DISPLAY_PIXELS_X * DISPLAY_PIXELS_Y + 0x7) >> 3;
 const DISPLAY_PIXELS_X: usize = 64;
 const DISPLAY_PIXELS_Y: usize = 32;
 const PROGRAM_MEM_ADDR: usize = 0x200;
+const MEMORY_BYTES: usize = 4096;
+/// The largest program `initialize` can load, from 0x200 to the end of memory.
+pub const MAX_PROGRAM_BYTES: usize = MEMORY_BYTES - PROGRAM_MEM_ADDR;
+/// Instructions per frame the frontends run unless told otherwise, for `run_frame`.
+pub const DEFAULT_INSTRUCTIONS_PER_FRAME: u32 = 10;
+/// The most instructions per frame the frontends accept.
+pub const MAX_INSTRUCTIONS_PER_FRAME: u32 = 10000;
+/// The program counter wraps around the 12 bit address space, like jump addresses.
+const ADDR_MASK: u16 = 0xFFF;
+
+/// A write by the program over one of its own instructions that had already run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CodeWrite {
+    /// The address of the writing instruction.
+    pub pc: u16,
+    /// The address of the overwritten instruction.
+    pub addr: u16,
+    /// The overwritten instruction before the write.
+    pub opcode: u16,
+}
 
 #[derive(Debug)]
 pub struct Processor {
     logger: Logger,
     registers: Registers,
     display: [u8; DISPLAY_BYTES],
-    memory: [u8; 4096],
+    display_version: u32,
+    memory: [u8; MEMORY_BYTES],
+    /// The decoded instruction starting at each address, cleared when its bytes are written.
+    decoded: Vec<Option<Instruction>>,
+    /// Whether an instruction starting at each address has run.
+    executed: Vec<bool>,
+    /// The bytes of instructions that had run and were then overwritten.
+    modified_code: BTreeSet<u16>,
+    /// The first write over code by the last instruction.
+    last_code_write: Option<CodeWrite>,
     stack: [u16; 16],
     keys: Keys,
     wait_for_key: WaitForKey,
     exit: bool,
+    /// Set by `initialize`. Running before then is a bug in the caller.
+    initialized: bool,
+    quirks: Quirks,
+    seed: u64,
+    rng: Rng,
 }
 
 #[derive(Debug)]
@@ -61,6 +98,7 @@ enum WaitForKey {
 impl Processor {
     pub fn new(mut logger: Logger) -> Self {
         logger.log("Processor::new");
+        let seed = fastrand::u64(..);
         Self {
             logger,
             registers: Registers {
@@ -72,11 +110,20 @@ impl Processor {
                 sound_timer: 0,
             },
             display: [0; DISPLAY_BYTES],
-            memory: [0; 4096],
+            display_version: 0,
+            memory: [0; MEMORY_BYTES],
+            decoded: vec![None; MEMORY_BYTES],
+            executed: vec![false; MEMORY_BYTES],
+            modified_code: BTreeSet::new(),
+            last_code_write: None,
             stack: [0; 16],
             keys: 0,
             wait_for_key: WaitForKey::NotWaiting,
             exit: false,
+            initialized: false,
+            quirks: Quirks::default(),
+            seed,
+            rng: Rng::with_seed(seed),
         }
     }
 
@@ -89,18 +136,26 @@ impl Processor {
         self.registers.delay_timer = 0;
         self.registers.sound_timer = 0;
         self.display.fill(0);
+        self.display_version = self.display_version.wrapping_add(1);
         self.stack.fill(0);
         self.keys = 0;
         self.wait_for_key = WaitForKey::NotWaiting;
         self.exit = false;
+        self.initialized = true;
+        self.rng = Rng::with_seed(self.seed);
 
+        self.executed.fill(false);
+        self.modified_code.clear();
+        self.last_code_write = None;
         self.memory.fill(0);
+        self.decoded.fill(None);
         self.memory[DIGIT_SPRITES_MEM_ADDR..DIGIT_SPRITES_MEM_ADDR + DIGIT_SPRITES.len()]
             .copy_from_slice(&DIGIT_SPRITES);
         self.memory[PROGRAM_MEM_ADDR..PROGRAM_MEM_ADDR + program.len()].copy_from_slice(program);
     }
 
     pub fn run_next_instruction(&mut self) -> bool {
+        assert!(self.initialized, "Processor run before initialize.");
         if self.exit {
             return true;
         }
@@ -109,27 +164,314 @@ impl Processor {
             return false;
         }
 
-        let byte1 = self.memory[self.registers.program_counter as usize];
-        let byte2 = self.memory[(self.registers.program_counter + 1) as usize];
-        let instruction = decode_instruction(byte1, byte2);
-        self.logger.log(
-            format!(
-                "0x{:x}: Executing instruction: {:?}",
-                self.registers.program_counter, instruction
-            )
-            .as_str(),
-        );
-        self.registers.program_counter += 2;
+        let pc = self.registers.program_counter;
+        let instruction = self.fetch(pc);
+        self.logger
+            .log_event(LogEvent::InstructionExecuted { pc, instruction });
+        self.executed[pc as usize] = true;
+        self.last_code_write = None;
+        self.skip_instruction();
+
+        if self.logger.records_trace_entries() {
+            let before = self.get_cpu_state();
+            self.execute_instruction(instruction);
+            let entry = TraceEntry {
+                pc,
+                opcode: u16::from_be_bytes([
+                    self.memory[pc as usize],
+                    self.memory[(pc as usize + 1) % MEMORY_BYTES],
+                ]),
+                instruction,
+                before,
+                after: self.get_cpu_state(),
+            };
+            self.logger
+                .log_event(LogEvent::InstructionTraced { entry: &entry });
+            return self.exit;
+        }
 
         self.execute_instruction(instruction);
         return self.exit;
     }
 
+    /// Run up to `num_instructions` instructions, stopping early if the program exits or
+    /// waits for a key.
+    ///
+    /// Unless the logger wants every executed instruction, this skips the per-instruction
+    /// logging of `run_next_instruction`, and only checks whether to stop after the
+    /// instructions that can exit or wait for a key.
+    /// @return the number of instructions run.
+    pub fn run(&mut self, num_instructions: u32) -> u32 {
+        if self.is_logging_instructions() {
+            for count in 0..num_instructions {
+                if self.is_halted() {
+                    return count;
+                }
+                self.run_next_instruction();
+            }
+            return num_instructions;
+        }
+
+        assert!(self.initialized, "Processor run before initialize.");
+        if self.is_halted() {
+            return 0;
+        }
+        for count in 0..num_instructions {
+            let pc = self.registers.program_counter;
+            let instruction = self.fetch(pc);
+            self.executed[pc as usize] = true;
+            self.last_code_write = None;
+            self.skip_instruction();
+            self.execute_instruction(instruction);
+
+            let can_halt = matches!(
+                instruction,
+                Instruction::Exit | Instruction::Jump { .. } | Instruction::LoadNextKeyPress { .. }
+            );
+            if can_halt && self.is_halted() {
+                return count + 1;
+            }
+        }
+        num_instructions
+    }
+
+    /// Move the program counter past an instruction, wrapping at the end of memory.
+    #[inline]
+    fn skip_instruction(&mut self) {
+        self.registers.program_counter = (self.registers.program_counter + 2) & ADDR_MASK;
+    }
+
+    /// @return the address of the instruction being executed, which the program counter has
+    /// already moved past.
+    fn instruction_addr(&self) -> u16 {
+        self.registers.program_counter.wrapping_sub(2) & ADDR_MASK
+    }
+
+    /// @return true if the logger wants an event for every executed instruction, which
+    /// faster ways of running instructions have to fall back to `run_next_instruction` for.
+    pub fn is_logging_instructions(&self) -> bool {
+        self.logger.enabled(LogLevel::Trace, LogCategory::Cpu)
+    }
+
+    fn is_halted(&self) -> bool {
+        self.exit || self.is_waiting_for_key()
+    }
+
+    /// @return the instruction at `pc`, decoding it only if memory there has changed since
+    /// it was last decoded.
+    #[inline]
+    fn fetch(&mut self, pc: u16) -> Instruction {
+        if let Some(instruction) = self.decoded[pc as usize] {
+            return instruction;
+        }
+        let instruction = decode_instruction(
+            self.memory[pc as usize],
+            self.memory[(pc as usize + 1) % MEMORY_BYTES],
+        );
+        self.decoded[pc as usize] = Some(instruction);
+        instruction
+    }
+
+    /// Write a byte of memory and drop the decoded instructions that include it.
+    fn write_memory(&mut self, addr: usize, value: u8) {
+        self.memory[addr] = value;
+        self.decoded[addr] = None;
+        self.decoded[(addr + MEMORY_BYTES - 1) % MEMORY_BYTES] = None;
+    }
+
+    fn get_cpu_state(&self) -> CpuState {
+        CpuState {
+            registers: self.registers.general,
+            pointer: self.registers.pointer,
+            stack_pointer: self.registers.stack_pointer,
+            delay_timer: self.registers.delay_timer,
+            sound_timer: self.registers.sound_timer,
+        }
+    }
+
+    /// Note writes by the instruction at `pc` to `addr..addr + len` that land on
+    /// instructions which have already run, before the memory is changed.
+    fn check_code_write(&mut self, pc: u16, addr: u16, len: u16) {
+        let start = addr as usize;
+        let end = (start + len as usize).min(MEMORY_BYTES);
+        // An instruction starting just before the write has its second byte overwritten.
+        for code_addr in start.saturating_sub(1)..end {
+            if !self.executed[code_addr] {
+                continue;
+            }
+            let code_write = CodeWrite {
+                pc,
+                addr: code_addr as u16,
+                opcode: u16::from_be_bytes([
+                    self.memory[code_addr],
+                    self.memory[(code_addr + 1) % MEMORY_BYTES],
+                ]),
+            };
+            self.logger.log_event(LogEvent::CodeModified {
+                pc: code_write.pc,
+                addr: code_write.addr,
+                opcode: code_write.opcode,
+            });
+            self.last_code_write.get_or_insert(code_write);
+            for byte_addr in code_addr..code_addr + 2 {
+                if (start..end).contains(&byte_addr) {
+                    self.modified_code.insert(byte_addr as u16);
+                }
+            }
+        }
+    }
+
+    /// @return the first write over an instruction that had already run made by the last
+    /// instruction, such as `LD [I], V3` rewriting the program.
+    pub fn get_last_code_write(&self) -> Option<CodeWrite> {
+        self.last_code_write
+    }
+
+    /// @return the addresses of the bytes of instructions that had run before the program
+    /// wrote over them.
+    pub fn get_modified_code(&self) -> &BTreeSet<u16> {
+        &self.modified_code
+    }
+
+    /// @return true if an instruction starting at the address has run.
+    pub fn has_executed(&self, addr: u16) -> bool {
+        self.executed.get(addr as usize).copied().unwrap_or(false)
+    }
+
+    /// Write an event to the processor's logger, for tools built on top of it.
+    pub fn log_event(&mut self, event: LogEvent) {
+        self.logger.log_event(event);
+    }
+
+    pub fn get_quirks(&self) -> Quirks {
+        self.quirks
+    }
+
+    pub fn set_quirks(&mut self, quirks: Quirks) {
+        self.quirks = quirks;
+    }
+
+    /// @return the seed for `RND`. A random seed is chosen in `new`.
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Set the seed for `RND`, which takes effect on the next `initialize`. Runs with the
+    /// same program, seed, quirks and keys per frame are identical.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+    }
+
+    /// Run one frame: up to `num_instructions` instructions followed by a timer tick.
+    /// @return true if the program has exited.
+    pub fn run_frame(&mut self, num_instructions: u32) -> bool {
+        self.run(num_instructions);
+        if self.exit {
+            return true;
+        }
+        self.tick_timers();
+        self.exit
+    }
+
     pub fn get_display<'a>(&'a self) -> Display<'a> {
         Display::new(&self.display, DISPLAY_PIXELS_X, DISPLAY_PIXELS_Y)
     }
 
+    /// @return a counter that changes whenever the display may have changed, so callers
+    /// can skip redrawing an unchanged frame.
+    pub fn get_display_version(&self) -> u32 {
+        self.display_version
+    }
+
+    pub fn get_register(&self, x: u8) -> u8 {
+        self.registers.general[x as usize]
+    }
+
+    pub fn set_register(&mut self, x: u8, value: u8) {
+        self.registers.general[x as usize] = value;
+    }
+
+    pub fn get_registers(&self) -> &[u8; 16] {
+        &self.registers.general
+    }
+
+    pub fn set_registers(&mut self, registers: &[u8; 16]) {
+        self.registers.general = *registers;
+    }
+
+    pub fn get_pointer(&self) -> u16 {
+        self.registers.pointer
+    }
+
+    pub fn set_pointer(&mut self, addr: u16) {
+        self.registers.pointer = addr;
+    }
+
+    pub fn get_program_counter(&self) -> u16 {
+        self.registers.program_counter
+    }
+
+    /// Set the program counter, wrapped to the 12 bit address space like jump addresses.
+    pub fn set_program_counter(&mut self, addr: u16) {
+        self.registers.program_counter = addr & ADDR_MASK;
+    }
+
+    pub fn get_stack_pointer(&self) -> usize {
+        self.registers.stack_pointer
+    }
+
+    /// @return the return addresses currently on the stack, oldest first.
+    pub fn get_stack(&self) -> &[u16] {
+        &self.stack[..self.registers.stack_pointer]
+    }
+
+    pub fn get_delay_timer(&self) -> u8 {
+        self.registers.delay_timer
+    }
+
+    pub fn set_delay_timer(&mut self, value: u8) {
+        self.registers.delay_timer = value;
+    }
+
+    pub fn get_sound_timer(&self) -> u8 {
+        self.registers.sound_timer
+    }
+
+    pub fn set_sound_timer(&mut self, value: u8) {
+        self.registers.sound_timer = value;
+    }
+
+    /// @return the memory in `addr..addr + len`, clamped to the end of memory.
+    pub fn get_memory(&self, addr: u16, len: usize) -> &[u8] {
+        let start = (addr as usize).min(self.memory.len());
+        let end = start.saturating_add(len).min(self.memory.len());
+        &self.memory[start..end]
+    }
+
+    /// Write bytes into memory starting at `addr`. Bytes past the end of memory are dropped.
+    pub fn set_memory(&mut self, addr: u16, bytes: &[u8]) {
+        let start = (addr as usize).min(self.memory.len());
+        let end = start.saturating_add(bytes.len()).min(self.memory.len());
+        for (i, byte) in bytes[..end - start].iter().enumerate() {
+            self.write_memory(start + i, *byte);
+        }
+    }
+
+    pub fn is_exited(&self) -> bool {
+        self.exit
+    }
+
+    pub fn is_waiting_for_key(&self) -> bool {
+        matches!(self.wait_for_key, WaitForKey::Waiting { .. })
+    }
+
     pub fn tick_timers(&mut self) -> () {
+        if self.registers.delay_timer > 0 || self.registers.sound_timer > 0 {
+            self.logger.log_event(LogEvent::TimersTicked {
+                delay_timer: self.registers.delay_timer,
+                sound_timer: self.registers.sound_timer,
+            });
+        }
         if self.registers.delay_timer > 0 {
             self.registers.delay_timer -= 1;
         }
@@ -144,8 +486,7 @@ impl Processor {
 
     pub fn set_keys(&mut self, keys: Keys) -> () {
         if keys != self.keys {
-            self.logger
-                .log(format!("Set new keys: {:#b}", keys).as_str());
+            self.logger.log_event(LogEvent::KeysChanged { keys });
         }
 
         if let WaitForKey::Waiting { x } = self.wait_for_key {
@@ -162,7 +503,9 @@ impl Processor {
     fn execute_instruction(&mut self, instruction: Instruction) -> () {
         match instruction {
             Instruction::ClearDisplay => {
+                self.logger.log_event(LogEvent::DisplayCleared);
                 self.display.fill(0);
+                self.display_version = self.display_version.wrapping_add(1);
             }
             Instruction::Return => {
                 self.registers.stack_pointer -= 1;
@@ -172,8 +515,9 @@ impl Processor {
                 self.exit = true;
             }
             Instruction::Jump { addr } => {
-                if self.registers.program_counter - 2 == addr {
-                    self.logger.log("Jump loop detected. Exiting.");
+                if self.instruction_addr() == addr {
+                    self.logger
+                        .log_event(LogEvent::JumpLoopDetected { pc: addr });
                     self.exit = true;
                 }
                 self.registers.program_counter = addr;
@@ -185,17 +529,17 @@ impl Processor {
             }
             Instruction::SkipRegEqualsImm { x, byte } => {
                 if self.registers.general[x as usize] == byte {
-                    self.registers.program_counter += 2;
+                    self.skip_instruction();
                 }
             }
             Instruction::SkipRegNotEqualsImm { x, byte } => {
                 if self.registers.general[x as usize] != byte {
-                    self.registers.program_counter += 2;
+                    self.skip_instruction();
                 }
             }
             Instruction::SkipRegEqualsReg { x, y } => {
                 if self.registers.general[x as usize] == self.registers.general[y as usize] {
-                    self.registers.program_counter += 2;
+                    self.skip_instruction();
                 }
             }
             Instruction::LoadImmToReg { x, byte } => {
@@ -210,12 +554,15 @@ impl Processor {
             }
             Instruction::OrReg { x, y } => {
                 self.registers.general[x as usize] |= self.registers.general[y as usize];
+                self.reset_vf_quirk();
             }
             Instruction::AndReg { x, y } => {
                 self.registers.general[x as usize] &= self.registers.general[y as usize];
+                self.reset_vf_quirk();
             }
             Instruction::XorReg { x, y } => {
                 self.registers.general[x as usize] ^= self.registers.general[y as usize];
+                self.reset_vf_quirk();
             }
             Instruction::AddReg { x, y } => {
                 let x_val: u16 = self.registers.general[x as usize] as u16;
@@ -230,8 +577,8 @@ impl Processor {
                 self.registers.general[x as usize] = x_val.wrapping_sub(y_val);
                 self.registers.general[0xF] = if x_val >= y_val { 1 } else { 0 };
             }
-            Instruction::ShiftRight { x } => {
-                let x_val = self.registers.general[x as usize];
+            Instruction::ShiftRight { x, y } => {
+                let x_val = self.registers.general[self.shift_source(x, y) as usize];
                 self.registers.general[x as usize] = x_val >> 1;
                 self.registers.general[0xF] = x_val & 0x1;
             }
@@ -241,35 +588,56 @@ impl Processor {
                 self.registers.general[x as usize] = y_val.wrapping_sub(x_val);
                 self.registers.general[0xF] = if y_val >= x_val { 1 } else { 0 };
             }
-            Instruction::ShiftLeft { x } => {
-                let x_val = self.registers.general[x as usize];
+            Instruction::ShiftLeft { x, y } => {
+                let x_val = self.registers.general[self.shift_source(x, y) as usize];
                 self.registers.general[x as usize] = x_val << 1;
                 self.registers.general[0xF] = if x_val & 0x80 != 0 { 1 } else { 0 };
             }
             Instruction::SkipRegNotEqualsReg { x, y } => {
                 if self.registers.general[x as usize] != self.registers.general[y as usize] {
-                    self.registers.program_counter += 2;
+                    self.skip_instruction();
                 }
             }
             Instruction::LoadImmToPointer { addr } => {
                 self.registers.pointer = addr;
             }
             Instruction::JumpOffset { addr } => {
-                self.registers.program_counter = (self.registers.general[0] as u16) + addr;
+                let offset_register = if self.quirks.jump_offset_uses_vx {
+                    (addr >> 8) as usize
+                } else {
+                    0
+                };
+                self.registers.program_counter =
+                    ((self.registers.general[offset_register] as u16) + addr) & ADDR_MASK;
             }
             Instruction::Random { x, byte } => {
-                self.registers.general[x as usize] = fastrand::u8(0..=u8::MAX) & byte;
+                self.registers.general[x as usize] = self.rng.u8(..) & byte;
             }
             Instruction::Draw { x, y, nibble } => {
+                self.logger.log_event(LogEvent::MemoryRead {
+                    addr: self.registers.pointer,
+                    len: nibble as u16,
+                });
                 let mut erase = false;
+                // The starting position always wraps. Pixels past the edges wrap or are
+                // clipped depending on the quirk.
+                let start_col = self.registers.general[x as usize] as usize % DISPLAY_PIXELS_X;
+                let start_row = self.registers.general[y as usize] as usize % DISPLAY_PIXELS_Y;
                 for i in 0..nibble {
                     for j in 0..8 {
                         let sprite_bit = (self.memory
                             [(self.registers.pointer as usize) + (i as usize)]
                             >> (7 - j))
                             & 1;
-                        let col = self.registers.general[x as usize].wrapping_add(j);
-                        let row = self.registers.general[y as usize].wrapping_add(i);
+                        let col = start_col + j as usize;
+                        let row = start_row + i as usize;
+                        if self.quirks.clip_sprites
+                            && (col >= DISPLAY_PIXELS_X || row >= DISPLAY_PIXELS_Y)
+                        {
+                            continue;
+                        }
+                        let col = (col % DISPLAY_PIXELS_X) as u8;
+                        let row = (row % DISPLAY_PIXELS_Y) as u8;
                         let (display_byte, display_bit) =
                             get_display_bit(col, row, DISPLAY_PIXELS_X);
                         let before = self.display[display_byte];
@@ -281,15 +649,22 @@ impl Processor {
                     }
                 }
                 self.registers.general[0xF] = if erase { 1 } else { 0 };
+                self.display_version = self.display_version.wrapping_add(1);
+                self.logger.log_event(LogEvent::SpriteDrawn {
+                    x: start_col as u8,
+                    y: start_row as u8,
+                    height: nibble,
+                    collision: erase,
+                });
             }
             Instruction::SkipKeyPressed { x } => {
                 if self.keys & (1 << self.registers.general[x as usize]) != 0 {
-                    self.registers.program_counter += 2;
+                    self.skip_instruction();
                 }
             }
             Instruction::SkipNotKeyPressed { x } => {
                 if self.keys & (1 << self.registers.general[x as usize]) == 0 {
-                    self.registers.program_counter += 2;
+                    self.skip_instruction();
                 }
             }
             Instruction::LoadDelayTimerToReg { x } => {
@@ -317,28 +692,59 @@ impl Processor {
                 let tens = (x / 10) % 10;
                 let hundreds = (x / 100) % 10;
 
-                self.memory[self.registers.pointer as usize] = hundreds;
-                self.memory[(self.registers.pointer + 1) as usize] = tens;
-                self.memory[(self.registers.pointer + 2) as usize] = ones;
+                let pc = self.instruction_addr();
+                self.check_code_write(pc, self.registers.pointer, 3);
+                let pointer = self.registers.pointer as usize;
+                self.write_memory(pointer, hundreds);
+                self.write_memory(pointer + 1, tens);
+                self.write_memory(pointer + 2, ones);
             }
             Instruction::WriteRegToPointer { x } => {
-                for i in 0..=(x as u16) {
-                    self.memory[(self.registers.pointer + i) as usize] =
-                        self.registers.general[i as usize];
+                let pc = self.instruction_addr();
+                self.check_code_write(pc, self.registers.pointer, x as u16 + 1);
+                for i in 0..=(x as usize) {
+                    self.write_memory(
+                        self.registers.pointer as usize + i,
+                        self.registers.general[i],
+                    );
+                }
+                if self.quirks.memory_increments_i {
+                    self.registers.pointer += x as u16 + 1;
                 }
             }
             Instruction::ReadRegFromPointer { x } => {
+                self.logger.log_event(LogEvent::MemoryRead {
+                    addr: self.registers.pointer,
+                    len: x as u16 + 1,
+                });
                 for i in 0..=(x as u16) {
                     self.registers.general[i as usize] =
                         self.memory[(self.registers.pointer + i) as usize];
                 }
+                if self.quirks.memory_increments_i {
+                    self.registers.pointer += x as u16 + 1;
+                }
             }
             Instruction::Unknown { byte1, byte2 } => {
-                self.logger
-                    .log(format!("Unknown instruction 0x{:x} 0x{:x}", byte1, byte2).as_str());
+                self.logger.log_event(LogEvent::UnknownInstruction {
+                    pc: self.instruction_addr(),
+                    byte1,
+                    byte2,
+                });
             }
         }
     }
+
+    /// @return the register shifted by `SHR` and `SHL`.
+    fn shift_source(&self, x: u8, y: u8) -> u8 {
+        if self.quirks.shift_uses_vy { y } else { x }
+    }
+
+    fn reset_vf_quirk(&mut self) {
+        if self.quirks.vf_reset {
+            self.registers.general[0xF] = 0;
+        }
+    }
 }
 
 /// @return the (byte, bit) to index into display memory.
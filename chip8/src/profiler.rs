@@ -0,0 +1,273 @@
+use crate::symbol_map::SymbolMap;
+
+use chip8_instructions::Instruction;
+use std::collections::HashMap;
+
+const MEMORY_BYTES: usize = 4096;
+
+/// COSMAC VIP machine cycles in a 60 Hz frame, each cycle taking about 4.54 µs.
+pub const VIP_CYCLES_PER_FRAME: u64 = 3668;
+
+/// Executions and estimated cycles spent on something.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Counts {
+    pub executions: u64,
+    pub cycles: u64,
+}
+
+/// Time spent in a subroutine. Inclusive counts take in the subroutines it calls, and
+/// exclusive counts only its own instructions, from its first instruction to its `RET`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SubroutineCounts {
+    pub calls: u64,
+    pub inclusive: Counts,
+    pub exclusive: Counts,
+}
+
+/// A subroutine that has been called and not yet returned.
+#[derive(Debug, Clone, Copy)]
+struct Frame {
+    addr: u16,
+    total_at_call: Counts,
+}
+
+/// Counts of the instructions a program runs, by address, by kind and by subroutine, with
+/// cycles estimated from the original COSMAC VIP interpreter's timings.
+#[derive(Debug, Clone)]
+pub struct Profile {
+    total: Counts,
+    by_addr: Vec<Counts>,
+    by_name: HashMap<&'static str, Counts>,
+    subroutines: HashMap<u16, SubroutineCounts>,
+    call_stack: Vec<Frame>,
+}
+
+impl Profile {
+    pub fn new() -> Self {
+        Self {
+            total: Counts::default(),
+            by_addr: vec![Counts::default(); MEMORY_BYTES],
+            by_name: HashMap::new(),
+            subroutines: HashMap::new(),
+            call_stack: Vec::new(),
+        }
+    }
+
+    /// Count an instruction about to run at `pc`.
+    pub fn record(&mut self, pc: u16, instruction: Instruction) {
+        let (name, cycles) = instruction_cost(instruction);
+        let add = |counts: &mut Counts| {
+            counts.executions += 1;
+            counts.cycles += cycles;
+        };
+        add(&mut self.total);
+        if let Some(counts) = self.by_addr.get_mut(pc as usize) {
+            add(counts);
+        }
+        add(self.by_name.entry(name).or_default());
+        if let Some(frame) = self.call_stack.last() {
+            add(&mut self.subroutines.entry(frame.addr).or_default().exclusive);
+        }
+
+        match instruction {
+            Instruction::Call { addr } => {
+                self.subroutines.entry(addr).or_default().calls += 1;
+                self.call_stack.push(Frame {
+                    addr,
+                    total_at_call: self.total,
+                });
+            }
+            Instruction::Return => {
+                if let Some(frame) = self.call_stack.pop() {
+                    self.add_inclusive(frame);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub fn get_total(&self) -> Counts {
+        self.total
+    }
+
+    /// @return the counts for the instruction at an address.
+    pub fn get_addr_counts(&self, addr: u16) -> Counts {
+        self.by_addr.get(addr as usize).copied().unwrap_or_default()
+    }
+
+    /// @return the counts for an `Instruction` variant by name, such as `Draw`.
+    pub fn get_instruction_counts(&self, name: &str) -> Counts {
+        self.by_name.get(name).copied().unwrap_or_default()
+    }
+
+    /// @return the counts for the subroutine at an address, including time in calls that
+    /// have not returned yet.
+    pub fn get_subroutine_counts(&self, addr: u16) -> SubroutineCounts {
+        self.finish()
+            .subroutines
+            .get(&addr)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Add a returned call's time to its subroutine's inclusive counts, unless it is
+    /// still running further up the stack, so recursion is not counted twice.
+    fn add_inclusive(&mut self, frame: Frame) {
+        if self.call_stack.iter().any(|outer| outer.addr == frame.addr) {
+            return;
+        }
+        let inclusive = &mut self.subroutines.entry(frame.addr).or_default().inclusive;
+        inclusive.executions += self.total.executions - frame.total_at_call.executions;
+        inclusive.cycles += self.total.cycles - frame.total_at_call.cycles;
+    }
+
+    /// @return a copy with every call still running returned.
+    fn finish(&self) -> Self {
+        let mut profile = self.clone();
+        while let Some(frame) = profile.call_stack.pop() {
+            profile.add_inclusive(frame);
+        }
+        profile
+    }
+
+    /// @return a text report of the `top` hottest addresses, the instructions by kind and
+    /// the subroutines, naming addresses from the symbol map if given.
+    pub fn report(&self, symbols: Option<&SymbolMap>, top: usize) -> String {
+        let profile = self.finish();
+        let total = profile.total;
+        let describe = |addr: u16| {
+            let name = symbols.and_then(|symbols| symbols.describe(addr));
+            format!("0x{:03x} {}", addr, name.unwrap_or_default())
+        };
+        let percent = |cycles: u64| 100.0 * cycles as f64 / total.cycles.max(1) as f64;
+
+        let mut report = format!(
+            "{} instructions, about {} VIP cycles ({:.1} frames at VIP speed)\n",
+            total.executions,
+            total.cycles,
+            total.cycles as f64 / VIP_CYCLES_PER_FRAME as f64
+        );
+
+        report += "\nHottest addresses:\n";
+        report += &format!(
+            "  {:<28} {:>12} {:>14} {:>7}\n",
+            "address", "executions", "cycles", "cycles%"
+        );
+        let mut by_addr: Vec<(u16, Counts)> = (0..MEMORY_BYTES as u16)
+            .map(|addr| (addr, profile.by_addr[addr as usize]))
+            .filter(|(_, counts)| counts.executions > 0)
+            .collect();
+        by_addr.sort_by_key(|(addr, counts)| (std::cmp::Reverse(counts.cycles), *addr));
+        for (addr, counts) in by_addr.iter().take(top) {
+            report += &format!(
+                "  {:<28} {:>12} {:>14} {:>6.1}%\n",
+                describe(*addr),
+                counts.executions,
+                counts.cycles,
+                percent(counts.cycles)
+            );
+        }
+
+        report += "\nBy instruction:\n";
+        report += &format!(
+            "  {:<28} {:>12} {:>14} {:>7}\n",
+            "instruction", "executions", "cycles", "cycles%"
+        );
+        let mut by_name: Vec<(&str, Counts)> = profile
+            .by_name
+            .iter()
+            .map(|(name, counts)| (*name, *counts))
+            .collect();
+        by_name.sort_by_key(|(name, counts)| (std::cmp::Reverse(counts.cycles), *name));
+        for (name, counts) in by_name.iter() {
+            report += &format!(
+                "  {:<28} {:>12} {:>14} {:>6.1}%\n",
+                name,
+                counts.executions,
+                counts.cycles,
+                percent(counts.cycles)
+            );
+        }
+
+        if profile.subroutines.is_empty() {
+            return report;
+        }
+        report += "\nSubroutines:\n";
+        report += &format!(
+            "  {:<28} {:>8} {:>14} {:>7} {:>14} {:>7}\n",
+            "subroutine", "calls", "incl cycles", "incl%", "excl cycles", "excl%"
+        );
+        let mut subroutines: Vec<(u16, SubroutineCounts)> = profile
+            .subroutines
+            .iter()
+            .map(|(addr, counts)| (*addr, *counts))
+            .collect();
+        subroutines
+            .sort_by_key(|(addr, counts)| (std::cmp::Reverse(counts.inclusive.cycles), *addr));
+        for (addr, counts) in subroutines.iter() {
+            report += &format!(
+                "  {:<28} {:>8} {:>14} {:>6.1}% {:>14} {:>6.1}%\n",
+                describe(*addr),
+                counts.calls,
+                counts.inclusive.cycles,
+                percent(counts.inclusive.cycles),
+                counts.exclusive.cycles,
+                percent(counts.exclusive.cycles)
+            );
+        }
+        report
+    }
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// @return the name of the instruction's `Instruction` variant and its approximate cost in
+/// COSMAC VIP machine cycles.
+///
+/// The costs are typical timings of the original interpreter. The instructions that loop
+/// over registers or rows scale with them, and `DRW` leaves out waiting for the display
+/// interrupt, which depends on when it runs.
+pub fn instruction_cost(instruction: Instruction) -> (&'static str, u64) {
+    match instruction {
+        Instruction::Unknown { .. } => ("Unknown", 0),
+        Instruction::ClearDisplay => ("ClearDisplay", 24),
+        Instruction::Return => ("Return", 23),
+        Instruction::Exit => ("Exit", 0),
+        Instruction::Jump { .. } => ("Jump", 23),
+        Instruction::Call { .. } => ("Call", 23),
+        Instruction::SkipRegEqualsImm { .. } => ("SkipRegEqualsImm", 12),
+        Instruction::SkipRegNotEqualsImm { .. } => ("SkipRegNotEqualsImm", 12),
+        Instruction::SkipRegEqualsReg { .. } => ("SkipRegEqualsReg", 16),
+        Instruction::LoadImmToReg { .. } => ("LoadImmToReg", 6),
+        Instruction::AddImmToReg { .. } => ("AddImmToReg", 10),
+        Instruction::LoadRegToReg { .. } => ("LoadRegToReg", 44),
+        Instruction::OrReg { .. } => ("OrReg", 44),
+        Instruction::AndReg { .. } => ("AndReg", 44),
+        Instruction::XorReg { .. } => ("XorReg", 44),
+        Instruction::AddReg { .. } => ("AddReg", 44),
+        Instruction::SubReg { .. } => ("SubReg", 44),
+        Instruction::ShiftRight { .. } => ("ShiftRight", 44),
+        Instruction::SubNegReg { .. } => ("SubNegReg", 44),
+        Instruction::ShiftLeft { .. } => ("ShiftLeft", 44),
+        Instruction::SkipRegNotEqualsReg { .. } => ("SkipRegNotEqualsReg", 16),
+        Instruction::LoadImmToPointer { .. } => ("LoadImmToPointer", 12),
+        Instruction::JumpOffset { .. } => ("JumpOffset", 23),
+        Instruction::Random { .. } => ("Random", 36),
+        Instruction::Draw { nibble, .. } => ("Draw", 68 + 46 * nibble as u64),
+        Instruction::SkipKeyPressed { .. } => ("SkipKeyPressed", 16),
+        Instruction::SkipNotKeyPressed { .. } => ("SkipNotKeyPressed", 16),
+        Instruction::LoadDelayTimerToReg { .. } => ("LoadDelayTimerToReg", 10),
+        Instruction::LoadNextKeyPress { .. } => ("LoadNextKeyPress", 10),
+        Instruction::LoadRegToDelayTimer { .. } => ("LoadRegToDelayTimer", 10),
+        Instruction::LoadRegToSoundTimer { .. } => ("LoadRegToSoundTimer", 10),
+        Instruction::AddRegToPointer { .. } => ("AddRegToPointer", 19),
+        Instruction::LoadDigitSpriteToPointer { .. } => ("LoadDigitSpriteToPointer", 20),
+        Instruction::LoadDecimalDigitsToPointer { .. } => ("LoadDecimalDigitsToPointer", 204),
+        Instruction::WriteRegToPointer { x } => ("WriteRegToPointer", 14 + 14 * (x as u64 + 1)),
+        Instruction::ReadRegFromPointer { x } => ("ReadRegFromPointer", 14 + 14 * (x as u64 + 1)),
+    }
+}
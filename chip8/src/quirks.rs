@@ -0,0 +1,90 @@
+/// Behaviors that differ between CHIP-8 implementations. The default keeps this emulator's
+/// original behavior for every quirk but drawing: sprites now start at the position modulo
+/// the display size and wrap around its edges, where the original ran pixels past the right
+/// edge into the next row and could write past the end of the display.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Quirks {
+    /// `OR`, `AND` and `XOR` reset VF to 0.
+    pub vf_reset: bool,
+    /// `SHR Vx, Vy` and `SHL Vx, Vy` shift Vy into Vx instead of shifting Vx in place.
+    pub shift_uses_vy: bool,
+    /// `LD [I], Vx` and `LD Vx, [I]` leave I pointing past the last register.
+    pub memory_increments_i: bool,
+    /// `JP V0, addr` jumps to `addr + Vx`, where x is the high nibble of `addr`.
+    pub jump_offset_uses_vx: bool,
+    /// Sprites are clipped at the edges of the display instead of wrapping around.
+    pub clip_sprites: bool,
+}
+
+impl Quirks {
+    pub const PRESET_NAMES: [&str; 4] = ["default", "cosmac", "schip", "xochip"];
+
+    /// The original COSMAC VIP interpreter.
+    pub fn cosmac() -> Self {
+        Self {
+            vf_reset: true,
+            shift_uses_vy: true,
+            memory_increments_i: true,
+            jump_offset_uses_vx: false,
+            clip_sprites: true,
+        }
+    }
+
+    /// SUPER-CHIP 1.1.
+    pub fn schip() -> Self {
+        Self {
+            vf_reset: false,
+            shift_uses_vy: false,
+            memory_increments_i: false,
+            jump_offset_uses_vx: true,
+            clip_sprites: true,
+        }
+    }
+
+    /// XO-CHIP, as implemented by Octo.
+    pub fn xochip() -> Self {
+        Self {
+            vf_reset: false,
+            shift_uses_vy: true,
+            memory_increments_i: true,
+            jump_offset_uses_vx: false,
+            clip_sprites: false,
+        }
+    }
+
+    pub fn from_preset_name(name: &str) -> Option<Self> {
+        match name {
+            "default" => Some(Self::default()),
+            "cosmac" => Some(Self::cosmac()),
+            "schip" => Some(Self::schip()),
+            "xochip" => Some(Self::xochip()),
+            _ => None,
+        }
+    }
+
+    /// @return the quirks as `name=0|1` pairs, in a fixed order.
+    pub fn to_flags(&self) -> Vec<(&'static str, bool)> {
+        vec![
+            ("vf_reset", self.vf_reset),
+            ("shift_uses_vy", self.shift_uses_vy),
+            ("memory_increments_i", self.memory_increments_i),
+            ("jump_offset_uses_vx", self.jump_offset_uses_vx),
+            ("clip_sprites", self.clip_sprites),
+        ]
+    }
+
+    /// Set a quirk by the name used in `to_flags`.
+    /// @return false if there is no quirk with that name.
+    pub fn set_flag(&mut self, name: &str, value: bool) -> bool {
+        let flag = match name {
+            "vf_reset" => &mut self.vf_reset,
+            "shift_uses_vy" => &mut self.shift_uses_vy,
+            "memory_increments_i" => &mut self.memory_increments_i,
+            "jump_offset_uses_vx" => &mut self.jump_offset_uses_vx,
+            "clip_sprites" => &mut self.clip_sprites,
+            _ => return false,
+        };
+        *flag = value;
+        true
+    }
+}
@@ -0,0 +1,94 @@
+const MEMORY_BYTES: usize = 4096;
+
+/// The source line an instruction or data row was assembled from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SourceEntry {
+    pub addr: u16,
+    /// The line in the source file, counting from 1.
+    pub line_num: usize,
+    /// The number of bytes assembled from the line.
+    pub size: u16,
+}
+
+/// Where each part of a program came from in its assembly source, as written by the
+/// assembler's `-m` option.
+///
+/// The file names the source file, then has a line per instruction or data row with its
+/// address in hex, its line number and its size in bytes:
+///
+/// ```text
+/// source game.asm
+/// 0x200 3 2
+/// 0x202 4 2
+/// 0x220 12 5
+/// ```
+///
+/// Blank lines and lines starting with `#` are skipped.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SourceMap {
+    pub source_path: Option<String>,
+    pub entries: Vec<SourceEntry>,
+}
+
+impl SourceMap {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut source_map = Self::default();
+        for (line_num, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |message: &str| format!("Line {}: {}", line_num + 1, message);
+            if let Some(source_path) = line.strip_prefix("source ") {
+                source_map.source_path = Some(source_path.trim().to_string());
+                continue;
+            }
+
+            let words: Vec<&str> = line.split_whitespace().collect();
+            let [addr, source_line_num, size] = words[..] else {
+                return Err(error("Expected an address, a line number and a size."));
+            };
+            let hex = addr.strip_prefix("0x").unwrap_or(addr);
+            let entry = SourceEntry {
+                addr: u16::from_str_radix(hex, 16)
+                    .map_err(|_| error(format!("Invalid address: {}", addr).as_str()))?,
+                line_num: source_line_num.parse().map_err(|_| {
+                    error(format!("Invalid line number: {}", source_line_num).as_str())
+                })?,
+                size: size
+                    .parse()
+                    .map_err(|_| error(format!("Invalid size: {}", size).as_str()))?,
+            };
+            if entry.addr as usize + entry.size as usize > MEMORY_BYTES {
+                return Err(error(
+                    format!("Entry at {} runs past the end of memory.", addr).as_str(),
+                ));
+            }
+            source_map.entries.push(entry);
+        }
+        Ok(source_map)
+    }
+
+    /// @return the entry for the instruction or data row covering an address.
+    pub fn get_entry(&self, addr: u16) -> Option<&SourceEntry> {
+        self.entries.iter().find(|entry| {
+            entry.addr <= addr && (addr as u32) < entry.addr as u32 + entry.size as u32
+        })
+    }
+
+    /// @return the lowest address assembled from a source line, or from the first line
+    /// after it that has any, as a debugger places a breakpoint on a line without code.
+    pub fn find_addr(&self, line_num: usize) -> Option<u16> {
+        let first_line_num = self
+            .entries
+            .iter()
+            .map(|entry| entry.line_num)
+            .filter(|entry_line_num| *entry_line_num >= line_num)
+            .min()?;
+        self.entries
+            .iter()
+            .filter(|entry| entry.line_num == first_line_num)
+            .map(|entry| entry.addr)
+            .min()
+    }
+}
@@ -0,0 +1,73 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Names for addresses in a program, such as the labels written by the assembler's `-s`
+/// option.
+///
+/// The file has a line per symbol with its address in hex and its name:
+///
+/// ```text
+/// 0x200 main
+/// 0x21a draw_score
+/// ```
+///
+/// Blank lines and lines starting with `#` are skipped.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SymbolMap {
+    symbols: BTreeMap<u16, String>,
+}
+
+impl SymbolMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut symbol_map = Self::new();
+        for (line_num, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |message: &str| format!("Line {}: {}", line_num + 1, message);
+            let (addr, name) = line
+                .split_once(char::is_whitespace)
+                .ok_or(error("Expected an address and a name."))?;
+            let hex = addr.strip_prefix("0x").unwrap_or(addr);
+            let addr = u16::from_str_radix(hex, 16)
+                .map_err(|_| error(format!("Invalid address: {}", addr).as_str()))?;
+            symbol_map.insert(addr, name.trim());
+        }
+        Ok(symbol_map)
+    }
+
+    pub fn insert(&mut self, addr: u16, name: &str) {
+        self.symbols.insert(addr, name.to_string());
+    }
+
+    /// @return the name of the symbol at exactly this address.
+    pub fn get_name(&self, addr: u16) -> Option<&str> {
+        self.symbols.get(&addr).map(String::as_str)
+    }
+
+    /// @return the nearest symbol at or before the address, with the offset from it if
+    /// any, such as `draw_score+0x4`.
+    pub fn describe(&self, addr: u16) -> Option<String> {
+        let (symbol_addr, name) = self.symbols.range(..=addr).next_back()?;
+        if *symbol_addr == addr {
+            Some(name.clone())
+        } else {
+            Some(format!("{}+0x{:x}", name, addr - symbol_addr))
+        }
+    }
+}
+
+/// Formats the map in the file format read by `parse`.
+impl fmt::Display for SymbolMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (addr, name) in self.symbols.iter() {
+            writeln!(f, "0x{:03x} {}", addr, name)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use super::audio::*;
+
+#[test]
+fn test_silence() {
+    let mut renderer = AudioRenderer::new(8000);
+    assert_eq!(vec![0.0; 4], renderer.render_to_vec(false, 4));
+}
+
+#[test]
+fn test_square_wave() {
+    let mut renderer = AudioRenderer::new(8);
+    renderer.set_volume(1.0);
+    renderer.set_waveform(Waveform::Square { frequency_hz: 2.0 });
+    assert_eq!(
+        vec![1.0, 1.0, -1.0, -1.0, 1.0, 1.0, -1.0, -1.0],
+        renderer.render_to_vec(true, 8)
+    );
+}
+
+#[test]
+fn test_square_wave_continues_between_buffers() {
+    let mut renderer = AudioRenderer::new(8);
+    renderer.set_volume(1.0);
+    renderer.set_waveform(Waveform::Square { frequency_hz: 2.0 });
+    assert_eq!(vec![1.0, 1.0, -1.0], renderer.render_to_vec(true, 3));
+    assert_eq!(vec![-1.0, 1.0], renderer.render_to_vec(true, 2));
+}
+
+#[test]
+fn test_pattern() {
+    let mut bits = [0; 16];
+    bits[0] = 0b1010_0000;
+    let pattern = AudioPattern { bits, pitch: 64 };
+    assert_eq!(4000.0, pattern.playback_rate());
+
+    let mut renderer = AudioRenderer::new(4000);
+    renderer.set_volume(0.5);
+    renderer.set_waveform(Waveform::Pattern(pattern));
+    assert_eq!(
+        vec![0.5, -0.5, 0.5, -0.5, -0.5],
+        renderer.render_to_vec(true, 5)
+    );
+}
+
+#[test]
+fn test_samples_for_micros() {
+    let renderer = AudioRenderer::new(44100);
+    assert_eq!(441, renderer.samples_for_micros(10000));
+}
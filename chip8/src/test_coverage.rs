@@ -0,0 +1,76 @@
+use super::coverage::*;
+use crate::Chip8;
+use crate::logger::Logger;
+use crate::logger::coverage_log_source::CoverageLogSource;
+use crate::source_map::SourceMap;
+
+// 0x200: LD I, 0x20A
+// 0x202: DRW V0, V1, 2
+// 0x204: LD V1, [I]
+// 0x206: JP 0x206
+// 0x208: CLS
+// 0x20A: 0xF0 0x90
+const SPRITE_PROGRAM: [u8; 12] = [
+    0xA2, 0x0A, 0xD0, 0x12, 0xF1, 0x65, 0x12, 0x06, 0x00, 0xE0, 0xF0, 0x90,
+];
+
+fn cover_program(program: &[u8]) -> Coverage {
+    let (log_source, coverage) = CoverageLogSource::new();
+    let mut chip8 = Chip8::new(Logger::new(Box::new(log_source)));
+    chip8.initialize(program);
+    assert!(chip8.run_frame(100));
+    coverage.lock().unwrap().clone()
+}
+
+#[test]
+fn test_records_executions_and_reads() {
+    let coverage = cover_program(&SPRITE_PROGRAM);
+    assert_eq!(1, coverage.get_executions(0x202));
+    assert_eq!(0, coverage.get_executions(0x208));
+    assert_eq!(0, coverage.get_reads(0x209));
+    assert_eq!(2, coverage.get_reads(0x20A));
+    assert_eq!(2, coverage.get_reads(0x20B));
+    assert_eq!(0, coverage.get_reads(0x20C));
+}
+
+#[test]
+fn test_record_read_past_memory() {
+    let mut coverage = Coverage::new();
+    coverage.record_read(0xFFE, 4);
+    coverage.record_read(0x1000, 1);
+    assert_eq!(1, coverage.get_reads(0xFFF));
+    assert_eq!(0, coverage.get_reads(0x1000));
+}
+
+#[test]
+fn test_lcov() {
+    let coverage = cover_program(&SPRITE_PROGRAM);
+    let source_map = SourceMap::parse(
+        "source sprite.asm\n0x200 2 2\n0x202 3 2\n0x204 4 2\n0x206 6 2\n0x208 7 2\n0x20a 9 2",
+    )
+    .unwrap();
+    assert_eq!(
+        "TN:\nSF:sprite.asm\nDA:2,1\nDA:3,1\nDA:4,1\nDA:6,1\nDA:7,0\nDA:9,2\nLF:6\nLH:5\nend_of_record\n",
+        coverage.to_lcov(&source_map)
+    );
+}
+
+#[test]
+fn test_annotate() {
+    let coverage = cover_program(&SPRITE_PROGRAM);
+    let listing = coverage.annotate(&SPRITE_PROGRAM, 0x200, |byte1, byte2| {
+        format!("op {:02x}{:02x}", byte1, byte2)
+    });
+    assert_eq!(
+        concat!(
+            "         1  0x200  a20a  op a20a\n",
+            "         1  0x202  d012  op d012\n",
+            "         1  0x204  f165  op f165\n",
+            "         1  0x206  1206  op 1206\n",
+            "         -  0x208  00e0  op 00e0\n",
+            "        r2  0x20a  f0    0xF0\n",
+            "        r2  0x20b  90    0x90\n",
+        ),
+        listing
+    );
+}
@@ -0,0 +1,155 @@
+use super::debugger::*;
+use crate::Chip8;
+use crate::logger::Logger;
+use crate::logger::ring_buffer_log_source::RingBufferLogSource;
+
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+// 0x200: LD V0, 0x01
+// 0x202: ADD V0, 0x01
+// 0x204: JP 0x202
+const COUNTER_PROGRAM: [u8; 6] = [0x60, 0x01, 0x70, 0x01, 0x12, 0x02];
+
+fn new_chip8(program: &[u8]) -> Chip8 {
+    let mut chip8 = Chip8::new(Logger::new_null_logger());
+    chip8.initialize(program);
+    chip8
+}
+
+#[test]
+fn test_run_stops_at_breakpoint() {
+    let mut chip8 = new_chip8(&COUNTER_PROGRAM);
+    let mut debugger = Debugger::new();
+    debugger.add_breakpoint(0x204);
+
+    assert_eq!(
+        StopReason::Breakpoint { addr: 0x204 },
+        debugger.run(&mut chip8, 100)
+    );
+    assert_eq!(0x204, chip8.get_program_counter());
+    assert_eq!(2, chip8.get_register(0));
+}
+
+#[test]
+fn test_run_resumes_from_breakpoint() {
+    let mut chip8 = new_chip8(&COUNTER_PROGRAM);
+    let mut debugger = Debugger::new();
+    debugger.add_breakpoint(0x202);
+
+    assert_eq!(
+        StopReason::Breakpoint { addr: 0x202 },
+        debugger.run(&mut chip8, 100)
+    );
+    assert_eq!(1, chip8.get_register(0));
+    assert_eq!(
+        StopReason::Breakpoint { addr: 0x202 },
+        debugger.run(&mut chip8, 100)
+    );
+    assert_eq!(2, chip8.get_register(0));
+}
+
+#[test]
+fn test_run_instruction_limit() {
+    let mut chip8 = new_chip8(&COUNTER_PROGRAM);
+    let debugger = Debugger::new();
+
+    assert_eq!(StopReason::InstructionLimit, debugger.run(&mut chip8, 5));
+    assert_eq!(3, chip8.get_register(0));
+}
+
+#[test]
+fn test_step() {
+    let mut chip8 = new_chip8(&COUNTER_PROGRAM);
+    let mut debugger = Debugger::new();
+    debugger.add_breakpoint(0x200);
+
+    assert_eq!(StopReason::InstructionLimit, debugger.step(&mut chip8));
+    assert_eq!(0x202, chip8.get_program_counter());
+}
+
+#[test]
+fn test_stop_reasons() {
+    let debugger = Debugger::new();
+
+    // LD V0, K
+    let mut chip8 = new_chip8(&[0xF0, 0x0A]);
+    assert_eq!(StopReason::WaitingForKey, debugger.run(&mut chip8, 10));
+
+    // EXIT
+    let mut chip8 = new_chip8(&[0x00, 0xFD]);
+    assert_eq!(StopReason::Exited, debugger.step(&mut chip8));
+}
+
+#[test]
+fn test_breakpoint_management() {
+    let mut debugger = Debugger::new();
+    assert!(debugger.add_breakpoint(0x300));
+    assert!(!debugger.add_breakpoint(0x300));
+    assert!(debugger.add_breakpoint(0x200));
+    assert_eq!(
+        vec![0x200, 0x300],
+        debugger.get_breakpoints().collect::<Vec<u16>>()
+    );
+    assert!(debugger.remove_breakpoint(0x300));
+    assert!(!debugger.has_breakpoint(0x300));
+    debugger.clear_breakpoints();
+    assert_eq!(0, debugger.get_breakpoints().count());
+}
+
+#[test]
+fn test_run_dumps_trace_at_breakpoint() {
+    let dump = Arc::new(Mutex::new(Vec::new()));
+    let (ring_buffer, _) = RingBufferLogSource::new(8, Some(Box::new(SharedBuffer(dump.clone()))));
+    let mut chip8 = Chip8::new(Logger::new(Box::new(ring_buffer)));
+    chip8.initialize(&COUNTER_PROGRAM);
+    let mut debugger = Debugger::new();
+    debugger.add_breakpoint(0x204);
+
+    debugger.run(&mut chip8, 100);
+    let text = String::from_utf8(dump.lock().unwrap().clone()).unwrap();
+    assert!(
+        text.starts_with("Trace dump: 0x204: Breakpoint hit\n0x200: 6001"),
+        "{}",
+        text
+    );
+    assert_eq!(7, text.lines().count(), "{}", text);
+}
+
+#[derive(Debug)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_run_breaks_on_code_write() {
+    // LD I, 0x206; LD V0, 0x00; LD V1, 0xFD; JP 0x20A; 0x0000; LD [I], V1; JP 0x206
+    let program = [
+        0xA2, 0x06, 0x60, 0x00, 0x61, 0xFD, 0x12, 0x0A, 0x00, 0x00, 0xF1, 0x55, 0x12, 0x06,
+    ];
+    let mut chip8 = new_chip8(&program);
+    let mut debugger = Debugger::new();
+    debugger.set_break_on_code_write(true);
+
+    assert_eq!(
+        StopReason::CodeModified {
+            pc: 0x20A,
+            addr: 0x206
+        },
+        debugger.run(&mut chip8, 100)
+    );
+    assert_eq!(0x20C, chip8.get_program_counter());
+    assert_eq!(StopReason::Exited, debugger.run(&mut chip8, 100));
+
+    let mut chip8 = new_chip8(&program);
+    debugger.set_break_on_code_write(false);
+    assert_eq!(StopReason::Exited, debugger.run(&mut chip8, 100));
+}
@@ -11,3 +11,35 @@ fn test_get_pixel() {
         }
     }
 }
+
+#[test]
+fn test_to_palette_indices() {
+    let data: [u8; 2] = [0b1000_0001, 0b0110_0000];
+    let display = Display::new(&data, 8, 2);
+    assert_eq!(
+        vec![1, 0, 0, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0],
+        display.to_palette_indices()
+    );
+}
+
+#[test]
+fn test_to_rgba() {
+    let data: [u8; 1] = [0b0100_0000];
+    let display = Display::new(&data, 2, 1);
+    let palette = [[1, 2, 3, 4], [5, 6, 7, 8]];
+    assert_eq!(vec![1, 2, 3, 4, 5, 6, 7, 8], display.to_rgba(&palette));
+}
+
+#[test]
+fn test_to_text() {
+    let data: [u8; 1] = [0b1001_0000];
+    let display = Display::new(&data, 2, 2);
+    assert_eq!("#.\n.#\n", display.to_text('#', '.'));
+}
+
+#[test]
+fn test_to_pbm() {
+    let data: [u8; 1] = [0b1001_0000];
+    let display = Display::new(&data, 2, 2);
+    assert_eq!("P1\n2 2\n1 0\n0 1\n", display.to_pbm());
+}
@@ -0,0 +1,52 @@
+use super::display::*;
+use super::golden::*;
+
+#[test]
+fn test_parse_text() {
+    assert_eq!(
+        vec![vec![true, false], vec![false, true]],
+        parse_text("X.\n.#\n")
+    );
+}
+
+#[test]
+fn test_parse_pbm() {
+    assert_eq!(
+        Ok(vec![vec![true, false], vec![false, true]]),
+        parse_pbm("P1\n# comment\n2 2\n1 0\n0 1\n")
+    );
+    assert!(parse_pbm("P4\n2 2\n").is_err());
+    assert!(parse_pbm("P1\n2 2\n1 0\n0").is_err());
+}
+
+#[test]
+fn test_diff_display_matches() {
+    let data: [u8; 1] = [0b1001_0000];
+    let display = Display::new(&data, 2, 2);
+    assert_eq!(None, diff_display(&display, &parse_text("X.\n.X")));
+}
+
+#[test]
+fn test_diff_display_mismatch() {
+    let data: [u8; 1] = [0b1001_0000];
+    let display = Display::new(&data, 2, 2);
+    assert_eq!(
+        Some(
+            "1 pixels differ:
+row  1 expected ..
+       actual   .X
+                 ^
+"
+            .to_string()
+        ),
+        diff_display(&display, &parse_text("X.\n.."))
+    );
+}
+
+#[test]
+fn test_diff_display_size_mismatch() {
+    let data: [u8; 1] = [0b1001_0000];
+    let display = Display::new(&data, 2, 2);
+    let diff = diff_display(&display, &parse_text("X.")).unwrap();
+    assert!(diff.contains("Expected a 2x1 display, found 2x2."));
+}
@@ -0,0 +1,41 @@
+use super::input_script::*;
+
+#[test]
+fn test_keys_at() {
+    let script = InputScript::parse(
+        "# frame keys
+        0 -
+        30 5 a  # jump
+        35 -
+        40 F",
+    )
+    .unwrap();
+    assert_eq!(0, script.keys_at(0));
+    assert_eq!(0, script.keys_at(29));
+    assert_eq!((1 << 0x5) | (1 << 0xA), script.keys_at(30));
+    assert_eq!((1 << 0x5) | (1 << 0xA), script.keys_at(34));
+    assert_eq!(0, script.keys_at(35));
+    assert_eq!(1 << 0xF, script.keys_at(1000));
+}
+
+#[test]
+fn test_empty_script() {
+    let script = InputScript::parse("").unwrap();
+    assert_eq!(0, script.keys_at(10));
+}
+
+#[test]
+fn test_parse_errors() {
+    assert_eq!(
+        Err("Line 1: Invalid frame number: x".to_string()),
+        InputScript::parse("x 1")
+    );
+    assert_eq!(
+        Err("Line 2: Invalid key: 10".to_string()),
+        InputScript::parse("0 1\n1 10")
+    );
+    assert_eq!(
+        Err("Line 2: Frame 5 is not after frame 5".to_string()),
+        InputScript::parse("5 1\n5 2")
+    );
+}
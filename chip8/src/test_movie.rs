@@ -0,0 +1,87 @@
+use super::movie::*;
+use crate::Chip8;
+use crate::Chip8Keys;
+use crate::logger::Logger;
+use crate::quirks::Quirks;
+
+// 0x200: RND V0, 0xFF
+// 0x202: LD V3, 0x05
+// 0x204: SKNP V3
+// 0x206: ADD V2, V0
+// 0x208: LD I, 0x300
+// 0x20A: LD [I], V2
+// 0x20C: JP 0x200
+const PROGRAM: [u8; 14] = [
+    0xC0, 0xFF, 0x63, 0x05, 0xE3, 0xA1, 0x82, 0x04, 0xA3, 0x00, 0xF2, 0x55, 0x12, 0x00,
+];
+
+fn run_frames(chip8: &mut Chip8, frames: &[Chip8Keys], instructions_per_frame: u32) {
+    for keys in frames {
+        chip8.set_keys(*keys);
+        chip8.run_frame(instructions_per_frame);
+    }
+}
+
+#[test]
+fn test_rom_hash() {
+    assert_eq!(0xcbf29ce484222325, rom_hash(&[]));
+    assert_eq!(0xaf63dc4c8601ec8c, rom_hash(b"a"));
+}
+
+#[test]
+fn test_round_trip() {
+    let movie = Movie {
+        header: MovieHeader {
+            rom_hash: 0x0123456789abcdef,
+            seed: 42,
+            quirks: Quirks::cosmac(),
+            instructions_per_frame: 20,
+        },
+        frames: vec![0, 0x20, 0xFFFF],
+    };
+    let mut bytes: Vec<u8> = Vec::new();
+    movie.write_to(&mut bytes).unwrap();
+    let text = String::from_utf8(bytes).unwrap();
+    assert_eq!(Ok(movie), Movie::parse(&text));
+}
+
+#[test]
+fn test_parse_errors() {
+    assert!(Movie::parse("").is_err());
+    assert!(Movie::parse("not a movie").is_err());
+    let text =
+        "chip8-movie 1\nrom_hash 00\nseed 1\nquirks bogus=1\ninstructions_per_frame 1\nframes\n";
+    assert_eq!(
+        Err("Line 4: Invalid quirk: bogus=1".to_string()),
+        Movie::parse(text)
+    );
+}
+
+#[test]
+fn test_playback_reproduces_run() {
+    let frames: Vec<Chip8Keys> = (0..50)
+        .map(|frame| if frame % 7 < 3 { 0xFFFF } else { 0 })
+        .collect();
+
+    let mut recorded = Chip8::new(Logger::new_null_logger());
+    recorded.set_quirks(Quirks::cosmac());
+    recorded.initialize(&PROGRAM);
+    let header = MovieHeader::new(&recorded, &PROGRAM, 7);
+    run_frames(&mut recorded, &frames, 7);
+
+    let mut played = Chip8::new(Logger::new_null_logger());
+    header.start_playback(&mut played, &PROGRAM).unwrap();
+    run_frames(&mut played, &frames, 7);
+
+    assert_eq!(recorded.get_registers(), played.get_registers());
+    assert_eq!(recorded.get_pointer(), played.get_pointer());
+    assert_eq!(recorded.get_memory(0, 4096), played.get_memory(0, 4096));
+}
+
+#[test]
+fn test_playback_rejects_other_rom() {
+    let chip8 = Chip8::new(Logger::new_null_logger());
+    let header = MovieHeader::new(&chip8, &PROGRAM, 7);
+    let mut played = Chip8::new(Logger::new_null_logger());
+    assert!(header.start_playback(&mut played, &[0x00, 0xFD]).is_err());
+}
@@ -1,5 +1,6 @@
 use super::processor::*;
 use crate::logger::Logger;
+use crate::logger::null_log_source::NullLogSource;
 
 #[test]
 #[should_panic]
@@ -8,3 +9,217 @@ fn test_uninitialized_execution_panics() {
     let mut processor = Processor::new(logger);
     while !processor.run_next_instruction() {}
 }
+
+#[test]
+fn test_display_version_changes_on_draw() {
+    let logger = Logger::new_null_logger();
+    let mut processor = Processor::new(logger);
+    // LD V0, 0x01; DRW V0, V0, 1; LD V1, 0x01
+    processor.initialize(&[0x60, 0x01, 0xD0, 0x01, 0x61, 0x01]);
+    let initial_version = processor.get_display_version();
+
+    processor.run_next_instruction();
+    assert_eq!(initial_version, processor.get_display_version());
+    processor.run_next_instruction();
+    let drawn_version = processor.get_display_version();
+    assert_ne!(initial_version, drawn_version);
+    processor.run_next_instruction();
+    assert_eq!(drawn_version, processor.get_display_version());
+}
+
+#[test]
+fn test_state_accessors() {
+    let logger = Logger::new_null_logger();
+    let mut processor = Processor::new(logger);
+    // CALL 0x204; EXIT; LD V3, 0x42
+    processor.initialize(&[0x22, 0x04, 0x00, 0xFD, 0x63, 0x42]);
+    processor.run_next_instruction();
+    processor.run_next_instruction();
+
+    assert_eq!(0x42, processor.get_register(3));
+    assert_eq!(0x206, processor.get_program_counter());
+    assert_eq!(1, processor.get_stack_pointer());
+    assert_eq!(&[0x202], processor.get_stack());
+
+    processor.set_register(3, 0x10);
+    processor.set_pointer(0x300);
+    processor.set_delay_timer(5);
+    processor.set_sound_timer(6);
+    processor.set_memory(0xFFE, &[1, 2, 3]);
+    assert_eq!(0x10, processor.get_registers()[3]);
+    assert_eq!(0x300, processor.get_pointer());
+    assert_eq!(5, processor.get_delay_timer());
+    assert_eq!(6, processor.get_sound_timer());
+    assert_eq!(&[0, 1, 2], processor.get_memory(0xFFD, 8));
+}
+
+#[test]
+fn test_state_accessors_stay_in_memory() {
+    let logger = Logger::new_null_logger();
+    let mut processor = Processor::new(logger);
+    processor.initialize(&[0x00, 0xFD]);
+
+    processor.set_program_counter(0x1204);
+    assert_eq!(0x204, processor.get_program_counter());
+    processor.set_program_counter(0xFFFF);
+    assert_eq!(0xFFF, processor.get_program_counter());
+
+    assert_eq!(1, processor.get_memory(0xFFF, usize::MAX).len());
+    processor.set_memory(0xFFFF, &[1, 2]);
+    assert_eq!(&[0, 0], processor.get_memory(0xFFE, 2));
+}
+
+#[test]
+fn test_program_counter_wraps_at_the_end_of_memory() {
+    for start in [0xFFE, 0xFFF] {
+        let mut processor = Processor::new(Logger::new_null_logger());
+        processor.initialize(&[0x00, 0xFD]);
+        processor.set_program_counter(start);
+        processor.run_next_instruction();
+        assert_eq!((start + 2) & 0xFFF, processor.get_program_counter());
+        processor.run_next_instruction();
+        assert_eq!((start + 4) & 0xFFF, processor.get_program_counter());
+    }
+}
+
+#[test]
+fn test_skip_wraps_at_the_end_of_memory() {
+    let mut processor = Processor::new(Logger::new_null_logger());
+    processor.initialize(&[0x00, 0xFD]);
+    // SE V0, 0x00
+    processor.set_memory(0xFFE, &[0x30, 0x00]);
+    processor.set_program_counter(0xFFE);
+    processor.run_next_instruction();
+    assert_eq!(0x002, processor.get_program_counter());
+}
+
+#[test]
+fn test_run_wraps_at_the_end_of_memory() {
+    let mut processor = Processor::new(Logger::new_null_logger());
+    processor.initialize(&[0x00, 0xFD]);
+    // ADD V0, 0x01 twice at the end of memory, then the digit sprites from 0x000 run as an
+    // unknown instruction.
+    processor.set_memory(0xFFC, &[0x70, 0x01, 0x70, 0x01]);
+    processor.set_program_counter(0xFFC);
+    assert_eq!(3, processor.run(3));
+    assert_eq!(2, processor.get_register(0));
+    assert_eq!(0x002, processor.get_program_counter());
+}
+
+#[test]
+fn test_run_frame() {
+    let logger = Logger::new_null_logger();
+    let mut processor = Processor::new(logger);
+    // LD V0, 0x02; LD DT, V0; ADD V1, 0x01; JP 0x204
+    processor.initialize(&[0x60, 0x02, 0xF0, 0x15, 0x71, 0x01, 0x12, 0x04]);
+
+    assert!(!processor.run_frame(4));
+    assert_eq!(1, processor.get_register(1));
+    assert_eq!(1, processor.get_delay_timer());
+    assert!(!processor.run_frame(4));
+    assert_eq!(3, processor.get_register(1));
+    assert_eq!(0, processor.get_delay_timer());
+}
+
+// 0x200: LD I, 0x206
+// 0x202: LD V0, 0x00
+// 0x204: LD V1, 0xFD
+// 0x206: JP 0x20A
+// 0x208: 0x00 0x00
+// 0x20A: LD [I], V1   Rewrites 0x206 to EXIT.
+// 0x20C: JP 0x206
+const SELF_MODIFYING_PROGRAM: [u8; 14] = [
+    0xA2, 0x06, 0x60, 0x00, 0x61, 0xFD, 0x12, 0x0A, 0x00, 0x00, 0xF1, 0x55, 0x12, 0x06,
+];
+
+#[test]
+fn test_write_over_executed_code() {
+    let mut processor = Processor::new(Logger::new_null_logger());
+    processor.initialize(&SELF_MODIFYING_PROGRAM);
+    for _ in 0..4 {
+        processor.run_next_instruction();
+        assert_eq!(None, processor.get_last_code_write());
+    }
+    assert!(processor.has_executed(0x206));
+    assert!(!processor.has_executed(0x208));
+
+    processor.run_next_instruction();
+    assert_eq!(
+        Some(CodeWrite {
+            pc: 0x20A,
+            addr: 0x206,
+            opcode: 0x120A
+        }),
+        processor.get_last_code_write()
+    );
+    assert_eq!(
+        &std::collections::BTreeSet::from([0x206, 0x207]),
+        processor.get_modified_code()
+    );
+
+    processor.run_next_instruction();
+    assert_eq!(None, processor.get_last_code_write());
+    assert!(processor.run_next_instruction());
+}
+
+#[test]
+fn test_write_over_second_byte_of_executed_code() {
+    let mut program = SELF_MODIFYING_PROGRAM;
+    // LD I, 0x207, so only the second byte of the jump and the unused bytes are written.
+    program[1] = 0x07;
+    let mut processor = Processor::new(Logger::new_null_logger());
+    processor.initialize(&program);
+    for _ in 0..5 {
+        processor.run_next_instruction();
+    }
+    assert_eq!(
+        Some(0x206),
+        processor.get_last_code_write().map(|write| write.addr)
+    );
+    assert_eq!(
+        &std::collections::BTreeSet::from([0x207]),
+        processor.get_modified_code()
+    );
+}
+
+#[test]
+fn test_run_stops_at_exit() {
+    let mut processor = Processor::new(Logger::new_null_logger());
+    // LD V0, 0x01; ADD V0, 0x01; EXIT; LD V0, 0x09
+    processor.initialize(&[0x60, 0x01, 0x70, 0x01, 0x00, 0xFD, 0x60, 0x09]);
+    assert_eq!(3, processor.run(10));
+    assert!(processor.is_exited());
+    assert_eq!(2, processor.get_register(0));
+    assert_eq!(0, processor.run(10));
+}
+
+#[test]
+fn test_run_stops_waiting_for_key() {
+    let mut processor = Processor::new(Logger::new_null_logger());
+    // LD V0, 0x01; LD V1, K; LD V0, 0x02
+    processor.initialize(&[0x60, 0x01, 0xF1, 0x0A, 0x60, 0x02]);
+    assert_eq!(2, processor.run(10));
+    assert!(processor.is_waiting_for_key());
+    assert_eq!(0, processor.run(10));
+}
+
+#[test]
+fn test_run_with_logged_instructions() {
+    let logger = Logger::new(Box::new(NullLogSource::new()));
+    let mut processor = Processor::new(logger);
+    // LD V0, 0x01; ADD V0, 0x01; JP 0x202
+    processor.initialize(&[0x60, 0x01, 0x70, 0x01, 0x12, 0x02]);
+    assert_eq!(5, processor.run(5));
+    assert_eq!(3, processor.get_register(0));
+}
+
+#[test]
+fn test_memory_write_replaces_decoded_instruction() {
+    let mut processor = Processor::new(Logger::new_null_logger());
+    // LD V0, 0x01; JP 0x200
+    processor.initialize(&[0x60, 0x01, 0x12, 0x00]);
+    processor.run(2);
+    processor.set_memory(0x201, &[0x05]);
+    processor.run(1);
+    assert_eq!(5, processor.get_register(0));
+}
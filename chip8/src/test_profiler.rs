@@ -0,0 +1,110 @@
+use super::profiler::*;
+use crate::Chip8;
+use crate::logger::Logger;
+use crate::logger::profiler_log_source::ProfilerLogSource;
+use crate::symbol_map::SymbolMap;
+
+use chip8_instructions::Instruction;
+
+// 0x200: CALL 0x20A
+// 0x202: CALL 0x20A
+// 0x204: JP 0x204
+// 0x20A: LD V0, 0x01
+// 0x20C: CALL 0x210
+// 0x20E: RET
+// 0x210: ADD V0, 0x01
+// 0x212: RET
+const NESTED_CALLS_PROGRAM: [u8; 20] = [
+    0x22, 0x0A, 0x22, 0x0A, 0x12, 0x04, 0x00, 0x00, 0x00, 0x00, 0x60, 0x01, 0x22, 0x10, 0x00, 0xEE,
+    0x70, 0x01, 0x00, 0xEE,
+];
+
+fn profile_program(program: &[u8]) -> Profile {
+    let (log_source, profile) = ProfilerLogSource::new();
+    let mut chip8 = Chip8::new(Logger::new(Box::new(log_source)));
+    chip8.initialize(program);
+    assert!(chip8.run_frame(100));
+    profile.lock().unwrap().clone()
+}
+
+#[test]
+fn test_counts_by_addr_and_instruction() {
+    let profile = profile_program(&NESTED_CALLS_PROGRAM);
+    assert_eq!(
+        Counts {
+            executions: 13,
+            cycles: 239
+        },
+        profile.get_total()
+    );
+    assert_eq!(
+        Counts {
+            executions: 2,
+            cycles: 20
+        },
+        profile.get_addr_counts(0x210)
+    );
+    assert_eq!(0, profile.get_addr_counts(0x206).executions);
+    assert_eq!(4, profile.get_instruction_counts("Return").executions);
+    assert_eq!(0, profile.get_instruction_counts("Draw").executions);
+}
+
+#[test]
+fn test_subroutine_counts() {
+    let profile = profile_program(&NESTED_CALLS_PROGRAM);
+    assert_eq!(
+        SubroutineCounts {
+            calls: 2,
+            inclusive: Counts {
+                executions: 10,
+                cycles: 170
+            },
+            exclusive: Counts {
+                executions: 6,
+                cycles: 104
+            },
+        },
+        profile.get_subroutine_counts(0x20A)
+    );
+    let inner = profile.get_subroutine_counts(0x210);
+    assert_eq!(2, inner.calls);
+    assert_eq!(inner.inclusive, inner.exclusive);
+    assert_eq!(4, inner.exclusive.executions);
+}
+
+#[test]
+fn test_recursion_and_unfinished_calls() {
+    let mut profile = Profile::new();
+    profile.record(0x200, Instruction::Call { addr: 0x300 });
+    profile.record(0x300, Instruction::Call { addr: 0x300 });
+    profile.record(0x300, Instruction::Return);
+    profile.record(0x302, Instruction::Return);
+    // A return without a call is ignored.
+    profile.record(0x202, Instruction::Return);
+    // A call that has not returned counts up to now.
+    profile.record(0x204, Instruction::Call { addr: 0x300 });
+    profile.record(0x300, Instruction::LoadImmToReg { x: 0, byte: 1 });
+
+    let counts = profile.get_subroutine_counts(0x300);
+    assert_eq!(3, counts.calls);
+    assert_eq!(4, counts.inclusive.executions);
+    assert_eq!(4, counts.exclusive.executions);
+}
+
+#[test]
+fn test_report_names_symbols() {
+    let profile = profile_program(&NESTED_CALLS_PROGRAM);
+    let symbols = SymbolMap::parse("0x200 main\n0x20a outer\n0x210 inner").unwrap();
+    let report = profile.report(Some(&symbols), 3);
+    assert!(
+        report.starts_with("13 instructions, about 239 VIP cycles (0.1 frames at VIP speed)"),
+        "{}",
+        report
+    );
+    assert!(report.contains("0x20c outer+0x2"), "{}", report);
+    assert!(report.contains("\n  0x20a outer"), "{}", report);
+    assert!(report.contains("\n  Return "), "{}", report);
+
+    let report = profile.report(None, 3);
+    assert!(report.contains("\n  0x20a  "), "{}", report);
+}
@@ -0,0 +1,117 @@
+use crate::Chip8;
+use crate::logger::Logger;
+use crate::quirks::Quirks;
+
+fn run(program: &[u8], quirks: Quirks) -> Chip8 {
+    let mut chip8 = Chip8::new(Logger::new_null_logger());
+    chip8.set_quirks(quirks);
+    chip8.initialize(program);
+    while !chip8.run_next_instruction() {}
+    chip8
+}
+
+#[test]
+fn test_vf_reset() {
+    // LD VF, 0x05; OR V0, V1; EXIT
+    let program = [0x6F, 0x05, 0x80, 0x11, 0x00, 0xFD];
+    assert_eq!(5, run(&program, Quirks::default()).get_register(0xF));
+    let quirks = Quirks {
+        vf_reset: true,
+        ..Quirks::default()
+    };
+    assert_eq!(0, run(&program, quirks).get_register(0xF));
+}
+
+#[test]
+fn test_shift_uses_vy() {
+    // LD V0, 0x08; LD V1, 0x02; SHR V0, V1; EXIT
+    let program = [0x60, 0x08, 0x61, 0x02, 0x80, 0x16, 0x00, 0xFD];
+    assert_eq!(4, run(&program, Quirks::default()).get_register(0));
+    let quirks = Quirks {
+        shift_uses_vy: true,
+        ..Quirks::default()
+    };
+    assert_eq!(1, run(&program, quirks).get_register(0));
+}
+
+#[test]
+fn test_memory_increments_i() {
+    // LD I, 0x300; LD [I], V2; EXIT
+    let program = [0xA3, 0x00, 0xF2, 0x55, 0x00, 0xFD];
+    assert_eq!(0x300, run(&program, Quirks::default()).get_pointer());
+    let quirks = Quirks {
+        memory_increments_i: true,
+        ..Quirks::default()
+    };
+    assert_eq!(0x303, run(&program, quirks).get_pointer());
+}
+
+#[test]
+fn test_jump_offset_uses_vx() {
+    // LD V0, 0x02; LD V2, 0x04; JP V0, 0x206; EXIT; EXIT; EXIT
+    let program = [
+        0x60, 0x02, 0x62, 0x04, 0xB2, 0x06, 0x00, 0xFD, 0x00, 0xFD, 0x00, 0xFD,
+    ];
+    assert_eq!(
+        0x20A,
+        run(&program, Quirks::default()).get_program_counter()
+    );
+    let quirks = Quirks {
+        jump_offset_uses_vx: true,
+        ..Quirks::default()
+    };
+    assert_eq!(0x20C, run(&program, quirks).get_program_counter());
+}
+
+#[test]
+fn test_clip_sprites() {
+    // LD V0, 0x3C; LD V1, 0x1F; LD I, 0x20E; DRW V0, V1, 2; EXIT; sprite 0xFF 0xFF
+    let program = [
+        0x60, 0x3C, 0x61, 0x1F, 0xA2, 0x0C, 0xD0, 0x12, 0x00, 0xFD, 0x00, 0x00, 0xFF, 0xFF,
+    ];
+    let chip8 = run(&program, Quirks::default());
+    let display = chip8.get_display();
+    assert!(display.get_pixel(31, 63));
+    assert!(display.get_pixel(31, 0));
+    assert!(display.get_pixel(0, 0));
+
+    let chip8 = run(&program, Quirks::schip());
+    let display = chip8.get_display();
+    assert!(display.get_pixel(31, 63));
+    assert!(!display.get_pixel(31, 0));
+    assert!(!display.get_pixel(0, 0));
+}
+
+#[test]
+fn test_presets() {
+    assert_eq!(Some(Quirks::default()), Quirks::from_preset_name("default"));
+    for name in Quirks::PRESET_NAMES {
+        assert!(Quirks::from_preset_name(name).is_some());
+    }
+    assert_eq!(None, Quirks::from_preset_name("unknown"));
+}
+
+#[test]
+fn test_flags() {
+    let mut quirks = Quirks::default();
+    for (name, _) in Quirks::cosmac().to_flags() {
+        assert!(quirks.set_flag(name, true));
+    }
+    assert!(quirks.to_flags().iter().all(|(_, value)| *value));
+    assert!(!quirks.set_flag("unknown", true));
+}
+
+#[test]
+fn test_seeded_random() {
+    // RND V0, 0xFF; RND V1, 0xFF; EXIT
+    let program = [0xC0, 0xFF, 0xC1, 0xFF, 0x00, 0xFD];
+    let run_seeded = |seed: u64| {
+        let mut chip8 = Chip8::new(Logger::new_null_logger());
+        chip8.set_seed(seed);
+        chip8.initialize(&program);
+        while !chip8.run_next_instruction() {}
+        (chip8.get_register(0), chip8.get_register(1))
+    };
+    assert_eq!(run_seeded(1234), run_seeded(1234));
+    assert_ne!(run_seeded(1234), run_seeded(5678));
+}
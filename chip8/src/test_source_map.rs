@@ -0,0 +1,80 @@
+use super::source_map::*;
+
+#[test]
+fn test_parse() {
+    let source_map = SourceMap::parse("# map\nsource game.asm\n\n0x200 3 2\n21a 12 5\n").unwrap();
+    assert_eq!(Some("game.asm".to_string()), source_map.source_path);
+    assert_eq!(
+        vec![
+            SourceEntry {
+                addr: 0x200,
+                line_num: 3,
+                size: 2
+            },
+            SourceEntry {
+                addr: 0x21a,
+                line_num: 12,
+                size: 5
+            },
+        ],
+        source_map.entries
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    assert_eq!(
+        Err("Line 1: Expected an address, a line number and a size.".to_string()),
+        SourceMap::parse("0x200 3")
+    );
+    assert_eq!(
+        Err("Line 2: Invalid line number: x".to_string()),
+        SourceMap::parse("0x200 3 2\n0x202 x 2")
+    );
+    assert_eq!(
+        Err("Line 1: Invalid size: -1".to_string()),
+        SourceMap::parse("0x200 3 -1")
+    );
+    assert_eq!(
+        Err("Line 1: Entry at 0xffff runs past the end of memory.".to_string()),
+        SourceMap::parse("0xffff 3 2")
+    );
+    assert_eq!(
+        Err("Line 1: Entry at 0xffe runs past the end of memory.".to_string()),
+        SourceMap::parse("0xffe 3 3")
+    );
+    assert!(SourceMap::parse("0xffe 3 2").is_ok());
+}
+
+#[test]
+fn test_get_entry() {
+    let source_map = SourceMap::parse("0x200 3 2\n0x202 4 2\n0x220 12 5").unwrap();
+    assert_eq!(3, source_map.get_entry(0x201).unwrap().line_num);
+    assert_eq!(12, source_map.get_entry(0x224).unwrap().line_num);
+    assert_eq!(None, source_map.get_entry(0x204));
+    assert_eq!(None, source_map.get_entry(0x225));
+}
+
+#[test]
+fn test_get_entry_at_the_end_of_the_address_space() {
+    // Entries built by hand skip the checks in parse, and must not overflow.
+    let source_map = SourceMap {
+        source_path: None,
+        entries: vec![SourceEntry {
+            addr: 0xfffe,
+            line_num: 3,
+            size: 4,
+        }],
+    };
+    assert_eq!(3, source_map.get_entry(0xffff).unwrap().line_num);
+    assert_eq!(None, source_map.get_entry(0x0000));
+}
+
+#[test]
+fn test_find_addr() {
+    let source_map = SourceMap::parse("0x200 3 2\n0x202 4 2\n0x220 12 5").unwrap();
+    assert_eq!(Some(0x202), source_map.find_addr(4));
+    assert_eq!(Some(0x200), source_map.find_addr(1));
+    assert_eq!(Some(0x220), source_map.find_addr(5));
+    assert_eq!(None, source_map.find_addr(13));
+}
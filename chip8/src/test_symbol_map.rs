@@ -0,0 +1,28 @@
+use super::symbol_map::*;
+
+#[test]
+fn test_parse_and_describe() {
+    let symbol_map = SymbolMap::parse("# labels\n0x200 main\n\n21a draw_score\n").unwrap();
+    assert_eq!(Some("main"), symbol_map.get_name(0x200));
+    assert_eq!(None, symbol_map.get_name(0x202));
+    assert_eq!(Some("main".to_string()), symbol_map.describe(0x200));
+    assert_eq!(Some("main+0x4".to_string()), symbol_map.describe(0x204));
+    assert_eq!(
+        Some("draw_score+0x2".to_string()),
+        symbol_map.describe(0x21c)
+    );
+    assert_eq!(None, symbol_map.describe(0x1fe));
+    assert_eq!("0x200 main\n0x21a draw_score\n", symbol_map.to_string());
+}
+
+#[test]
+fn test_parse_errors() {
+    assert_eq!(
+        Err("Line 2: Expected an address and a name.".to_string()),
+        SymbolMap::parse("0x200 main\n0x202")
+    );
+    assert_eq!(
+        Err("Line 1: Invalid address: 0xzz".to_string()),
+        SymbolMap::parse("0xzz main")
+    );
+}
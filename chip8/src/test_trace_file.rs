@@ -0,0 +1,139 @@
+use super::trace_file::*;
+use crate::Chip8;
+use crate::logger::Logger;
+use crate::logger::trace_file_log_source::TraceFileLogSource;
+
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+// 0x200: LD V0, 0x01
+// 0x202: ADD V0, 0x01
+// 0x204: JP 0x202
+const COUNTER_PROGRAM: [u8; 6] = [0x60, 0x01, 0x70, 0x01, 0x12, 0x02];
+
+#[derive(Debug, Clone)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+fn trace_program(program: &[u8], num_instructions: usize) -> String {
+    let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+    let log_source = TraceFileLogSource::new(buffer.clone());
+    let mut chip8 = Chip8::new(Logger::new(Box::new(log_source)));
+    chip8.initialize(program);
+    for _ in 0..num_instructions {
+        chip8.run_next_instruction();
+    }
+    String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap()
+}
+
+fn parse_lines(lines: &[&str]) -> Vec<TraceLine> {
+    TraceFile::parse(&lines.join("\n")).unwrap().lines
+}
+
+#[test]
+fn test_trace_file_log_source() {
+    let text = trace_program(&COUNTER_PROGRAM, 3);
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(3, lines.len());
+    assert_eq!(
+        "pc=0202 op=7001 v0=01 v1=00 v2=00 v3=00 v4=00 v5=00 v6=00 v7=00 v8=00 v9=00 va=00 vb=00 vc=00 vd=00 ve=00 vf=00 i=0000 sp=00 dt=00 st=00",
+        lines[1]
+    );
+
+    let trace_file = TraceFile::parse(&text).unwrap();
+    assert_eq!(vec![1, 2, 3], trace_file.line_nums);
+    assert_eq!(lines[2], trace_file.lines[2].to_string());
+}
+
+#[test]
+fn test_parse_other_formats() {
+    let lines = parse_lines(&[
+        "# exported trace",
+        "",
+        "PC:0x200 OPCODE:0x6001 V0:0 I:0 extra=1",
+    ]);
+    assert_eq!(1, lines.len());
+    assert_eq!(Some(0x200), lines[0].get("pc"));
+    assert_eq!(Some(0x6001), lines[0].get("op"));
+    assert_eq!(None, lines[0].get("vf"));
+    assert_eq!("pc=0200 op=6001 v0=00 i=0000", lines[0].to_string());
+}
+
+#[test]
+fn test_parse_errors() {
+    assert_eq!(
+        Err("Line 2: Expected a pc field.".to_string()),
+        TraceFile::parse("pc=200\nv0=1")
+    );
+    assert_eq!(
+        Err("Line 1: Invalid value for v0: `zz`.".to_string()),
+        TraceFile::parse("pc=200 v0=zz")
+    );
+    assert_eq!(
+        Err("Line 1: Expected `name=value`, found `200`.".to_string()),
+        TraceFile::parse("200")
+    );
+}
+
+#[test]
+fn test_diff_identical_traces() {
+    let lines = TraceFile::parse(&trace_program(&COUNTER_PROGRAM, 10))
+        .unwrap()
+        .lines;
+    let diff = diff_traces(&lines, &lines, &[]);
+    assert_eq!(10, diff.matched);
+    assert_eq!(None, diff.divergence);
+}
+
+#[test]
+fn test_diff_reports_first_divergence() {
+    let expected = parse_lines(&["pc=200 v0=0", "pc=202 v0=1 vf=0", "pc=204 v0=2 vf=1"]);
+    let actual = parse_lines(&["pc=200 v0=0", "pc=202 v0=1 vf=0", "pc=204 v0=3 vf=0"]);
+    let diff = diff_traces(&expected, &actual, &[]);
+    assert_eq!(2, diff.matched);
+    assert_eq!(
+        Some(Divergence {
+            expected_index: 2,
+            actual_index: 2,
+            fields: vec!["v0", "vf"],
+        }),
+        diff.divergence
+    );
+
+    let diff = diff_traces(&expected, &actual, &["v0"]);
+    assert_eq!(vec!["vf"], diff.divergence.unwrap().fields);
+}
+
+#[test]
+fn test_diff_aligns_later_start() {
+    let expected = parse_lines(&["pc=200", "pc=202", "pc=204", "pc=206 v0=1"]);
+    let actual = parse_lines(&["pc=204", "pc=206 v0=2"]);
+    assert_eq!(Some((2, 0)), align(&expected, &actual));
+    assert_eq!(Some((0, 2)), align(&actual, &expected));
+
+    let diff = diff_traces(&expected, &actual, &[]);
+    assert_eq!(
+        (2, 0, 1),
+        (diff.expected_start, diff.actual_start, diff.matched)
+    );
+    assert_eq!(vec!["v0"], diff.divergence.unwrap().fields);
+}
+
+#[test]
+fn test_diff_unaligned_traces_compare_from_start() {
+    let expected = parse_lines(&["pc=200", "pc=202"]);
+    let actual = parse_lines(&["pc=300", "pc=302"]);
+    assert_eq!(None, align(&expected, &actual));
+    let diff = diff_traces(&expected, &actual, &[]);
+    assert_eq!(0, diff.matched);
+    assert_eq!(vec!["pc"], diff.divergence.unwrap().fields);
+}
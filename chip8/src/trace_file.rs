@@ -0,0 +1,256 @@
+use crate::logger::trace::TraceEntry;
+
+use std::fmt;
+
+/// The fields of a trace line, in the order they are written, with the number of hex
+/// digits written for each.
+pub const TRACE_FIELDS: [(&str, usize); 22] = [
+    ("pc", 4),
+    ("op", 4),
+    ("v0", 2),
+    ("v1", 2),
+    ("v2", 2),
+    ("v3", 2),
+    ("v4", 2),
+    ("v5", 2),
+    ("v6", 2),
+    ("v7", 2),
+    ("v8", 2),
+    ("v9", 2),
+    ("va", 2),
+    ("vb", 2),
+    ("vc", 2),
+    ("vd", 2),
+    ("ve", 2),
+    ("vf", 2),
+    ("i", 4),
+    ("sp", 2),
+    ("dt", 2),
+    ("st", 2),
+];
+
+/// Other names for fields accepted when reading traces from other emulators.
+const FIELD_ALIASES: [(&str, &str); 4] = [
+    ("opcode", "op"),
+    ("delay", "dt"),
+    ("sound", "st"),
+    ("index", "i"),
+];
+
+/// The state of the processor before an instruction runs.
+///
+/// Trace files have one line per instruction, each a list of `name=value` fields in hex:
+///
+/// ```text
+/// pc=0200 op=6001 v0=00 v1=00 v2=00 v3=00 v4=00 v5=00 v6=00 v7=00 v8=00 v9=00 va=00 vb=00 vc=00 vd=00 ve=00 vf=00 i=0000 sp=00 dt=00 st=00
+/// ```
+///
+/// When reading, field names are case insensitive, `:` may separate the name and value,
+/// values may start with `0x`, and unknown fields, blank lines and lines starting with
+/// `#` are skipped. Only `pc` is required, so traces from emulators that record less can
+/// still be compared.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceLine {
+    /// The value of each of `TRACE_FIELDS`, if recorded.
+    values: [Option<u16>; 22],
+}
+
+/// A parsed trace file.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TraceFile {
+    pub lines: Vec<TraceLine>,
+    /// The line number each instruction was read from, counting from 1.
+    pub line_nums: Vec<usize>,
+}
+
+/// Where two traces stop matching.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Divergence {
+    pub expected_index: usize,
+    pub actual_index: usize,
+    /// The fields recorded in both lines with different values.
+    pub fields: Vec<&'static str>,
+}
+
+/// The result of comparing two traces after aligning them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceDiff {
+    /// The first instruction compared in each trace.
+    pub expected_start: usize,
+    pub actual_start: usize,
+    /// The number of instructions that matched.
+    pub matched: usize,
+    /// None if the traces match until one of them ends.
+    pub divergence: Option<Divergence>,
+}
+
+/// The number of lines whose `pc` and `op` must match for traces to be aligned there.
+const ALIGN_WINDOW: usize = 8;
+
+impl TraceLine {
+    /// @return the line for the state before `entry` ran.
+    pub fn from_entry(entry: &TraceEntry) -> Self {
+        let state = &entry.before;
+        let mut values = [None; 22];
+        values[0] = Some(entry.pc);
+        values[1] = Some(entry.opcode);
+        for (value, register) in values[2..18].iter_mut().zip(state.registers) {
+            *value = Some(register as u16);
+        }
+        values[18] = Some(state.pointer);
+        values[19] = Some(state.stack_pointer as u16);
+        values[20] = Some(state.delay_timer as u16);
+        values[21] = Some(state.sound_timer as u16);
+        Self { values }
+    }
+
+    /// @return the value of one of `TRACE_FIELDS`, or None if it was not recorded.
+    pub fn get(&self, field: &str) -> Option<u16> {
+        field_index(field).and_then(|index| self.values[index])
+    }
+
+    pub fn parse(line: &str) -> Result<Self, String> {
+        let mut values = [None; 22];
+        for word in line.split_whitespace() {
+            let Some((name, value)) = word.split_once('=').or(word.split_once(':')) else {
+                return Err(format!("Expected `name=value`, found `{}`.", word));
+            };
+            let Some(index) = field_index(&name.to_ascii_lowercase()) else {
+                continue;
+            };
+            let hex = value.strip_prefix("0x").unwrap_or(value);
+            let value = u16::from_str_radix(hex, 16)
+                .map_err(|_| format!("Invalid value for {}: `{}`.", name, value))?;
+            values[index] = Some(value);
+        }
+        if values[0].is_none() {
+            return Err("Expected a pc field.".to_string());
+        }
+        Ok(Self { values })
+    }
+
+    /// @return the fields recorded in both lines with different values, skipping any in
+    /// `ignore`.
+    pub fn diff_fields(&self, other: &TraceLine, ignore: &[&str]) -> Vec<&'static str> {
+        TRACE_FIELDS
+            .iter()
+            .zip(self.values.iter().zip(other.values.iter()))
+            .filter(|((name, _), _)| !ignore.contains(name))
+            .filter(|(_, (a, b))| matches!((a, b), (Some(a), Some(b)) if a != b))
+            .map(|((name, _), _)| *name)
+            .collect()
+    }
+
+    /// @return whether the lines are at the same instruction, comparing `pc` and `op`.
+    fn same_instruction(&self, other: &TraceLine) -> bool {
+        self.diff_fields(other, &[])
+            .iter()
+            .all(|field| !["pc", "op"].contains(field))
+    }
+}
+
+/// Formats the line in the canonical format, skipping fields that were not recorded.
+impl fmt::Display for TraceLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for ((name, digits), value) in TRACE_FIELDS.iter().zip(self.values.iter()) {
+            if let Some(value) = value {
+                let separator = if first { "" } else { " " };
+                write!(
+                    f,
+                    "{}{}={:0width$x}",
+                    separator,
+                    name,
+                    value,
+                    width = digits
+                )?;
+                first = false;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl TraceFile {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let lines = text
+            .lines()
+            .enumerate()
+            .map(|(line_num, line)| (line_num + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+            .map(|(line_num, line)| {
+                TraceLine::parse(line)
+                    .map(|trace_line| (line_num, trace_line))
+                    .map_err(|e| format!("Line {}: {}", line_num, e))
+            })
+            .collect::<Result<Vec<(usize, TraceLine)>, String>>()?;
+        let (line_nums, lines) = lines.into_iter().unzip();
+        Ok(Self { lines, line_nums })
+    }
+}
+
+/// @return the first instruction in each trace where they line up, so a trace that starts
+/// later can be compared against one that starts earlier. One of the indices is always 0.
+pub fn align(expected: &[TraceLine], actual: &[TraceLine]) -> Option<(usize, usize)> {
+    let aligned_at = |expected_start: usize, actual_start: usize| {
+        let (Some(expected), Some(actual)) =
+            (expected.get(expected_start..), actual.get(actual_start..))
+        else {
+            return false;
+        };
+        !expected.is_empty()
+            && !actual.is_empty()
+            && expected
+                .iter()
+                .zip(actual.iter())
+                .take(ALIGN_WINDOW)
+                .all(|(a, b)| a.same_instruction(b))
+    };
+    (0..expected.len().max(actual.len())).find_map(|offset| {
+        if aligned_at(offset, 0) {
+            Some((offset, 0))
+        } else if aligned_at(0, offset) {
+            Some((0, offset))
+        } else {
+            None
+        }
+    })
+}
+
+/// Align two traces and compare them line by line from there, skipping the fields in
+/// `ignore`. If they cannot be aligned they are compared from the start.
+pub fn diff_traces(expected: &[TraceLine], actual: &[TraceLine], ignore: &[&str]) -> TraceDiff {
+    let (expected_start, actual_start) = align(expected, actual).unwrap_or((0, 0));
+    let pairs = expected[expected_start..]
+        .iter()
+        .zip(actual[actual_start..].iter());
+    for (i, (a, b)) in pairs.enumerate() {
+        let fields = a.diff_fields(b, ignore);
+        if !fields.is_empty() {
+            return TraceDiff {
+                expected_start,
+                actual_start,
+                matched: i,
+                divergence: Some(Divergence {
+                    expected_index: expected_start + i,
+                    actual_index: actual_start + i,
+                    fields,
+                }),
+            };
+        }
+    }
+    TraceDiff {
+        expected_start,
+        actual_start,
+        matched: (expected.len() - expected_start).min(actual.len() - actual_start),
+        divergence: None,
+    }
+}
+
+fn field_index(name: &str) -> Option<usize> {
+    let name = FIELD_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == name)
+        .map_or(name, |(_, field)| field);
+    TRACE_FIELDS.iter().position(|(field, _)| *field == name)
+}